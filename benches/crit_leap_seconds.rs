@@ -0,0 +1,34 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use hifitime::leap_seconds::LeapSecondsFile;
+use hifitime::{Epoch, TimeScale};
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let file = LeapSecondsFile::from_path("data/leap-seconds.list").unwrap();
+    // The older the epoch, the more leap seconds follow it.
+    let e1980 = Epoch::from_gregorian_utc_hms(1980, 2, 7, 11, 22, 33);
+    let e2020 = Epoch::from_gregorian_utc_hms(2020, 2, 7, 11, 22, 33);
+
+    for (name, epoch) in [("1980", e1980), ("2020", e2020)] {
+        c.bench_function(
+            &format!("Leap seconds with default leap seconds {name}"),
+            |b| b.iter(|| black_box(epoch).leap_seconds(true)),
+        );
+
+        c.bench_function(
+            &format!("Leap seconds with leap seconds file {name}"),
+            |b| b.iter(|| black_box(epoch).leap_seconds_with(true, &file)),
+        );
+
+        c.bench_function(
+            &format!("UTC to TAI with default leap seconds {name}"),
+            |b| b.iter(|| black_box(epoch).to_time_scale(TimeScale::TAI)),
+        );
+
+        c.bench_function(&format!("UTC to TAI with leap seconds file {name}"), |b| {
+            b.iter(|| black_box(epoch).to_time_scale_with(TimeScale::TAI, &file))
+        });
+    }
+}
+
+criterion_group!(leap_seconds, criterion_benchmark);
+criterion_main!(leap_seconds);
@@ -0,0 +1,21 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use hifitime::ut1::Ut1Provider;
+use std::fs::read_to_string;
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let contents = read_to_string("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    let bytes = Ut1Provider::from_eop_data(contents.clone())
+        .unwrap()
+        .to_bytes();
+
+    c.bench_function("UT1 provider from EOP2 data", |b| {
+        b.iter(|| Ut1Provider::from_eop_data(black_box(contents.clone())))
+    });
+
+    c.bench_function("UT1 provider from cached bytes", |b| {
+        b.iter(|| Ut1Provider::from_bytes(black_box(&bytes)))
+    });
+}
+
+criterion_group!(ut1, criterion_benchmark);
+criterion_main!(ut1);
@@ -1,5 +1,108 @@
 import typing
 
+@typing.final
+class CustomTimeScale:
+    """A user-defined time scale, e.g. the clock of a spacecraft or a mission elapsed time, defined from a base time scale.
+
+The custom clock reads `offset` at its reference epoch, and runs faster than the base time scale by `rate_ppm`
+parts per million: `custom = offset + (1 + rate_ppm × 10^-6) × (base - reference)`.
+Its durations are counted past its reference epoch, and its calendar is that of the base time scale, such that
+a custom time scale without any offset nor rate shows the same Gregorian dates as its base time scale.
+
+Register it to parse epochs in this time scale with `Epoch::from_str`, e.g. `2024-01-01T00:00:00 MET`.
+
+(Python documentation hints)"""
+
+    def __init__(self, name: str, reference_epoch: Epoch, base: TimeScale, offset: Duration=None, rate_ppm: float=None) -> CustomTimeScale:
+        """A user-defined time scale, e.g. the clock of a spacecraft or a mission elapsed time, defined from a base time scale.
+
+The custom clock reads `offset` at its reference epoch, and runs faster than the base time scale by `rate_ppm`
+parts per million: `custom = offset + (1 + rate_ppm × 10^-6) × (base - reference)`.
+Its durations are counted past its reference epoch, and its calendar is that of the base time scale, such that
+a custom time scale without any offset nor rate shows the same Gregorian dates as its base time scale.
+
+Register it to parse epochs in this time scale with `Epoch::from_str`, e.g. `2024-01-01T00:00:00 MET`.
+
+(Python documentation hints)"""
+
+    def base(self) -> TimeScale:
+        """Returns the base time scale of this time scale."""
+
+    def from_duration(self, duration: Duration) -> Epoch:
+        """Returns the epoch, in the base time scale, at which this time scale reads the provided duration past its reference epoch."""
+
+    @staticmethod
+    def from_time_scale(ts: TimeScale) -> CustomTimeScale:
+        """Returns the custom time scale of the provided `TimeScale` handle, even if it was unregistered,
+or None for a built-in time scale."""
+
+    def name(self) -> str:
+        """Returns the name of this time scale."""
+
+    def offset(self) -> Duration:
+        """Returns the reading of this time scale at its reference epoch."""
+
+    def parse(self, s_in: str) -> Epoch:
+        """Parses an epoch in this time scale, either as a Gregorian date and time (e.g. `2024-01-01T12:34:56.789 MET`) or as seconds
+past its reference epoch (e.g. `SEC 17.2 MET`), and returns it in the base time scale."""
+
+    @staticmethod
+    def purge_unregistered() -> int:
+        """Forgets the definitions of all the unregistered time scales, and returns how many were forgotten.
+Their handles are reused by the next registrations, so the epochs in these time scales must no longer be used."""
+
+    def rate_ppm(self) -> float:
+        """Returns the rate of this time scale relative to its base time scale, in parts per million."""
+
+    def reference_epoch(self) -> Epoch:
+        """Returns the reference epoch of this time scale, in its base time scale."""
+
+    def register(self) -> TimeScale:
+        """Registers a copy of this time scale and returns its `TimeScale` handle, which epochs can be converted into,
+and such that `Epoch` parses the epochs suffixed by its name.
+Raises an exception if a different time scale of the same name is registered."""
+
+    def to_duration(self, epoch: Epoch) -> Duration:
+        """Returns the duration past the reference epoch read by this time scale at the provided epoch, in any time scale."""
+
+    def to_gregorian_str(self, epoch: Epoch) -> str:
+        """Returns the provided epoch formatted as a Gregorian date and time in this time scale, e.g. `2024-01-01T00:00:00 MET`."""
+
+    @staticmethod
+    def unregister(name: str) -> None:
+        """Unregisters the time scale of the provided name, if any."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
+    def __str__(self) -> str:
+        """Return str(self)."""
+
+@typing.final
+class DeltaTModel:
+    """A model of ΔT = TT - UT1, the accumulated slowing of the rotation of the Earth, for the epochs without any UT1 data,
+i.e. before the first Earth Orientation Parameters (1962) and far in the future.
+
+These models only capture the long-term trend of ΔT: their uncertainty is about a second in the 20th century,
+minutes in antiquity and hours tens of millennia away from today."""
+
+    def delta_t(self, epoch: Epoch) -> Duration:
+        """Returns ΔT = TT - UT1 at the provided epoch."""
+
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __int__(self) -> None:
+        """int(self)"""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+    EspenakMeeus: DeltaTModel = ...
+    LongTermParabola: DeltaTModel = ...
+
 @typing.final
 class Duration:
     """Defines generally usable durations for nanosecond precision valid for 32,768 centuries in either direction, and only on 80 bits / 10 octets.
@@ -266,6 +369,18 @@ set self.__traceback__ to tb and return self."""
     def __str__():
         """Return str(self)."""
 
+@typing.final
+class EarthOrientation:
+    """The Earth orientation parameters other than UT1, each of which is None if the EOP data does not include it."""
+    dx_arcsec: float | None
+    dy_arcsec: float | None
+    lod: Duration | None
+    x_pole_arcsec: float | None
+    y_pole_arcsec: float | None
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
 @typing.final
 class Epoch:
     """Defines a nanosecond-precision Epoch.
@@ -287,6 +402,42 @@ Refer to the appropriate functions for initializing this Epoch from different ti
     def duration_in_year(self) -> Duration:
         """Returns the duration since the start of the year"""
 
+    def earth_rotation_angle_rad(self) -> float:
+        """Returns the Earth Rotation Angle (IAU 2000) of this epoch, in radians in [0, 2π).
+
+UT1 comes from the registered UT1 provider, and **this panics if it does not cover this epoch**, like `to_time_scale`:
+use `try_earth_rotation_angle_rad` to get an error instead, or `earth_rotation_angle_rad_with_ut1` (`ut1` feature)
+to provide the UT1 data."""
+
+    def equation_of_the_equinoxes_2000b_rad(self) -> float:
+        """Returns the equation of the equinoxes (IAU 2000B) of this epoch, in radians, as SOFA `iauEe00b`, i.e. the Greenwich
+Apparent minus Mean Sidereal Time of `gast_2000b_rad`."""
+
+    def equation_of_time(self) -> Duration:
+        """Returns the equation of time of this epoch, i.e. the apparent minus the mean solar time, which varies between
+about -14 and +16 minutes over the year.
+
+This is the formula of Smart (1956), as given by Meeus (Astronomical Algorithms, chapter 28), with the mean orbital
+elements of the Sun: it is accurate to a few seconds, which is plenty for the apparent solar time."""
+
+    def gast_2000b_rad(self) -> float:
+        """Returns the Greenwich Apparent Sidereal Time (IAU 2000B) of this epoch, in radians in [0, 2π), as SOFA `iauGst00b`,
+i.e. the Greenwich Mean Sidereal Time (IAU 2000) plus the equation of the equinoxes of `equation_of_the_equinoxes_2000b_rad`.
+
+This is not the IAU 2006/2000A model of SOFA `iauGst06a`, from which it differs by up to about a milliarcsecond
+between 1995 and 2050, nor is its mean sidereal time that of `gmst_rad` (IAU 2006).
+
+UT1 comes from the registered UT1 provider, and **this panics if it does not cover this epoch**, cf. `earth_rotation_angle_rad`."""
+
+    def gmst_rad(self) -> float:
+        """Returns the Greenwich Mean Sidereal Time (IAU 2006) of this epoch, in radians in [0, 2π).
+
+UT1 comes from the registered UT1 provider, and **this panics if it does not cover this epoch**, cf. `earth_rotation_angle_rad`."""
+
+    def has_ut1_data(self) -> bool:
+        """Returns true if the registered `Ut1Provider` covers this epoch, i.e. if `to_time_scale` and the computations which depend on UT1
+(the sidereal and solar times) can convert this epoch to or from UT1 instead of panicking."""
+
     def hours(self) -> int:
         """Returns the hours of the Gregorian representation  of this epoch in the time scale it was initialized in."""
 
@@ -314,6 +465,27 @@ defined as January 1st 2006 (cf. <https://gssc.esa.int/navipedia/index.php/Time_
     def init_from_et_seconds(seconds_since_j2000: float) -> Epoch:
         """Initialize an Epoch from the Ephemeris Time seconds past 2000 JAN 01 (J2000 reference)"""
 
+    @staticmethod
+    def init_from_glonasst_days(days: float) -> Epoch:
+        """Initialize an Epoch from the number of days since the GLONASS Time Epoch,
+defined as 1996 January 1st midnight GLONASST, i.e. 1995 December 31st at 21:00:00 UTC."""
+
+    @staticmethod
+    def init_from_glonasst_n4_nt(n4: int, nt: int, nanoseconds: int) -> Epoch:
+        """Initialize an Epoch in GLONASST from the four-year interval number N4 (1 for 1996 to 1999),
+the calendar day number NT within that interval, and the nanoseconds elapsed in that day."""
+
+    @staticmethod
+    def init_from_glonasst_nanoseconds(nanoseconds: int) -> Epoch:
+        """Initialize an Epoch from the number of nanoseconds since the GLONASS Time Epoch,
+defined as 1996 January 1st midnight GLONASST, i.e. 1995 December 31st at 21:00:00 UTC.
+This may be useful for time keeping devices that use GLONASS as a time source."""
+
+    @staticmethod
+    def init_from_glonasst_seconds(seconds: float) -> Epoch:
+        """Initialize an Epoch from the number of seconds since the GLONASS Time Epoch,
+defined as 1996 January 1st midnight GLONASST, i.e. 1995 December 31st at 21:00:00 UTC."""
+
     @staticmethod
     def init_from_gpst_days(days: float) -> Epoch:
         """Initialize an Epoch from the number of days since the GPS Time Epoch,
@@ -366,6 +538,22 @@ This may be useful for time keeping devices that use GST as a time source."""
 starting on August 21st 1999 Midnight UT,
 (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS>)."""
 
+    @staticmethod
+    def init_from_irnsst_days(days: float) -> Epoch:
+        """Initialize an Epoch from the number of days since the NavIC (IRNSS) Time Epoch,
+starting on August 22nd 1999 midnight GPST, i.e. 13 seconds before midnight UTC, like Galileo."""
+
+    @staticmethod
+    def init_from_irnsst_nanoseconds(nanoseconds: int) -> Epoch:
+        """Initialize an Epoch from the number of nanoseconds since the NavIC (IRNSS) Time Epoch,
+starting on August 22nd 1999 midnight GPST, i.e. 13 seconds before midnight UTC, like Galileo.
+This may be useful for time keeping devices that use NavIC as a time source."""
+
+    @staticmethod
+    def init_from_irnsst_seconds(seconds: float) -> Epoch:
+        """Initialize an Epoch from the number of seconds since the NavIC (IRNSS) Time Epoch,
+starting on August 22nd 1999 midnight GPST, i.e. 13 seconds before midnight UTC, like Galileo."""
+
     @staticmethod
     def init_from_jde_et(days: float) -> Epoch:
         """Initialize from the JDE days"""
@@ -422,6 +610,22 @@ defined as UTC midnight of January 5th to 6th 1980 (cf. <https://gssc.esa.int/na
     def init_from_tai_seconds(seconds: float) -> Epoch:
         """Initialize an Epoch from the provided TAI seconds since 1900 January 01 at midnight"""
 
+    @staticmethod
+    def init_from_tcb_duration(duration_since_j2000: Duration) -> Epoch:
+        """Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) duration past J2000, like TDB."""
+
+    @staticmethod
+    def init_from_tcb_seconds(seconds_j2000: float) -> Epoch:
+        """Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) seconds past J2000, like TDB."""
+
+    @staticmethod
+    def init_from_tcg_duration(duration: Duration) -> Epoch:
+        """Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) duration past J1900, like TT."""
+
+    @staticmethod
+    def init_from_tcg_seconds(seconds: float) -> Epoch:
+        """Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) seconds past J1900, like TT."""
+
     @staticmethod
     def init_from_tdb_duration(duration_since_j2000: Duration) -> Epoch:
         """Initialize from Dynamic Barycentric Time (TDB) (same as SPICE ephemeris time) whose epoch is 2000 JAN 01 noon TAI."""
@@ -476,6 +680,28 @@ Returns None if the epoch is before 1960, year at which UTC was defined.
 # Why does this function return an `Option` when the other returns a value
 This is to match the `iauDat` function of SOFA (src/dat.c). That function will return a warning and give up if the start date is before 1960."""
 
+    def local_apparent_solar_time(self, longitude_rad: float) -> Duration:
+        """Returns the local apparent solar time of this epoch at the provided longitude (positive east, in radians),
+as the time of day since the local apparent midnight, i.e. the time of a sundial: the local mean solar time
+plus the equation of time.
+
+UT1 comes from the registered UT1 provider, and **this panics if it does not cover this epoch**, cf. `local_mean_solar_time`.
+Use `Formatter::with_local_apparent_solar_time` to format the local apparent solar date and time."""
+
+    def local_mean_solar_time(self, longitude_rad: float) -> Duration:
+        """Returns the local mean solar time of this epoch at the provided longitude (positive east, in radians),
+as the time of day since the local mean midnight, i.e. UT1 shifted by one hour per 15 degrees of longitude.
+
+UT1 comes from the registered UT1 provider, and **this panics if it does not cover this epoch**, like `to_time_scale`:
+use `try_local_mean_solar_time` to get an error instead, or `local_mean_solar_time_with_ut1` (`ut1` feature) to provide
+the UT1 data. This never panics for an epoch in the UT1 time scale. Use `Formatter::with_local_mean_solar_time` to format the local mean solar date and time."""
+
+    def local_sidereal_time_rad(self, longitude_rad: float) -> float:
+        """Returns the local apparent sidereal time of this epoch at the provided longitude (positive east), both in radians,
+i.e. the Greenwich Apparent Sidereal Time of `gast_2000b_rad` plus that longitude, in [0, 2π).
+
+UT1 comes from the registered UT1 provider, and **this panics if it does not cover this epoch**, cf. `earth_rotation_angle_rad`."""
+
     def microseconds(self) -> int:
         """Returns the microseconds of the Gregorian representation  of this epoch in the time scale it was initialized in."""
 
@@ -545,6 +771,24 @@ In order to match SPICE, the as_et_duration() function will manually get rid of
     def to_et_seconds(self) -> float:
         """Returns the Ephemeris Time seconds past 2000 JAN 01 midnight, matches NASA/NAIF SPICE."""
 
+    def to_glonasst_days(self) -> float:
+        """Returns days past GLONASS Time Epoch, defined as 1996 January 1st midnight GLONASST."""
+
+    def to_glonasst_duration(self) -> Duration:
+        """Returns `Duration` past GLONASS time Epoch."""
+
+    def to_glonasst_n4_nt(self) -> typing.Tuple:
+        """Returns the GLONASS four-year interval number N4, the day number NT within that interval,
+and the nanoseconds elapsed in that day.
+NOTE: This function will return an error if N4 is not within 1 to 255."""
+
+    def to_glonasst_nanoseconds(self) -> int:
+        """Returns nanoseconds past GLONASS Time Epoch, defined as 1996 January 1st midnight GLONASST.
+NOTE: This function will return an error if the centuries past GLONASST time are not zero."""
+
+    def to_glonasst_seconds(self) -> float:
+        """Returns seconds past GLONASS Time Epoch"""
+
     def to_gpst_days(self) -> float:
         """Returns days past GPS Time Epoch, defined as UTC midnight of January 5th to 6th 1980 (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#GPS_Time_.28GPST.29>)."""
 
@@ -574,6 +818,19 @@ NOTE: This function will return an error if the centuries past GST time are not
     def to_gst_seconds(self) -> float:
         """Returns seconds past GST (Galileo) Time Epoch"""
 
+    def to_irnsst_days(self) -> float:
+        """Returns days past NavIC (IRNSS) Time Epoch, starting on August 22nd 1999 midnight GPST, i.e. 13 seconds before midnight UTC, like Galileo."""
+
+    def to_irnsst_duration(self) -> Duration:
+        """Returns `Duration` past NavIC (IRNSS) time Epoch."""
+
+    def to_irnsst_nanoseconds(self) -> int:
+        """Returns nanoseconds past NavIC (IRNSS) Time Epoch, starting on August 22nd 1999 midnight GPST, i.e. 13 seconds before midnight UTC, like Galileo.
+NOTE: This function will return an error if the centuries past IRNSST time are not zero."""
+
+    def to_irnsst_seconds(self) -> float:
+        """Returns seconds past NavIC (IRNSS) Time Epoch"""
+
     def to_isoformat(self) -> str:
         """The standard ISO format of this epoch (six digits of subseconds) in the _current_ time scale, refer to <https://docs.rs/hifitime/latest/hifitime/efmt/format/struct.Format.html> for format options."""
 
@@ -598,6 +855,16 @@ as explained in "Fundamentals of astrodynamics and applications", Vallado et al.
     def to_jde_tai_seconds(self) -> float:
         """Returns the Julian seconds in TAI."""
 
+    def to_jde_tcb_days(self) -> float:
+        """Returns days past Julian epoch in Barycentric Coordinate Time (TCB)"""
+
+    def to_jde_tcb_duration(self) -> Duration:...
+
+    def to_jde_tcg_days(self) -> float:
+        """Returns days past Julian epoch in Geocentric Coordinate Time (TCG)"""
+
+    def to_jde_tcg_duration(self) -> Duration:...
+
     def to_jde_tdb_days(self) -> float:
         """Returns the Dynamic Barycentric Time (TDB) (higher fidelity SPICE ephemeris time) whose epoch is 2000 JAN 01 noon TAI (cf. <https://gssc.esa.int/navipedia/index.php/Transformations_between_Time_Systems#TDT_-_TDB.2C_TCB>)"""
 
@@ -678,6 +945,18 @@ NOTE: This function will return an error if the centuries past QZSST time are no
     def to_tai_seconds(self) -> float:
         """Returns the number of TAI seconds since J1900"""
 
+    def to_tcb_duration(self) -> Duration:
+        """Returns `Duration` past J2000 in Barycentric Coordinate Time (TCB), like TDB."""
+
+    def to_tcb_seconds(self) -> float:
+        """Returns seconds past J2000 in Barycentric Coordinate Time (TCB), like TDB."""
+
+    def to_tcg_duration(self) -> Duration:
+        """Returns `Duration` past J1900 in Geocentric Coordinate Time (TCG), like TT."""
+
+    def to_tcg_seconds(self) -> float:
+        """Returns seconds past J1900 in Geocentric Coordinate Time (TCG), like TT."""
+
     def to_tdb_centuries_since_j2000(self) -> float:
         """Returns the number of centuries since Dynamic Barycentric Time (TDB) J2000 (used for Archinal et al. rotations)"""
 
@@ -700,6 +979,14 @@ past J2000, one cannot solve the revert the operation analytically. Instead, we
 7. At this stage, we have a good approximation of the TDB seconds since J2000.
 8. Reverse the algorithm given that approximation: compute the `g` offset, compute the difference between TDB and TAI, add the TT offset (32.184 s), and offset by the difference between J1900 and J2000."""
 
+    def to_tdb_duration_at(self, observer: Observer, model: TdbModel) -> Duration:
+        """Returns the Barycentric Dynamical Time (TDB) duration past J2000 of this epoch at the provided observer, i.e. the geocentric
+TDB of the provided model plus the topocentric term of `Observer::topocentric_offset`."""
+
+    def to_tdb_duration_with_ephemeris(self, ephemeris: TimeEphemeris) -> Duration:
+        """Returns the Barycentric Dynamical Time (TDB) duration past J2000 of this epoch using the provided time ephemeris.
+Returns an error if the ephemeris does not cover this epoch."""
+
     def to_tdb_seconds(self) -> float:
         """Returns the Dynamic Barycentric Time (TDB) (higher fidelity SPICE ephemeris time) whose epoch is 2000 JAN 01 noon TAI (cf. <https://gssc.esa.int/navipedia/index.php/Transformations_between_Time_Systems#TDT_-_TDB.2C_TCB>)"""
 
@@ -707,7 +994,58 @@ past J2000, one cannot solve the revert the operation analytically. Instead, we
         """Converts self to another time scale
 
 As per the [Rust naming convention](https://rust-lang.github.io/api-guidelines/naming.html#ad-hoc-conversions-follow-as_-to_-into_-conventions-c-conv),
-this borrows an Epoch and returns an owned Epoch."""
+this borrows an Epoch and returns an owned Epoch.
+
+# Panics
+**If UT1 is involved and no registered `Ut1Provider` covers this epoch**, since UT1 cannot be computed without Earth
+Orientation Parameters. To convert to or from UT1, provide the UT1 data with `to_time_scale_with_ut1` (requires the `ut1`
+feature), or check `has_ut1_data`, or use `try_to_time_scale` to get an error instead. The conversions which do not involve
+UT1 never panic.
+
+# UTC before 1972
+UTC follows the SOFA definition, i.e. the UTC offsets from 1960 to 1972 and their drift, exactly as the `iauDat` function
+of SOFA (src/dat.c), such that UTC and TAI differ by about 1 to 10 seconds over that period. Prior to 1960, UTC is not defined
+and is equal to TAI. Use `to_time_scale_with` and `leap_seconds::IersOnly` to only account for the leap seconds announced
+by the IERS, such that UTC equals TAI before 1972."""
+
+    def to_time_scale_sofa(self, ts: TimeScale) -> Epoch:
+        """Converts self to another time scale using the SOFA definition of UTC, i.e. accounting for the UTC offsets from 1960 to 1972
+and their drift, exactly as the `iauDat` function of SOFA (src/dat.c).
+
+This is `to_time_scale`, unless the default leap seconds were set to a provider which only accounts for the leap seconds
+announced by the IERS, cf. `leap_seconds::IersOnly`. Prior to 1960, UTC is not defined and is equal to TAI.
+
+**Like `to_time_scale`, this panics if UT1 is involved and no registered `Ut1Provider` covers this epoch.**"""
+
+    def to_time_scale_with_delta_t(self, ts: TimeScale, model: DeltaTModel) -> Epoch:
+        """Converts self to another time scale using the provided ΔT model for UT1, e.g. to convert historical UT records to TT.
+Unlike the UT1 data, a ΔT model covers every epoch, so this conversion cannot fail."""
+
+    def to_time_scale_with_et_model(self, ts: TimeScale, model: EtModel) -> Epoch:
+        """Converts self to another time scale using the provided ET model instead of `EtModel::NAIF0012` for Ephemeris Time,
+e.g. the model of the leap seconds kernel loaded in SPICE with `LeapSecondsKernel::et_model`.
+
+**Like `to_time_scale`, this panics if UT1 is involved and no registered `Ut1Provider` covers this epoch.**"""
+
+    def to_time_scale_with_tdb_model(self, ts: TimeScale, model: TdbModel) -> Epoch:
+        """Converts self to another time scale using the provided TDB model instead of `TdbModel::Esa` for TDB and TCB,
+e.g. `TdbModel::FairheadBretagnon` for a nanosecond TDB. The ET conversions are unaffected.
+
+**Like `to_time_scale`, this panics if UT1 is involved and no registered `Ut1Provider` covers this epoch.**"""
+
+    def to_time_scale_with_file(self, ts: TimeScale, provider: LeapSecondsFile) -> Epoch:
+        """Converts self to another time scale using the leap seconds of the provided LeapSecondsFile instead of the default ones."""
+
+    def to_time_scale_with_ut1(self, ts: TimeScale, provider: Ut1Provider) -> Epoch:
+        """Converts self to another time scale using the UT1 data of the provided Ut1Provider instead of the registered one.
+Returns an error if UT1 is involved and the provider does not cover this epoch."""
+
+    def to_topocentric_tdb_duration_with(self, model: TdbModel, longitude_rad: float, u_km: float, v_km: float) -> Duration:
+        """Returns the Barycentric Dynamical Time (TDB) duration past J2000 of this epoch for an observer on (or near) the Earth,
+i.e. the geocentric TDB of the provided model plus the topocentric terms of `TdbModel::topocentric_terms`.
+
+The observer is at the provided longitude (positive east, in radians), at `u_km` from the spin axis of the Earth
+and at `v_km` north of the equatorial plane."""
 
     def to_tt_centuries_j2k(self) -> float:
         """Returns the centuries passed J2000 TT"""
@@ -751,6 +1089,41 @@ this borrows an Epoch and returns an owned Epoch."""
     def to_utc_seconds(self) -> float:
         """Returns the number of UTC seconds since the TAI epoch"""
 
+    def try_earth_rotation_angle_rad(self) -> float:
+        """Returns the Earth Rotation Angle (IAU 2000) of this epoch, in radians in [0, 2π), like `earth_rotation_angle_rad`,
+but returns an error instead of panicking if the registered UT1 provider does not cover this epoch."""
+
+    def try_gast_2000b_rad(self) -> float:
+        """Returns the Greenwich Apparent Sidereal Time (IAU 2000B) of this epoch, in radians in [0, 2π), like `gast_2000b_rad`,
+but returns an error instead of panicking if the registered UT1 provider does not cover this epoch."""
+
+    def try_gmst_rad(self) -> float:
+        """Returns the Greenwich Mean Sidereal Time (IAU 2006) of this epoch, in radians in [0, 2π), like `gmst_rad`,
+but returns an error instead of panicking if the registered UT1 provider does not cover this epoch."""
+
+    def try_local_apparent_solar_time(self, longitude_rad: float) -> Duration:
+        """Returns the local apparent solar time of this epoch at the provided longitude (positive east, in radians), like
+`local_apparent_solar_time`, but returns an error instead of panicking if the registered UT1 provider does not cover this epoch."""
+
+    def try_local_mean_solar_time(self, longitude_rad: float) -> Duration:
+        """Returns the local mean solar time of this epoch at the provided longitude (positive east, in radians), like
+`local_mean_solar_time`, but returns an error instead of panicking if the registered UT1 provider does not cover this epoch."""
+
+    def try_local_sidereal_time_rad(self, longitude_rad: float) -> float:
+        """Returns the local apparent sidereal time of this epoch at the provided longitude (positive east), both in radians,
+like `local_sidereal_time_rad`, but returns an error instead of panicking if the registered UT1 provider does not cover this epoch."""
+
+    def try_to_time_scale(self, ts: TimeScale) -> Epoch:
+        """Converts self to another time scale, returning an error if UT1 is involved and no UT1 data covers this epoch.
+
+The UT1 data is that of the `Ut1Provider` registered with `Ut1Provider::register` (requires the `ut1` feature), if any:
+use `to_time_scale_with_ut1` to provide it instead. Unlike `to_time_scale`, this never panics."""
+
+    def try_to_time_scale_with_ephemeris(self, ts: TimeScale, ephemeris: TimeEphemeris) -> Epoch:
+        """Converts self to another time scale using the provided time ephemeris instead of `TdbModel::Esa` for TDB and TCB.
+Returns an error if TDB (or TCB) is involved and the ephemeris does not cover this epoch, or if UT1 is involved
+and the registered UT1 provider does not cover it."""
+
     def year(self) -> int:
         """Returns the number of Gregorian years of this epoch in the current time scale."""
 
@@ -795,6 +1168,30 @@ this borrows an Epoch and returns an owned Epoch."""
     def __sub__():
         """Return self-value."""
 
+@typing.final
+class EtModel:
+    """The Ephemeris Time (ET) model of NASA/NAIF SPICE, as defined by the `DELTET` variables of a leap seconds kernel (LSK).
+
+ET - TAI = DELTA_T_A + K * sin(E), where E = M + EB * sin(M) and M = M0 + M1 * t, with t the number of seconds past J2000.
+
+The conversions use `EtModel::NAIF0012`, unless another model is provided per conversion, e.g. with
+`Epoch::to_time_scale_with_et_model` or `Epoch::to_et_duration_with`.
+
+(Python documentation hints)"""
+
+    def __init__(self, delta_t_a: float, k: float, eb: float, m0: float, m1: float) -> EtModel:
+        """The Ephemeris Time (ET) model of NASA/NAIF SPICE, as defined by the `DELTET` variables of a leap seconds kernel (LSK).
+
+ET - TAI = DELTA_T_A + K * sin(E), where E = M + EB * sin(M) and M = M0 + M1 * t, with t the number of seconds past J2000.
+
+The conversions use `EtModel::NAIF0012`, unless another model is provided per conversion, e.g. with
+`Epoch::to_time_scale_with_et_model` or `Epoch::to_et_duration_with`.
+
+(Python documentation hints)"""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
 @typing.final
 class HifitimeError:
     __cause__: typing.Any
@@ -849,13 +1246,63 @@ The unannoucned leap seconds come from dat.c in the SOFA library."""
 class LeapSecondsFile:
     """A leap second provider that uses an IERS formatted leap seconds file.
 
+The expiration date (`#@` line) and the last update (`#$` line) of the file are available with `expires` and `last_update`.
+With the `leap-seconds-hash` feature, if the file includes its SHA-1 hash (`#h` line), the data is verified against it
+and corrupted files are rejected.
+
 (Python documentation hints)"""
 
     def __init__(self, path: str) -> LeapSecondsFile:
         """A leap second provider that uses an IERS formatted leap seconds file.
 
+The expiration date (`#@` line) and the last update (`#$` line) of the file are available with `expires` and `last_update`.
+With the `leap-seconds-hash` feature, if the file includes its SHA-1 hash (`#h` line), the data is verified against it
+and corrupted files are rejected.
+
+(Python documentation hints)"""
+
+    @staticmethod
+    def discover() -> LeapSecondsFile:
+        """Looks for a leap seconds file in the usual system locations, and falls back to the built-in leap seconds."""
+
+    def expires(self) -> Epoch:
+        """Returns the expiration date of this file (`#@` line), after which a leap second may have been announced."""
+
+    @staticmethod
+    def from_bulletin_c_path(path: str) -> LeapSecondsFile:
+        """Builds a leap second provider from the provided IERS Bulletin C."""
+
+    @staticmethod
+    def from_tzdata_path(path: str) -> LeapSecondsFile:
+        """Builds a leap second provider from the provided tzdata `leapseconds` file, e.g. `/usr/share/zoneinfo/leapseconds`."""
+
+    def is_expired_at(self, epoch: Epoch) -> bool:
+        """Returns whether this file has expired at the provided epoch, i.e. whether a leap second may have been announced since."""
+
+    def last_update(self) -> Epoch:
+        """Returns the date of the last update of the leap seconds in this file (`#$` line), if specified."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
+@typing.final
+class LeapSecondsKernel:
+    """A leap second provider that uses a NASA/NAIF SPICE leap seconds kernel (LSK), e.g. `naif0012.tls`.
+
+The leap seconds are read from `DELTET/DELTA_AT`, and the ET model from the other `DELTET` variables, cf. `et_model`.
+
 (Python documentation hints)"""
 
+    def __init__(self, path: str) -> LeapSecondsKernel:
+        """A leap second provider that uses a NASA/NAIF SPICE leap seconds kernel (LSK), e.g. `naif0012.tls`.
+
+The leap seconds are read from `DELTET/DELTA_AT`, and the ET model from the other `DELTET` variables, cf. `et_model`.
+
+(Python documentation hints)"""
+
+    def et_model(self) -> EtModel:
+        """Returns the ET model defined by the `DELTET` variables of this kernel."""
+
     def __repr__(self) -> str:
         """Return repr(self)."""
 
@@ -898,6 +1345,54 @@ class MonthName:
     October: MonthName = ...
     September: MonthName = ...
 
+@typing.final
+class Observer:
+    """An observer on or near the Earth, e.g. a ground station or an Earth orbiter, for the topocentric TDB conversions.
+
+Away from the geocenter, TDB - TT gains the term v_E · x / c^2, where v_E is the barycentric velocity of the Earth and x the
+geocentric position of the observer: this is a diurnal term of about 2 µs for a ground station, and up to about 130 µs at the distance of the Moon.
+The velocity of the Earth comes from its mean Keplerian orbit, accurate to about 0.1 %, i.e. about a nanosecond for a ground station.
+
+(Python documentation hints)"""
+
+    def __init__(self, frame: ObserverFrame, position_km: list, velocity_km_s: list=None) -> Observer:
+        """An observer on or near the Earth, e.g. a ground station or an Earth orbiter, for the topocentric TDB conversions.
+
+Away from the geocenter, TDB - TT gains the term v_E · x / c^2, where v_E is the barycentric velocity of the Earth and x the
+geocentric position of the observer: this is a diurnal term of about 2 µs for a ground station, and up to about 130 µs at the distance of the Moon.
+The velocity of the Earth comes from its mean Keplerian orbit, accurate to about 0.1 %, i.e. about a nanosecond for a ground station.
+
+(Python documentation hints)"""
+
+    def topocentric_offset(self, epoch: Epoch) -> Duration:
+        """Returns the topocentric term of TDB - TT at the provided epoch, i.e. v_E · x / c^2, to add to the geocentric TDB - TT."""
+
+    def topocentric_rate(self, epoch: Epoch) -> float:
+        """Returns the rate of the topocentric term of TDB - TT at the provided epoch, in seconds per second, i.e.
+(a_E · x + v_E · v) / c^2, e.g. to correct the frequency of a clock of this observer.
+Returns None if the velocity of the observer is unknown."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
+@typing.final
+class ObserverFrame:
+    """The frame of the geocentric position (and velocity) of an `Observer`."""
+
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __int__(self) -> None:
+        """int(self)"""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+    EarthFixed: ObserverFrame = ...
+    Inertial: ObserverFrame = ...
+
 @typing.final
 class ParsingError:
     __cause__: typing.Any
@@ -934,9 +1429,119 @@ set self.__traceback__ to tb and return self."""
     def __str__():
         """Return str(self)."""
 
+@typing.final
+class ProperTime:
+    """The proper time τ of a clock along its trajectory, i.e. the time it would measure, integrated from
+dτ/dt = 1 - (U/c^2 + v^2/(2 c^2)) in the coordinate time t of TCG or TCB, to first order in 1/c^2.
+
+The coordinate time scale is one of TT, TCG, TDB or TCB: TT and TDB run slower than TCG and TCB by `IAU_L_G` and `IAU_L_B`,
+which are accounted for, such that a clock on the geoid has the same rate as TT.
+The proper time is a duration past the reference epoch of that time scale, equal to the coordinate time plus the initial offset
+at the first sample. Between the samples, the rate of the proper time is interpolated linearly, and integrated exactly.
+
+(Python documentation hints)"""
+
+    def __init__(self, samples: list, time_scale: TimeScale, initial_offset: Duration) -> ProperTime:
+        """The proper time τ of a clock along its trajectory, i.e. the time it would measure, integrated from
+dτ/dt = 1 - (U/c^2 + v^2/(2 c^2)) in the coordinate time t of TCG or TCB, to first order in 1/c^2.
+
+The coordinate time scale is one of TT, TCG, TDB or TCB: TT and TDB run slower than TCG and TCB by `IAU_L_G` and `IAU_L_B`,
+which are accounted for, such that a clock on the geoid has the same rate as TT.
+The proper time is a duration past the reference epoch of that time scale, equal to the coordinate time plus the initial offset
+at the first sample. Between the samples, the rate of the proper time is interpolated linearly, and integrated exactly.
+
+(Python documentation hints)"""
+
+    def end(self) -> Epoch:
+        """Returns the last epoch of the trajectory, in the coordinate time scale."""
+
+    def from_proper_time(self, proper_time: Duration) -> Epoch:
+        """Returns the epoch, in the coordinate time scale, at which the clock reads the provided proper time.
+Returns an error if the trajectory does not cover that epoch."""
+
+    def offset(self, epoch: Epoch) -> Duration:
+        """Returns the offset of the proper time from the coordinate time, τ - t, at the provided epoch.
+Returns an error if the trajectory does not cover this epoch."""
+
+    def start(self) -> Epoch:
+        """Returns the first epoch of the trajectory, in the coordinate time scale."""
+
+    def time_scale(self) -> TimeScale:
+        """Returns the coordinate time scale of this proper time."""
+
+    def to_proper_time(self, epoch: Epoch) -> Duration:
+        """Returns the proper time at the provided epoch, as a duration past the reference epoch of the coordinate time scale.
+Returns an error if the trajectory does not cover this epoch."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
+@typing.final
+class TdbModel:
+    """A model of TDB - TT, the periodic difference between the Barycentric Dynamical Time and the Terrestrial Time
+as seen from the geocenter, used for the TDB (and TCB) conversions.
+
+The conversions use `TdbModel::Esa`, unless another model is provided per conversion, e.g. with
+`Epoch::to_time_scale_with_tdb_model` or `Epoch::to_tdb_duration_with`.
+
+The ET conversions always use the `EtModel` of SPICE, so that they remain compatible with SPICE whichever TDB model is selected."""
+
+    def tdb_minus_tt(self, epoch: Epoch) -> Duration:
+        """Returns TDB - TT at the provided epoch, as seen from the geocenter."""
+
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __int__(self) -> None:
+        """int(self)"""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+    Esa: TdbModel = ...
+    FairheadBretagnon: TdbModel = ...
+
+@typing.final
+class TimeEphemeris:
+    """A time ephemeris of TT - TDB read from an SPK file of the JPL (e.g. `de440t.bsp`) or INPOP ephemerides, which include
+its numerical integration alongside that of the bodies of the solar system: this is the reference TDB of orbit determination.
+
+Only the TT - TDB segments are read, i.e. the Chebyshev segments (SPK types 2 and 3) of the target 1000000001 relative to
+the center 1000000000, in seconds, as a function of TDB. Use it per conversion with `Epoch::try_to_time_scale_with_ephemeris`,
+`Epoch::to_tdb_duration_with_ephemeris` or `Epoch::from_tdb_duration_with_ephemeris`, which return an error outside of it.
+
+(Python documentation hints)"""
+
+    def __init__(self, path: str) -> TimeEphemeris:
+        """A time ephemeris of TT - TDB read from an SPK file of the JPL (e.g. `de440t.bsp`) or INPOP ephemerides, which include
+its numerical integration alongside that of the bodies of the solar system: this is the reference TDB of orbit determination.
+
+Only the TT - TDB segments are read, i.e. the Chebyshev segments (SPK types 2 and 3) of the target 1000000001 relative to
+the center 1000000000, in seconds, as a function of TDB. Use it per conversion with `Epoch::try_to_time_scale_with_ephemeris`,
+`Epoch::to_tdb_duration_with_ephemeris` or `Epoch::from_tdb_duration_with_ephemeris`, which return an error outside of it.
+
+(Python documentation hints)"""
+
+    def end(self) -> Epoch:
+        """Returns the last epoch covered by this ephemeris, in TDB."""
+
+    def start(self) -> Epoch:
+        """Returns the first epoch covered by this ephemeris, in TDB."""
+
+    def tt_minus_tdb(self, tdb_duration_since_j2000: Duration) -> Duration:
+        """Returns TT - TDB at the provided TDB duration past J2000, if this ephemeris covers it."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
 @typing.final
 class TimeScale:
-    """Enum of the different time systems available"""
+    """Enum of the different time systems available, including the custom time scales registered with `CustomTimeScale.register`"""
+
+    def __init__(self, name: str) -> TimeScale:
+        """Enum of the different time systems available, including the custom time scales registered with `CustomTimeScale.register`"""
 
     def uses_leap_seconds(self) -> bool:
         """Returns true if self takes leap seconds into account"""
@@ -950,7 +1555,10 @@ class TimeScale:
     def __gt__(self, value: typing.Any) -> bool:
         """Return self>value."""
 
-    def __int__(self) -> None:
+    def __hash__(self) -> int:
+        """Return hash(self)."""
+
+    def __int__(self) -> int:
         """int(self)"""
 
     def __le__(self, value: typing.Any) -> bool:
@@ -964,14 +1572,22 @@ class TimeScale:
 
     def __repr__(self) -> str:
         """Return repr(self)."""
+
+    def __str__(self) -> str:
+        """Return str(self)."""
     BDT: TimeScale = ...
     ET: TimeScale = ...
+    GLONASST: TimeScale = ...
     GPST: TimeScale = ...
     GST: TimeScale = ...
+    IRNSST: TimeScale = ...
     QZSST: TimeScale = ...
     TAI: TimeScale = ...
+    TCB: TimeScale = ...
+    TCG: TimeScale = ...
     TDB: TimeScale = ...
     TT: TimeScale = ...
+    UT1: TimeScale = ...
     UTC: TimeScale = ...
 
 @typing.final
@@ -1017,6 +1633,23 @@ class TimeSeries:
     def __str__(self) -> str:
         """Return str(self)."""
 
+@typing.final
+class TrajectorySample:
+    """A sample of the trajectory of a clock, e.g. that of a spacecraft, for the integration of its proper time.
+
+(Python documentation hints)"""
+    epoch: Epoch
+    potential_km2_s2: float
+    speed_km_s: float
+
+    def __init__(self, epoch: Epoch, potential_km2_s2: float, speed_km_s: float) -> TrajectorySample:
+        """A sample of the trajectory of a clock, e.g. that of a spacecraft, for the integration of its proper time.
+
+(Python documentation hints)"""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
 @typing.final
 class Unit:
     """An Enum to perform time unit conversions."""
@@ -1076,6 +1709,56 @@ class Unit:
     Second: Unit = ...
     Week: Unit = ...
 
+@typing.final
+class Ut1Interpolation:
+    """How a `Ut1Provider` computes TAI - UT1 between two of its samples."""
+
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __int__(self) -> None:
+        """int(self)"""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+    Lagrange: Ut1Interpolation = ...
+    Linear: Ut1Interpolation = ...
+    Step: Ut1Interpolation = ...
+
+@typing.final
+class Ut1Prediction:
+    """The model of IERS Bulletin A to predict UT1 beyond the tabulated EOP data:
+
+UT1 - UTC = a + b (MJD - MJD0) - (UT2 - UT1), where MJD is the Modified Julian Date in UTC,
+and UT2 - UT1 is the conventional seasonal variation, cf. `Ut1Prediction::ut2_minus_ut1`.
+
+The IERS estimates the accuracy of this prediction to 0.00025 (MJD - MJD0)^0.75 seconds.
+
+(Python documentation hints)"""
+
+    def __init__(self, a: float, b: float, mjd0: float) -> Ut1Prediction:
+        """The model of IERS Bulletin A to predict UT1 beyond the tabulated EOP data:
+
+UT1 - UTC = a + b (MJD - MJD0) - (UT2 - UT1), where MJD is the Modified Julian Date in UTC,
+and UT2 - UT1 is the conventional seasonal variation, cf. `Ut1Prediction::ut2_minus_ut1`.
+
+The IERS estimates the accuracy of this prediction to 0.00025 (MJD - MJD0)^0.75 seconds.
+
+(Python documentation hints)"""
+
+    def delta_ut1_utc(self, epoch: Epoch) -> Duration:
+        """Returns the predicted UT1 - UTC at the provided epoch."""
+
+    @staticmethod
+    def from_bulletin_a_file(path: str) -> Ut1Prediction:
+        """Builds the prediction model from the provided path to an IERS Bulletin A."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+
 @typing.final
 class Ut1Provider:
     """A structure storing all of the TAI-UT1 data"""
@@ -1084,4 +1767,53 @@ class Ut1Provider:
         """A structure storing all of the TAI-UT1 data"""
 
     def __repr__(self) -> str:
-        """Return repr(self)."""
\ No newline at end of file
+        """Return repr(self)."""
+
+    def earth_orientation_at(self, epoch: Epoch) -> EarthOrientation:
+        """Returns the Earth orientation parameters other than UT1 at the provided epoch, or None if this epoch is outside of the data of this provider."""
+
+    @staticmethod
+    def from_c04_file(path: str) -> Ut1Provider:
+        """Builds a UT1 provider from the provided path to an IERS EOP 14 C04 or EOP 20 C04 file."""
+
+    @staticmethod
+    def from_finals_file(path: str) -> Ut1Provider:
+        """Builds a UT1 provider from the provided path to an IERS finals file, e.g. `finals2000A.all`."""
+
+    def interpolation(self) -> Ut1Interpolation:
+        """Returns the interpolation mode requested for this provider."""
+
+    def quality_at(self, epoch: Epoch) -> Ut1Quality:
+        """Returns the quality of TAI - UT1 at the provided epoch, or None if this epoch is outside of the data of this provider."""
+
+    def register(self) -> None:
+        """Registers a copy of this provider as the UT1 data used by the time scale conversions which do not take any UT1 data,
+in the whole process: prefer providing it per conversion with `Epoch.to_time_scale_with_ut1`."""
+
+    def with_delta_t_model(self, model: DeltaTModel) -> Ut1Provider:
+        """Returns a copy of this provider using the provided ΔT model outside of its samples."""
+
+    def with_interpolation(self, interpolation: Ut1Interpolation) -> Ut1Provider:
+        """Returns a copy of this provider using the provided interpolation mode."""
+
+    def with_prediction(self, prediction: Ut1Prediction) -> Ut1Provider:
+        """Returns a copy of this provider using the provided prediction model after its last sample."""
+
+@typing.final
+class Ut1Quality:
+    """Whether a sample of UT1 was measured or predicted, as flagged in the EOP products."""
+
+    def __eq__(self, value: typing.Any) -> bool:
+        """Return self==value."""
+
+    def __int__(self) -> None:
+        """int(self)"""
+
+    def __ne__(self, value: typing.Any) -> bool:
+        """Return self!=value."""
+
+    def __repr__(self) -> str:
+        """Return repr(self)."""
+    Final: Ut1Quality = ...
+    Predicted: Ut1Quality = ...
+    Rapid: Ut1Quality = ...
\ No newline at end of file
@@ -10,7 +10,9 @@
 
 use core::fmt;
 
-use crate::{parser::Token, Duration, Epoch, TimeScale};
+use crate::{
+    leap_seconds::LeapSecondProvider, parser::Token, Duration, Epoch, HifitimeError, TimeScale,
+};
 
 use super::format::Format;
 
@@ -101,10 +103,56 @@ impl Formatter {
         }
     }
 
+    /// Formats the local mean solar date and time of the provided epoch at the provided longitude (positive east, in radians),
+    /// where the offset (`%z`) is that of the local mean solar time from UT1.
+    ///
+    /// Returns a `MissingUt1Data` error if the epoch is not in the UT1 time scale and the registered UT1 provider does not cover it:
+    /// use `Epoch::to_time_scale_with_ut1` (`ut1` feature) to convert it to UT1 with the provided UT1 data first.
+    pub fn with_local_mean_solar_time(
+        epoch: Epoch,
+        longitude_rad: f64,
+        format: Format,
+    ) -> Result<Self, HifitimeError> {
+        Ok(Self::with_timezone(
+            epoch.try_to_time_scale(TimeScale::UT1)?,
+            Epoch::mean_solar_time_offset(longitude_rad),
+            format,
+        ))
+    }
+
+    /// Formats the local apparent solar date and time of the provided epoch at the provided longitude (positive east, in radians),
+    /// where the offset (`%z`) is that of the local apparent solar time from UT1, i.e. including the equation of time.
+    ///
+    /// Returns a `MissingUt1Data` error like `with_local_mean_solar_time`, if the epoch cannot be converted to UT1.
+    pub fn with_local_apparent_solar_time(
+        epoch: Epoch,
+        longitude_rad: f64,
+        format: Format,
+    ) -> Result<Self, HifitimeError> {
+        let ut1 = epoch.try_to_time_scale(TimeScale::UT1)?;
+        // The equation of time depends on TT, which also requires UT1 data for a UT1 epoch.
+        let equation = epoch.try_to_time_scale(TimeScale::TT)?.equation_of_time();
+        Ok(Self::with_timezone(
+            ut1,
+            Epoch::mean_solar_time_offset(longitude_rad) + equation,
+            format,
+        ))
+    }
+
     pub fn to_time_scale(epoch: Epoch, format: Format, time_scale: TimeScale) -> Self {
         Self::new(epoch.to_time_scale(time_scale), format)
     }
 
+    /// Formats the epoch in the provided time scale, using the leap seconds of the provided LeapSecondProvider instead of the default ones.
+    pub fn to_time_scale_with<L: LeapSecondProvider + ?Sized>(
+        epoch: Epoch,
+        format: Format,
+        time_scale: TimeScale,
+        provider: &L,
+    ) -> Self {
+        Self::new(epoch.to_time_scale_with(time_scale, provider), format)
+    }
+
     pub fn set_timezone(&mut self, offset: Duration) {
         self.offset = offset;
     }
@@ -0,0 +1,196 @@
+/*
+* Hifitime
+* Copyright (C) 2017-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+* This Source Code Form is subject to the terms of the Mozilla Public
+* License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at https://mozilla.org/MPL/2.0/.
+*
+* Documentation: https://nyxspace.com/
+*/
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::TT_OFFSET_MS;
+use crate::{Duration, Epoch, EtModel, TdbModel, TimeScale, TimeUnits};
+
+/// A model of ΔT = TT - UT1, the accumulated slowing of the rotation of the Earth, for the epochs without any UT1 data,
+/// i.e. before the first Earth Orientation Parameters (1962) and far in the future.
+///
+/// These models only capture the long-term trend of ΔT: their uncertainty is about a second in the 20th century,
+/// minutes in antiquity and hours tens of millennia away from today.
+#[cfg_attr(kani, derive(kani::Arbitrary))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+pub enum DeltaTModel {
+    /// The polynomial expressions of Espenak and Meeus (2006) from -500 to +2150, used for the NASA eclipse predictions,
+    /// and the long-term parabola of Morrison and Stephenson (2004) outside of that range.
+    #[default]
+    EspenakMeeus,
+    /// The long-term parabola of Morrison and Stephenson (2004) only: ΔT = -20 + 32 u^2 seconds,
+    /// where u is the number of centuries past 1820.
+    LongTermParabola,
+}
+
+impl DeltaTModel {
+    /// Returns ΔT = TT - UT1 at the provided epoch.
+    pub fn delta_t(&self, epoch: &Epoch) -> Duration {
+        // The models are expressed as a function of the decimal year, which does not need to be more precise than a day.
+        let year = 2000.0 + 100.0 * epoch.to_tt_centuries_j2k();
+        let seconds = match self {
+            Self::EspenakMeeus => espenak_meeus(year),
+            Self::LongTermParabola => long_term_parabola(year),
+        };
+        seconds.seconds()
+    }
+
+    /// Returns TAI - UT1 = ΔT - (TT - TAI) at the provided epoch.
+    pub fn delta_tai_ut1(&self, epoch: &Epoch) -> Duration {
+        self.delta_t(epoch) - TT_OFFSET_MS.milliseconds()
+    }
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(feature = "python", pymethods)]
+impl DeltaTModel {
+    /// Returns ΔT = TT - UT1 at the provided epoch.
+    ///
+    /// :type epoch: Epoch
+    /// :rtype: Duration
+    #[pyo3(name = "delta_t")]
+    fn py_delta_t(&self, epoch: Epoch) -> Duration {
+        self.delta_t(&epoch)
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Epoch {
+    #[must_use]
+    /// Converts self to another time scale using the provided ΔT model for UT1, e.g. to convert historical UT records to TT.
+    /// Unlike the UT1 data, a ΔT model covers every epoch, so this conversion cannot fail.
+    /// The leap seconds are those of `to_time_scale`, including the UTC offsets from 1960 to 1972, which matter for the historical epochs.
+    ///
+    /// :type ts: TimeScale
+    /// :type model: DeltaTModel
+    /// :rtype: Epoch
+    pub fn to_time_scale_with_delta_t(&self, ts: TimeScale, model: DeltaTModel) -> Self {
+        self.to_time_scale_inner(
+            ts,
+            &Self::conversion_delta_at,
+            &|tai| Ok(model.delta_tai_ut1(&Self::from_tai_duration(tai))),
+            EtModel::NAIF0012,
+            &TdbModel::Esa,
+        )
+        .unwrap()
+    }
+}
+
+/// Evaluates the polynomial of the provided coefficients, by increasing degree, at `t`.
+fn polynomial(t: f64, coefficients: &[f64]) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |value, coefficient| value * t + coefficient)
+}
+
+/// ΔT in seconds from the long-term parabola of Morrison and Stephenson (2004).
+fn long_term_parabola(year: f64) -> f64 {
+    let u = (year - 1820.0) / 100.0;
+    -20.0 + 32.0 * u * u
+}
+
+/// ΔT in seconds from the polynomial expressions of Espenak and Meeus (2006), cf. <https://eclipse.gsfc.nasa.gov/SEhelp/deltatpoly2004.html>.
+fn espenak_meeus(year: f64) -> f64 {
+    if year < -500.0 {
+        long_term_parabola(year)
+    } else if year < 500.0 {
+        polynomial(
+            year / 100.0,
+            &[
+                10583.6,
+                -1014.41,
+                33.78311,
+                -5.952053,
+                -0.1798452,
+                0.022174192,
+                0.0090316521,
+            ],
+        )
+    } else if year < 1600.0 {
+        polynomial(
+            (year - 1000.0) / 100.0,
+            &[
+                1574.2,
+                -556.01,
+                71.23472,
+                0.319781,
+                -0.8503463,
+                -0.005050998,
+                0.0083572073,
+            ],
+        )
+    } else if year < 1700.0 {
+        polynomial(year - 1600.0, &[120.0, -0.9808, -0.01532, 1.0 / 7129.0])
+    } else if year < 1800.0 {
+        polynomial(
+            year - 1700.0,
+            &[8.83, 0.1603, -0.0059285, 0.00013336, -1.0 / 1_174_000.0],
+        )
+    } else if year < 1860.0 {
+        polynomial(
+            year - 1800.0,
+            &[
+                13.72,
+                -0.332447,
+                0.0068612,
+                0.0041116,
+                -0.00037436,
+                0.0000121272,
+                -0.0000001699,
+                0.000000000875,
+            ],
+        )
+    } else if year < 1900.0 {
+        polynomial(
+            year - 1860.0,
+            &[
+                7.62,
+                0.5737,
+                -0.251754,
+                0.01680668,
+                -0.0004473624,
+                1.0 / 233_174.0,
+            ],
+        )
+    } else if year < 1920.0 {
+        polynomial(
+            year - 1900.0,
+            &[-2.79, 1.494119, -0.0598939, 0.0061966, -0.000197],
+        )
+    } else if year < 1941.0 {
+        polynomial(year - 1920.0, &[21.20, 0.84493, -0.076100, 0.0020936])
+    } else if year < 1961.0 {
+        polynomial(year - 1950.0, &[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0])
+    } else if year < 1986.0 {
+        polynomial(year - 1975.0, &[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0])
+    } else if year < 2005.0 {
+        polynomial(
+            year - 2000.0,
+            &[
+                63.86,
+                0.3345,
+                -0.060374,
+                0.0017275,
+                0.000651814,
+                0.00002373599,
+            ],
+        )
+    } else if year < 2050.0 {
+        polynomial(year - 2000.0, &[62.92, 0.32217, 0.005589])
+    } else if year < 2150.0 {
+        // Joins the polynomial of 2005-2050 to the long-term parabola.
+        long_term_parabola(year) - 0.5628 * (2150.0 - year)
+    } else {
+        long_term_parabola(year)
+    }
+}
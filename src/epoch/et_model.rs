@@ -0,0 +1,132 @@
+/*
+* Hifitime
+* Copyright (C) 2017-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+* This Source Code Form is subject to the terms of the Mozilla Public
+* License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at https://mozilla.org/MPL/2.0/.
+*
+* Documentation: https://nyxspace.com/
+*/
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)] // Import is indeed used.
+use num_traits::Float;
+
+use super::{NAIF_EB, NAIF_K, NAIF_M0, NAIF_M1};
+use crate::{Duration, TimeScale, TimeUnits};
+
+/// The Ephemeris Time (ET) model of NASA/NAIF SPICE, as defined by the `DELTET` variables of a leap seconds kernel (LSK).
+///
+/// ET - TAI = DELTA_T_A + K * sin(E), where E = M + EB * sin(M) and M = M0 + M1 * t, with t the number of seconds past J2000.
+///
+/// The conversions use `EtModel::NAIF0012`, unless another model is provided per conversion, e.g. with
+/// `Epoch::to_time_scale_with_et_model` or `Epoch::to_et_duration_with`.
+///
+/// (Python documentation hints)
+/// :type delta_t_a: float
+/// :type k: float
+/// :type eb: float
+/// :type m0: float
+/// :type m1: float
+/// :rtype: EtModel
+#[cfg_attr(kani, derive(kani::Arbitrary))]
+#[cfg_attr(feature = "python", pyclass)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EtModel {
+    /// `DELTET/DELTA_T_A`, the difference between TT and TAI in seconds.
+    pub delta_t_a: f64,
+    /// `DELTET/K`, the amplitude of the periodic term in seconds.
+    pub k: f64,
+    /// `DELTET/EB`, the eccentricity of the heliocentric orbit of the Earth-Moon barycenter.
+    pub eb: f64,
+    /// First value of `DELTET/M`, the mean anomaly of the heliocentric orbit of the Earth-Moon barycenter at J2000, in radians.
+    pub m0: f64,
+    /// Second value of `DELTET/M`, the rate of that mean anomaly in radians per second.
+    pub m1: f64,
+}
+
+impl EtModel {
+    /// The ET model of the `naif0012.tls` kernel, used by the conversions unless another model is provided.
+    pub const NAIF0012: Self = Self {
+        delta_t_a: 32.184,
+        k: NAIF_K,
+        eb: NAIF_EB,
+        m0: NAIF_M0,
+        m1: NAIF_M1,
+    };
+
+    /// Returns ET - TAI in seconds at the provided number of seconds past J2000.
+    pub fn delta_et_tai(&self, seconds: f64) -> f64 {
+        // Calculate M, the mean anomaly.
+        let m = self.m0 + seconds * self.m1;
+        // Calculate eccentric anomaly
+        let e = m + self.eb * m.sin();
+
+        self.delta_t_a + self.k * e.sin()
+    }
+
+    /// Returns the TAI duration past J1900 of the provided ET duration past J2000.
+    pub(crate) fn et_to_tai(&self, et: Duration) -> Duration {
+        // Run a Newton Raphson to find the number of seconds past J2000 at which to evaluate the periodic term.
+        let mut seconds_j2000 = et.to_seconds();
+        for _ in 0..5 {
+            seconds_j2000 += -self.k
+                * (self.m0
+                    + self.m1 * seconds_j2000
+                    + self.eb * (self.m0 + self.m1 * seconds_j2000).sin())
+                .sin();
+        }
+
+        // At this point, we have a good estimate of the number of seconds of this epoch.
+        // Reverse the algorithm:
+        let delta_et_tai = self.delta_et_tai(seconds_j2000 - self.delta_t_a);
+
+        // Match SPICE by changing the UTC definition.
+        et - delta_et_tai.seconds() + TimeScale::ET.prime_epoch_offset()
+    }
+
+    /// Returns the ET duration past J2000 of the provided TAI duration past J1900.
+    pub(crate) fn tai_to_et(&self, tai: Duration) -> Duration {
+        let mut seconds = (tai - TimeScale::ET.prime_epoch_offset()).to_seconds();
+        for _ in 0..5 {
+            seconds -= -self.k
+                * (self.m0 + self.m1 * seconds + self.eb * (self.m0 + self.m1 * seconds).sin())
+                    .sin();
+        }
+
+        // At this point, we have a good estimate of the number of seconds of this epoch.
+        // Reverse the algorithm:
+        let delta_et_tai = self.delta_et_tai(seconds + self.delta_t_a);
+
+        // Match SPICE by changing the UTC definition.
+        tai + delta_et_tai.seconds() - TimeScale::ET.prime_epoch_offset()
+    }
+}
+
+impl Default for EtModel {
+    fn default() -> Self {
+        Self::NAIF0012
+    }
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(feature = "python", pymethods)]
+impl EtModel {
+    #[new]
+    pub fn __new__(delta_t_a: f64, k: f64, eb: f64, m0: f64, m1: f64) -> Self {
+        Self {
+            delta_t_a,
+            k,
+            eb,
+            m0,
+            m1,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?} @ {self:p}")
+    }
+}
@@ -34,11 +34,14 @@ impl fmt::Display for Epoch {
 }
 
 impl fmt::Debug for Epoch {
-    /// The default format of an epoch is in UTC
+    /// The default format of an epoch is in UTC, or in the time scale used at initialization if it cannot be converted
+    /// to UTC (e.g. a UT1 epoch without UT1 data), so that formatting never panics.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let ts = TimeScale::UTC;
-        let (y, mm, dd, hh, min, s, nanos) =
-            Self::compute_gregorian(self.to_duration_in_time_scale(ts), ts);
+        let Ok(utc) = self.try_to_time_scale(ts) else {
+            return fmt::Display::fmt(self, f);
+        };
+        let (y, mm, dd, hh, min, s, nanos) = Self::compute_gregorian(utc.duration, ts);
         if nanos == 0 {
             write!(
                 f,
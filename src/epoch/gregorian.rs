@@ -9,9 +9,10 @@
 */
 
 use crate::errors::DurationError;
+use crate::leap_seconds::{self, LeapSecondProvider};
 use crate::parser::Token;
 use crate::{
-    Duration, Epoch, HifitimeError, ParsingError, TimeScale, Unit, DAYS_PER_YEAR_NLD,
+    Duration, Epoch, HifitimeError, ParsingError, TimeScale, TimeUnits, Unit, DAYS_PER_YEAR_NLD,
     HIFITIME_REF_YEAR, NANOSECONDS_PER_MICROSECOND, NANOSECONDS_PER_MILLISECOND,
     NANOSECONDS_PER_SECOND_U32,
 };
@@ -144,8 +145,27 @@ impl Epoch {
     #[must_use]
     /// Converts the Epoch to Gregorian in the provided time scale and in the ISO8601 format with the time scale appended to the string
     pub fn to_gregorian_str(&self, time_scale: TimeScale) -> String {
-        let (y, mm, dd, hh, min, s, nanos) =
-            Self::compute_gregorian(self.to_duration_in_time_scale(time_scale), time_scale);
+        self.gregorian_str_of(self.to_duration_in_time_scale(time_scale), time_scale)
+    }
+
+    #[cfg(feature = "std")]
+    #[must_use]
+    /// Converts the Epoch to Gregorian in the provided time scale and in the ISO8601 format with the time scale appended to the string,
+    /// using the leap seconds of the provided LeapSecondProvider instead of the default ones.
+    pub fn to_gregorian_str_with<L: LeapSecondProvider + ?Sized>(
+        &self,
+        time_scale: TimeScale,
+        provider: &L,
+    ) -> String {
+        self.gregorian_str_of(
+            self.to_time_scale_with(time_scale, provider).duration,
+            time_scale,
+        )
+    }
+
+    #[cfg(feature = "std")]
+    fn gregorian_str_of(&self, duration: Duration, time_scale: TimeScale) -> String {
+        let (y, mm, dd, hh, min, s, nanos) = Self::compute_gregorian(duration, time_scale);
 
         if nanos == 0 {
             format!(
@@ -194,6 +214,17 @@ impl Epoch {
         Self::compute_gregorian(self.to_duration_in_time_scale(ts), ts)
     }
 
+    #[must_use]
+    /// Converts the Epoch to the Gregorian UTC equivalent as (year, month, day, hour, minute, second, nanoseconds), using the
+    /// leap seconds of the provided LeapSecondProvider instead of the default ones.
+    pub fn to_gregorian_utc_with<L: LeapSecondProvider + ?Sized>(
+        &self,
+        provider: &L,
+    ) -> (i32, u8, u8, u8, u8, u8, u32) {
+        let ts = TimeScale::UTC;
+        Self::compute_gregorian(self.to_time_scale_with(ts, provider).duration, ts)
+    }
+
     #[must_use]
     /// Converts the Epoch to the Gregorian TAI equivalent as (year, month, day, hour, minute, second).
     /// WARNING: Nanoseconds are lost in this conversion!
@@ -369,6 +400,9 @@ impl Epoch {
     }
 
     /// Attempts to build an Epoch from the provided Gregorian date and time in UTC.
+    ///
+    /// Returns an error for the dates which never existed in UTC because their second was skipped by a negative leap second of
+    /// the default leap seconds (e.g. 23:59:59 on the last day of that month).
     pub fn maybe_from_gregorian_utc(
         year: i32,
         month: u8,
@@ -378,7 +412,7 @@ impl Epoch {
         second: u8,
         nanos: u32,
     ) -> Result<Self, HifitimeError> {
-        Self::maybe_from_gregorian(
+        let epoch = Self::maybe_from_gregorian(
             year,
             month,
             day,
@@ -387,7 +421,11 @@ impl Epoch {
             second,
             nanos,
             TimeScale::UTC,
-        )
+        )?;
+        if epoch.is_skipped_by_leap_second() {
+            return Err(HifitimeError::InvalidGregorianDate);
+        }
+        Ok(epoch)
     }
 
     #[must_use]
@@ -406,6 +444,64 @@ impl Epoch {
             .expect("invalid Gregorian date")
     }
 
+    /// Builds an Epoch **in TAI** from the provided Gregorian date and time in UTC, using the leap seconds of the provided
+    /// LeapSecondProvider instead of the default ones.
+    ///
+    /// Unlike `maybe_from_gregorian_utc`, the returned Epoch is not in UTC: the leap seconds of that provider are applied once,
+    /// here, so that later conversions (and comparisons) of this Epoch do not depend on the default leap seconds. Use
+    /// `to_time_scale_with` or `to_gregorian_utc_with` with the same provider to get back the UTC date. Returns an error for
+    /// the dates which never existed in UTC because their second was skipped by a negative leap second of that provider.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::prelude::*;
+    /// use hifitime::leap_seconds::LatestLeapSeconds;
+    ///
+    /// let epoch = Epoch::from_gregorian_utc_with(2017, 1, 14, 0, 31, 55, 0, &LatestLeapSeconds).unwrap();
+    /// assert_eq!(epoch.time_scale, TimeScale::TAI);
+    /// assert_eq!(epoch, Epoch::from_gregorian_utc_hms(2017, 1, 14, 0, 31, 55));
+    /// assert_eq!(epoch.to_gregorian_utc_with(&LatestLeapSeconds), (2017, 1, 14, 0, 31, 55, 0));
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_gregorian_utc_with<L: LeapSecondProvider + ?Sized>(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+        provider: &L,
+    ) -> Result<Self, HifitimeError> {
+        let epoch = Self::maybe_from_gregorian(
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanos,
+            TimeScale::UTC,
+        )?;
+        Self::to_tai_with(epoch, provider)
+    }
+
+    /// Converts a UTC (or GLONASST) epoch to TAI with the leap seconds of the provided LeapSecondProvider, and returns an error
+    /// if its second was skipped by a negative leap second of that provider. Epochs in other time scales are only converted.
+    fn to_tai_with<L: LeapSecondProvider + ?Sized>(
+        epoch: Self,
+        provider: &L,
+    ) -> Result<Self, HifitimeError> {
+        if epoch.is_skipped_by_leap_second_with(|duration, time_scale| {
+            leap_seconds::delta_at_in(provider.leap_seconds(), duration, time_scale, true)
+                .unwrap_or(0.0)
+                .seconds()
+        }) {
+            return Err(HifitimeError::InvalidGregorianDate);
+        }
+        Ok(epoch.to_time_scale_with(TimeScale::TAI, provider))
+    }
+
     #[must_use]
     /// Initialize from Gregorian date in UTC at midnight
     pub fn from_gregorian_utc_at_midnight(year: i32, month: u8, day: u8) -> Self {
@@ -539,6 +635,20 @@ impl Epoch {
     /// ```
     #[cfg(not(kani))]
     pub fn from_gregorian_str(s_in: &str) -> Result<Self, HifitimeError> {
+        let (epoch, tz, _) = Self::parse_gregorian_str(s_in)?;
+        if tz != Duration::ZERO && matches!(epoch.time_scale, TimeScale::Custom(_)) {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::ISO8601,
+                details: "timezone offsets are not supported in a custom time scale",
+            });
+        }
+        Ok(epoch + tz)
+    }
+
+    /// Parses a string in the formats of `from_gregorian_str`, and returns the epoch before its timezone offset is applied,
+    /// that timezone offset, and whether the time scale was explicit (i.e. a time scale name or `Z` ends the string).
+    #[cfg(not(kani))]
+    pub(crate) fn parse_gregorian_str(s_in: &str) -> Result<(Self, Duration, bool), HifitimeError> {
         // All of the integers in a date: year, month, day, hour, minute, second, subsecond, offset hours, offset minutes
 
         use snafu::ResultExt;
@@ -548,6 +658,7 @@ impl Epoch {
         let mut decomposed = [0_i32; 9];
         // The parsed time scale, defaults to UTC
         let mut ts = TimeScale::UTC;
+        let mut explicit_ts = false;
         // The offset sign, defaults to positive.
         let mut offset_sign = 1;
 
@@ -566,6 +677,7 @@ impl Epoch {
                         ts = TimeScale::from_str(s[idx..].trim()).with_context(|_| ParseSnafu {
                             details: "parsing as Gregorian date with time scale",
                         })?;
+                        explicit_ts = true;
                     }
                     break;
                 }
@@ -640,7 +752,30 @@ impl Epoch {
             ts,
         )?;
 
-        Ok(epoch + tz)
+        Ok((epoch, tz, explicit_ts || s.ends_with('Z')))
+    }
+
+    /// Initializes an Epoch from a string in the formats of `from_gregorian_str`, using the leap seconds of the provided
+    /// LeapSecondProvider instead of the default ones.
+    ///
+    /// Like `from_gregorian_utc_with`, the returned Epoch is in TAI so that it does not depend on the default leap seconds,
+    /// and an error is returned for the UTC dates which were skipped by a negative leap second of that provider.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::prelude::*;
+    /// use hifitime::leap_seconds::LatestLeapSeconds;
+    ///
+    /// let epoch = Epoch::from_gregorian_str_with("2017-01-14T00:31:55 UTC", &LatestLeapSeconds).unwrap();
+    /// assert_eq!(epoch.time_scale, TimeScale::TAI);
+    /// assert_eq!(epoch, Epoch::from_gregorian_utc_hms(2017, 1, 14, 0, 31, 55));
+    /// ```
+    #[cfg(not(kani))]
+    pub fn from_gregorian_str_with<L: LeapSecondProvider + ?Sized>(
+        s_in: &str,
+        provider: &L,
+    ) -> Result<Self, HifitimeError> {
+        Self::to_tai_with(Self::from_gregorian_str(s_in)?, provider)
     }
 }
 
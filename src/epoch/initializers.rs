@@ -17,6 +17,8 @@ use crate::{
     ET_OFFSET_US, MJD_J1900, MJD_OFFSET, NANOSECONDS_PER_DAY, UNIX_REF_EPOCH,
 };
 
+use super::GLONASST_DAYS_PER_N4;
+
 // Defines the methods that should be classmethods in Python, but must be redefined as per https://github.com/PyO3/pyo3/issues/1003#issuecomment-844433346
 impl Epoch {
     #[must_use]
@@ -100,6 +102,18 @@ impl Epoch {
         Self::from_duration(duration, TimeScale::BDT)
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the provided duration since 1996 January 1st midnight GLONASST (UTC(SU) + 3 hours)
+    pub fn from_glonasst_duration(duration: Duration) -> Self {
+        Self::from_duration(duration, TimeScale::GLONASST)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided duration since August 22nd 1999 midnight
+    pub fn from_irnsst_duration(duration: Duration) -> Self {
+        Self::from_duration(duration, TimeScale::IRNSST)
+    }
+
     #[must_use]
     pub fn from_mjd_tai(days: f64) -> Self {
         Self::from_mjd_in_time_scale(days, TimeScale::TAI)
@@ -231,6 +245,38 @@ impl Epoch {
         Self::from_duration(duration_since_j2000, TimeScale::TDB)
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) seconds past J1900, like TT.
+    pub fn from_tcg_seconds(seconds: f64) -> Self {
+        assert!(
+            seconds.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tcg_duration(seconds * Unit::Second)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) duration past J1900, like TT.
+    pub fn from_tcg_duration(duration: Duration) -> Self {
+        Self::from_duration(duration, TimeScale::TCG)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) seconds past J2000, like TDB.
+    pub fn from_tcb_seconds(seconds_j2000: f64) -> Self {
+        assert!(
+            seconds_j2000.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tcb_duration(seconds_j2000 * Unit::Second)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) duration past J2000, like TDB.
+    pub fn from_tcb_duration(duration_since_j2000: Duration) -> Self {
+        Self::from_duration(duration_since_j2000, TimeScale::TCB)
+    }
+
     #[must_use]
     /// Initialize from the JDE days
     pub fn from_jde_et(days: f64) -> Self {
@@ -341,6 +387,50 @@ impl Epoch {
         Self::from_duration(Duration::from_parts(0, nanoseconds), TimeScale::BDT)
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the number of seconds since the GLONASST Time Epoch,
+    /// defined as 1996 January 1st midnight GLONASST, i.e. 1995 December 31st at 21:00:00 UTC.
+    pub fn from_glonasst_seconds(seconds: f64) -> Self {
+        Self::from_duration(seconds * Unit::Second, TimeScale::GLONASST)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of days since the GLONASST Time Epoch,
+    /// defined as 1996 January 1st midnight GLONASST, i.e. 1995 December 31st at 21:00:00 UTC.
+    pub fn from_glonasst_days(days: f64) -> Self {
+        Self::from_duration(days * Unit::Day, TimeScale::GLONASST)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of nanoseconds since the GLONASST Time Epoch,
+    /// defined as 1996 January 1st midnight GLONASST, i.e. 1995 December 31st at 21:00:00 UTC.
+    /// This may be useful for time keeping devices that use GLONASS as a time source.
+    pub fn from_glonasst_nanoseconds(nanoseconds: u64) -> Self {
+        Self::from_duration(Duration::from_parts(0, nanoseconds), TimeScale::GLONASST)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of seconds since the IRNSST (NavIC) Time Epoch,
+    /// starting August 22nd 1999 midnight GPST, i.e. 13 seconds before midnight UTC, like Galileo.
+    pub fn from_irnsst_seconds(seconds: f64) -> Self {
+        Self::from_duration(seconds * Unit::Second, TimeScale::IRNSST)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of days since the IRNSST (NavIC) Time Epoch,
+    /// starting August 22nd 1999 midnight GPST, i.e. 13 seconds before midnight UTC, like Galileo.
+    pub fn from_irnsst_days(days: f64) -> Self {
+        Self::from_duration(days * Unit::Day, TimeScale::IRNSST)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of nanoseconds since the IRNSST (NavIC) Time Epoch,
+    /// starting August 22nd 1999 midnight GPST, i.e. 13 seconds before midnight UTC, like Galileo.
+    /// This may be useful for time keeping devices that use NavIC as a time source.
+    pub fn from_irnsst_nanoseconds(nanoseconds: u64) -> Self {
+        Self::from_duration(Duration::from_parts(0, nanoseconds), TimeScale::IRNSST)
+    }
+
     #[must_use]
     /// Initialize an Epoch from the provided duration since UTC midnight 1970 January 01.
     pub fn from_unix_duration(duration: Duration) -> Self {
@@ -385,6 +475,24 @@ impl Epoch {
         Self::from_duration(duration, time_scale)
     }
 
+    /// Builds a GLONASST Epoch as broadcast in the GLONASS navigation message: `n4` is the four-year interval number
+    /// (1 for 1996 to 1999), `nt` is the calendar day number within that four-year interval (1 on January 1st of the leap year),
+    /// and `nanoseconds` is the amount of nanoseconds elapsed in that GLONASST day.
+    /// NOTE: This function will return an error if `n4` is zero, if `nt` is not within 1 to 1461,
+    /// or if `nanoseconds` is not within a day.
+    pub fn from_glonasst_n4_nt(n4: u8, nt: u16, nanoseconds: u64) -> Result<Self, HifitimeError> {
+        if n4 == 0
+            || nt == 0
+            || i64::from(nt) > GLONASST_DAYS_PER_N4
+            || nanoseconds >= NANOSECONDS_PER_DAY
+        {
+            return Err(HifitimeError::InvalidGregorianDate);
+        }
+        let days = (i64::from(n4) - 1) * GLONASST_DAYS_PER_N4 + i64::from(nt) - 1;
+        let duration = Unit::Day * days + Duration::from_total_nanoseconds(i128::from(nanoseconds));
+        Ok(Self::from_duration(duration, TimeScale::GLONASST))
+    }
+
     #[must_use]
     /// Builds a UTC Epoch from given `week`: elapsed weeks counter and "ns" amount of nanoseconds since closest Sunday Midnight.
     pub fn from_time_of_week_utc(week: u32, nanoseconds: u64) -> Self {
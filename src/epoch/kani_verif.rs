@@ -10,7 +10,7 @@
 
 use crate::epoch::system_time::duration_since_unix_epoch;
 use crate::leap_seconds::LeapSecond;
-use crate::{Duration, Epoch, TimeScale, Unit, Weekday, TT_OFFSET_MS};
+use crate::{Duration, Epoch, EtModel, TimeScale, Unit, Weekday, TT_OFFSET_MS};
 
 #[kani::proof]
 fn formal_epoch_reciprocity_tai() {
@@ -379,10 +379,19 @@ mod kani_harnesses {
 
     #[kani::proof]
     fn kani_harness_LeapSecond_new() {
-        let timestamp_tai_s: f64 = kani::any();
+        let timestamp_utc_s: i64 = kani::any();
         let delta_at: f64 = kani::any();
         let announced: bool = kani::any();
-        LeapSecond::new(timestamp_tai_s, delta_at, announced);
+        LeapSecond::new(timestamp_utc_s, delta_at, announced);
+    }
+
+    #[kani::proof]
+    fn kani_harness_LeapSecond_with_drift() {
+        let timestamp_utc_s: i64 = kani::any();
+        let delta_at: f64 = kani::any();
+        let drift_ref_mjd: f64 = kani::any();
+        let drift_s_per_day: f64 = kani::any();
+        LeapSecond::with_drift(timestamp_utc_s, delta_at, drift_ref_mjd, drift_s_per_day);
     }
 
     #[kani::proof]
@@ -460,6 +469,18 @@ mod kani_harnesses {
         Epoch::from_qzsst_duration(duration);
     }
 
+    #[kani::proof]
+    fn kani_harness_Epoch_from_glonasst_duration() {
+        let duration: Duration = kani::any();
+        Epoch::from_glonasst_duration(duration);
+    }
+
+    #[kani::proof]
+    fn kani_harness_Epoch_from_irnsst_duration() {
+        let duration: Duration = kani::any();
+        Epoch::from_irnsst_duration(duration);
+    }
+
     #[kani::proof]
     fn kani_harness_Epoch_from_gst_duration() {
         let duration: Duration = kani::any();
@@ -594,6 +615,30 @@ mod kani_harnesses {
         Epoch::from_tdb_duration(duration_since_j2000);
     }
 
+    #[kani::proof]
+    fn kani_harness_Epoch_from_tcg_seconds() {
+        let seconds: f64 = kani::any();
+        Epoch::from_tcg_seconds(seconds);
+    }
+
+    #[kani::proof]
+    fn kani_harness_Epoch_from_tcg_duration() {
+        let duration: Duration = kani::any();
+        Epoch::from_tcg_duration(duration);
+    }
+
+    #[kani::proof]
+    fn kani_harness_Epoch_from_tcb_seconds() {
+        let seconds_j2000: f64 = kani::any();
+        Epoch::from_tcb_seconds(seconds_j2000);
+    }
+
+    #[kani::proof]
+    fn kani_harness_Epoch_from_tcb_duration() {
+        let duration_since_j2000: Duration = kani::any();
+        Epoch::from_tcb_duration(duration_since_j2000);
+    }
+
     #[kani::proof]
     fn kani_harness_Epoch_from_jde_et() {
         let days: f64 = kani::any();
@@ -678,6 +723,50 @@ mod kani_harnesses {
         Epoch::from_bdt_nanoseconds(nanoseconds);
     }
 
+    #[kani::proof]
+    fn kani_harness_Epoch_from_glonasst_seconds() {
+        let seconds: f64 = kani::any();
+        Epoch::from_glonasst_seconds(seconds);
+    }
+
+    #[kani::proof]
+    fn kani_harness_Epoch_from_glonasst_days() {
+        let days: f64 = kani::any();
+        Epoch::from_glonasst_days(days);
+    }
+
+    #[kani::proof]
+    fn kani_harness_Epoch_from_glonasst_nanoseconds() {
+        let nanoseconds: u64 = kani::any();
+        Epoch::from_glonasst_nanoseconds(nanoseconds);
+    }
+
+    #[kani::proof]
+    fn kani_harness_Epoch_from_glonasst_n4_nt() {
+        let n4: u8 = kani::any();
+        let nt: u16 = kani::any();
+        let nanoseconds: u64 = kani::any();
+        let _ = Epoch::from_glonasst_n4_nt(n4, nt, nanoseconds);
+    }
+
+    #[kani::proof]
+    fn kani_harness_Epoch_from_irnsst_seconds() {
+        let seconds: f64 = kani::any();
+        Epoch::from_irnsst_seconds(seconds);
+    }
+
+    #[kani::proof]
+    fn kani_harness_Epoch_from_irnsst_days() {
+        let days: f64 = kani::any();
+        Epoch::from_irnsst_days(days);
+    }
+
+    #[kani::proof]
+    fn kani_harness_Epoch_from_irnsst_nanoseconds() {
+        let nanoseconds: u64 = kani::any();
+        Epoch::from_irnsst_nanoseconds(nanoseconds);
+    }
+
     #[kani::proof]
     fn kani_harness_Epoch_from_unix_duration() {
         let duration: Duration = kani::any();
@@ -697,9 +786,9 @@ mod kani_harnesses {
     }
 
     #[kani::proof]
-    fn kani_harness_Epoch_delta_et_tai() {
+    fn kani_harness_EtModel_delta_et_tai() {
         let seconds: f64 = kani::any();
-        Epoch::delta_et_tai(seconds);
+        EtModel::NAIF0012.delta_et_tai(seconds);
     }
 
     #[kani::proof]
@@ -13,78 +13,377 @@ use pyo3::prelude::*;
 
 #[cfg(feature = "std")]
 pub use super::leap_seconds_file::LeapSecondsFile;
+#[cfg(feature = "std")]
+pub use super::leap_seconds_kernel::LeapSecondsKernel;
 
 use core::ops::Index;
 
-pub trait LeapSecondProvider: DoubleEndedIterator<Item = LeapSecond> + Index<usize> {}
+use crate::{
+    Duration, Epoch, TimeScale, TimeUnits, MJD_J1900, NANOSECONDS_PER_CENTURY,
+    NANOSECONDS_PER_SECOND, SECONDS_PER_DAY,
+};
+
+#[cfg(feature = "std")]
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    PoisonError, RwLock,
+};
+
+/// Number of whole seconds in a century, to compare durations with the leap second timestamps.
+const SECONDS_PER_CENTURY_I64: i64 = (NANOSECONDS_PER_CENTURY / NANOSECONDS_PER_SECOND) as i64;
+
+/// A source of leap seconds, e.g. the built-in `LatestLeapSeconds`, a `LeapSecondsFile` or a `LeapSecondsKernel`.
+///
+/// Providers expose their leap seconds as a borrowed slice sorted by increasing timestamp, so that finding the leap second
+/// in effect at an epoch is a binary search, and so that providers can be shared (and borrowed) without any iteration state.
+pub trait LeapSecondProvider {
+    /// Returns the leap seconds of this provider, sorted by increasing timestamp.
+    fn leap_seconds(&self) -> &[LeapSecond];
+
+    /// Returns the leap second in effect at the provided TAI duration past J1900, if any.
+    /// If `iers_only` is set, only the leap seconds announced by the IERS are considered.
+    fn leap_second_at(&self, tai: Duration, iers_only: bool) -> Option<&LeapSecond> {
+        latest_leap_second_tai(self.leap_seconds(), tai, iers_only)
+    }
+
+    /// Returns the leap second in effect at the provided UTC duration past J1900, if any.
+    /// If `iers_only` is set, only the leap seconds announced by the IERS are considered.
+    fn leap_second_at_utc(&self, utc: Duration, iers_only: bool) -> Option<&LeapSecond> {
+        latest_leap_second_utc(self.leap_seconds(), utc, iers_only)
+    }
+
+    /// Returns the epoch after which this provider may be missing newly announced leap seconds, if known.
+    fn expires(&self) -> Option<Epoch> {
+        None
+    }
+
+    /// Returns whether the conversions with this provider only account for the leap seconds announced by the IERS,
+    /// i.e. ignore the UTC offsets from 1960 to 1972 and their drift, such that UTC equals TAI before 1972.
+    ///
+    /// This is false by default, such that the conversions match the `iauDat` function of SOFA: wrap a provider in `IersOnly`
+    /// to keep UTC equal to TAI before 1972, e.g. for compatibility with SPICE or with hifitime 4.0.
+    fn iers_only(&self) -> bool {
+        false
+    }
+
+    /// Returns whether this provider has expired at the provided epoch, i.e. whether a leap second may have been announced since.
+    fn is_expired_at(&self, epoch: Epoch) -> bool {
+        self.expires().is_some_and(|expires| epoch > expires)
+    }
+}
+
+/// The leap seconds used by all conversions of this process instead of `LatestLeapSeconds`, if any.
+#[cfg(feature = "std")]
+static DEFAULT_LEAP_SECONDS: RwLock<Option<Vec<LeapSecond>>> = RwLock::new(None);
+/// The expiration date of the default leap seconds, if known.
+#[cfg(feature = "std")]
+static DEFAULT_LEAP_SECONDS_EXPIRES: RwLock<Option<Epoch>> = RwLock::new(None);
+/// Whether `DEFAULT_LEAP_SECONDS` is set, to avoid locking in the common case where it isn't.
+#[cfg(feature = "std")]
+static HAS_DEFAULT_LEAP_SECONDS: AtomicBool = AtomicBool::new(false);
+/// Whether the default leap seconds only account for the leap seconds announced by the IERS, cf. `LeapSecondProvider::iers_only`.
+#[cfg(feature = "std")]
+static DEFAULT_IERS_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// Replaces the built-in leap seconds (`LatestLeapSeconds`) with those of the provided provider for all the conversions of this process,
+/// e.g. to use a `LeapSecondsFile` which includes a newly announced leap second. The conversions also follow `LeapSecondProvider::iers_only`
+/// of this provider, e.g. `set_default_provider(&IersOnly(LatestLeapSeconds))` keeps UTC equal to TAI before 1972.
+#[cfg(feature = "std")]
+pub fn set_default_provider<L: LeapSecondProvider + ?Sized>(provider: &L) {
+    *DEFAULT_LEAP_SECONDS_EXPIRES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = provider.expires();
+    DEFAULT_IERS_ONLY.store(provider.iers_only(), Ordering::Release);
+    *DEFAULT_LEAP_SECONDS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = Some(provider.leap_seconds().to_vec());
+    HAS_DEFAULT_LEAP_SECONDS.store(true, Ordering::Release);
+}
+
+/// Restores the built-in leap seconds (`LatestLeapSeconds`) for all the conversions of this process.
+#[cfg(feature = "std")]
+pub fn reset_default_provider() {
+    HAS_DEFAULT_LEAP_SECONDS.store(false, Ordering::Release);
+    DEFAULT_IERS_ONLY.store(false, Ordering::Release);
+    *DEFAULT_LEAP_SECONDS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = None;
+    *DEFAULT_LEAP_SECONDS_EXPIRES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Returns the expiration date of the leap seconds set with `set_default_provider`, if known,
+/// e.g. to detect that the conversions of this process rely on a stale leap seconds file.
+#[cfg(feature = "std")]
+pub fn default_provider_expires() -> Option<Epoch> {
+    *DEFAULT_LEAP_SECONDS_EXPIRES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns whether the default leap seconds only account for the leap seconds announced by the IERS, cf. `set_default_provider`.
+pub(crate) fn default_iers_only() -> bool {
+    #[cfg(feature = "std")]
+    return DEFAULT_IERS_ONLY.load(Ordering::Acquire);
+    #[cfg(not(feature = "std"))]
+    false
+}
+
+/// Returns None if no default leap seconds were set for this process, and otherwise ΔAT from these default leap seconds,
+/// cf. `delta_at_in` for the arguments.
+#[cfg(feature = "std")]
+pub(crate) fn default_delta_at(
+    duration: Duration,
+    time_scale: TimeScale,
+    iers_only: bool,
+) -> Option<Option<f64>> {
+    if !HAS_DEFAULT_LEAP_SECONDS.load(Ordering::Acquire) {
+        return None;
+    }
+    let leap_seconds = DEFAULT_LEAP_SECONDS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+    leap_seconds
+        .as_ref()
+        .map(|leap_seconds| delta_at_in(leap_seconds, duration, time_scale, iers_only))
+}
+
+/// Calls `f` with the UTC interval (start inclusive, end exclusive, as UTC durations past J1900) which was skipped by each negative
+/// leap second announced by the IERS in the default leap seconds, i.e. those set with `set_default_provider` or `LatestLeapSeconds`.
+pub(crate) fn for_each_default_skipped_interval<F: FnMut(Duration, Duration)>(f: F) {
+    #[cfg(feature = "std")]
+    if HAS_DEFAULT_LEAP_SECONDS.load(Ordering::Acquire) {
+        let leap_seconds = DEFAULT_LEAP_SECONDS
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(leap_seconds) = leap_seconds.as_ref() {
+            return for_each_skipped_interval(leap_seconds, f);
+        }
+    }
+    for_each_skipped_interval(&LATEST_LEAP_SECONDS, f)
+}
+
+fn for_each_skipped_interval<F: FnMut(Duration, Duration)>(leap_seconds: &[LeapSecond], mut f: F) {
+    let mut prev: Option<&LeapSecond> = None;
+    for leap_second in leap_seconds.iter().filter(|ls| ls.announced_by_iers) {
+        if let Some(prev) = prev {
+            if leap_second.delta_at < prev.delta_at {
+                let end = leap_second.timestamp_utc_s.seconds();
+                f(end - (prev.delta_at - leap_second.delta_at).seconds(), end);
+            }
+        }
+        prev = Some(leap_second);
+    }
+}
 
 /// A structure representing a leap second
 #[cfg_attr(kani, derive(kani::Arbitrary))]
 #[repr(C)]
 #[cfg_attr(feature = "python", pyclass)]
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct LeapSecond {
-    /// Timestamp in TAI seconds for this leap second, e.g. `2_272_060_800.0` for the first IERS leap second.
-    pub timestamp_tai_s: f64,
+    /// Timestamp in whole **UTC** seconds past J1900 at which this leap second takes effect, i.e. midnight UTC of the day
+    /// following the leap second, e.g. `2_272_060_800` (1972-01-01T00:00:00 UTC) for the first IERS leap second.
+    /// These are the NTP timestamps of the IERS `leap-seconds.list`. The leap second takes effect in TAI about ΔAT seconds
+    /// later, cf. `Epoch::leap_seconds_with`.
+    pub timestamp_utc_s: i64,
     /// ΔAT is the accumulated time offset after this leap second has past.
     pub delta_at: f64,
     /// Whether or not this leap second was announced by the IERS.
     pub announced_by_iers: bool,
+    /// Reference UTC Modified Julian Date of the drift of ΔAT, only used for the UTC offsets from 1960 to 1972, cf. `with_drift`.
+    pub drift_ref_mjd: f64,
+    /// Drift of ΔAT in seconds per day of UTC, zero for the IERS leap seconds.
+    pub drift_s_per_day: f64,
 }
 
 impl LeapSecond {
-    pub const fn new(timestamp_tai_s: f64, delta_at: f64, announced: bool) -> Self {
+    /// Builds a leap second taking effect at the provided whole UTC seconds past J1900 (cf. `timestamp_utc_s`).
+    pub const fn new(timestamp_utc_s: i64, delta_at: f64, announced: bool) -> Self {
         Self {
-            timestamp_tai_s,
+            timestamp_utc_s,
             delta_at,
             announced_by_iers: announced,
+            drift_ref_mjd: 0.0,
+            drift_s_per_day: 0.0,
+        }
+    }
+
+    /// Builds one of the UTC offsets from 1960 to 1972, taking effect at the provided whole UTC seconds past J1900, where ΔAT = delta_at + (MJD - drift_ref_mjd) * drift_s_per_day
+    /// for the UTC Modified Julian Date MJD, as per the `iauDat` function of SOFA (src/dat.c). These were not announced by the IERS.
+    pub const fn with_drift(
+        timestamp_utc_s: i64,
+        delta_at: f64,
+        drift_ref_mjd: f64,
+        drift_s_per_day: f64,
+    ) -> Self {
+        Self {
+            timestamp_utc_s,
+            delta_at,
+            announced_by_iers: false,
+            drift_ref_mjd,
+            drift_s_per_day,
+        }
+    }
+
+    /// Returns ΔAT at the provided number of UTC seconds past J1900, accounting for the drift of the pre-1972 offsets.
+    pub fn delta_at_utc(&self, utc_s: f64) -> f64 {
+        if self.drift_s_per_day == 0.0 {
+            self.delta_at
+        } else {
+            let mjd_utc = utc_s / SECONDS_PER_DAY + MJD_J1900;
+            self.delta_at + (mjd_utc - self.drift_ref_mjd) * self.drift_s_per_day
+        }
+    }
+
+    /// Returns ΔAT at the provided number of TAI seconds past J1900, accounting for the drift of the pre-1972 offsets.
+    pub fn delta_at_tai(&self, tai_s: f64) -> f64 {
+        if self.drift_s_per_day == 0.0 {
+            self.delta_at
+        } else {
+            // The drift is defined in UTC, where UTC = TAI - ΔAT, so solve
+            // ΔAT = delta_at + (MJD_TAI - ΔAT / 86400 - drift_ref_mjd) * drift for ΔAT.
+            let mjd_tai = tai_s / SECONDS_PER_DAY + MJD_J1900;
+            (self.delta_at + (mjd_tai - self.drift_ref_mjd) * self.drift_s_per_day)
+                / (1.0 + self.drift_s_per_day / SECONDS_PER_DAY)
+        }
+    }
+}
+
+/// Returns the latest of the provided leap seconds, sorted by increasing timestamp, at or before the provided UTC duration past J1900.
+/// If `iers_only` is set, the leap seconds not announced by the IERS are skipped: as these precede the announced ones, this only
+/// walks back over the pre-1972 offsets.
+pub(crate) fn latest_leap_second_utc(
+    leap_seconds: &[LeapSecond],
+    utc: Duration,
+    iers_only: bool,
+) -> Option<&LeapSecond> {
+    // Leap seconds occur on whole seconds, so comparing the whole seconds of this duration is exact.
+    // The nanoseconds of a duration are always positive, so this is the floor even before J1900.
+    let (centuries, nanoseconds) = utc.to_parts();
+    let utc_s = i64::from(centuries) * SECONDS_PER_CENTURY_I64
+        + (nanoseconds / NANOSECONDS_PER_SECOND) as i64;
+    let count = leap_seconds.partition_point(|leap_second| leap_second.timestamp_utc_s <= utc_s);
+
+    leap_seconds[..count]
+        .iter()
+        .rev()
+        .find(|leap_second| !iers_only || leap_second.announced_by_iers)
+}
+
+/// Returns the latest of the provided leap seconds, sorted by increasing timestamp, in effect at the provided TAI duration past J1900.
+///
+/// The timestamps of the leap seconds are in UTC, so a change of ΔAT takes effect in TAI once both the previous and the new ΔAT
+/// have elapsed past that timestamp: the inserted second of a positive leap second (23:59:60) belongs to the new ΔAT, and
+/// the TAI second following 23:59:58 of a negative leap second is already midnight.
+pub(crate) fn latest_leap_second_tai(
+    leap_seconds: &[LeapSecond],
+    tai: Duration,
+    iers_only: bool,
+) -> Option<&LeapSecond> {
+    // As ΔAT is positive, the leap second in effect is at most the latest one whose UTC timestamp is before this TAI duration.
+    let mut candidates = latest_leap_second_utc(leap_seconds, tai, iers_only)
+        .map(|latest| {
+            let count = leap_seconds.partition_point(|leap_second| {
+                leap_second.timestamp_utc_s <= latest.timestamp_utc_s
+            });
+            leap_seconds[..count]
+                .iter()
+                .rev()
+                .filter(|leap_second| !iers_only || leap_second.announced_by_iers)
+        })?
+        .peekable();
+
+    // Walking back is bounded: leap seconds are months apart, whereas ΔAT is less than a minute.
+    while let Some(leap_second) = candidates.next() {
+        let timestamp_s = leap_second.timestamp_utc_s as f64;
+        let prev_delta_at = candidates
+            .peek()
+            .map_or(0.0, |prev| prev.delta_at_utc(timestamp_s));
+        let takes_effect = leap_second.timestamp_utc_s.seconds()
+            + prev_delta_at
+                .min(leap_second.delta_at_utc(timestamp_s))
+                .seconds();
+        if tai >= takes_effect {
+            return Some(leap_second);
         }
     }
+
+    None
+}
+
+/// Returns ΔAT as a duration rounded to the nanosecond, zero if None: the drift of the pre-1972 offsets is only defined to the
+/// microsecond, so truncating its floating point value would put 1970-01-01T00:00:00 UTC at 08.000081999 TAI instead of 08.000082.
+pub(crate) fn delta_at_duration(delta_at: Option<f64>) -> Duration {
+    delta_at.map_or(Duration::ZERO, |delta_at| {
+        // Casting truncates towards zero, so this rounds half away from zero (without `f64::round`, which requires `std`).
+        let nanoseconds = delta_at * 1e9;
+        let half = if nanoseconds < 0.0 { -0.5 } else { 0.5 };
+        Duration::from_total_nanoseconds((nanoseconds + half) as i128)
+    })
 }
 
-const LATEST_LEAP_SECONDS: [LeapSecond; 42] = [
-    LeapSecond::new(1_893_369_600.0, 1.417818, false), // SOFA: 01 Jan 1960
-    LeapSecond::new(1_924_992_000.0, 1.422818, false), // SOFA: 01 Jan 1961
-    LeapSecond::new(1_943_308_800.0, 1.372818, false), // SOFA: 01 Aug 1961
-    LeapSecond::new(1_956_528_000.0, 1.845858, false), // SOFA: 01 Jan 1962
-    LeapSecond::new(2_014_329_600.0, 1.945858, false), // SOFA: 01 Jan 1963
-    LeapSecond::new(2_019_600_000.0, 3.24013, false),  // SOFA: 01 Jan 1964
-    LeapSecond::new(2_027_462_400.0, 3.34013, false),  // SOFA: 01 Apr 1964
-    LeapSecond::new(2_040_681_600.0, 3.44013, false),  // SOFA: 01 Sep 1964
-    LeapSecond::new(2_051_222_400.0, 3.54013, false),  // SOFA: 01 Jan 1965
-    LeapSecond::new(2_056_320_000.0, 3.64013, false),  // SOFA: 01 Mar 1965
-    LeapSecond::new(2_066_860_800.0, 3.74013, false),  // SOFA: 01 Jul 1965
-    LeapSecond::new(2_072_217_600.0, 3.84013, false),  // SOFA: 01 Sep 1965
-    LeapSecond::new(2_082_758_400.0, 4.31317, false),  // SOFA: 01 Jan 1966
-    LeapSecond::new(2_148_508_800.0, 4.21317, false),  // SOFA: 01 Feb 1968
-    LeapSecond::new(2_272_060_800.0, 10.0, true),      // IERS: 01 Jan 1972
-    LeapSecond::new(2_287_785_600.0, 11.0, true),      // IERS: 01 Jul 1972
-    LeapSecond::new(2_303_683_200.0, 12.0, true),      // IERS: 01 Jan 1973
-    LeapSecond::new(2_335_219_200.0, 13.0, true),      // IERS: 01 Jan 1974
-    LeapSecond::new(2_366_755_200.0, 14.0, true),      // IERS: 01 Jan 1975
-    LeapSecond::new(2_398_291_200.0, 15.0, true),      // IERS: 01 Jan 1976
-    LeapSecond::new(2_429_913_600.0, 16.0, true),      // IERS: 01 Jan 1977
-    LeapSecond::new(2_461_449_600.0, 17.0, true),      // IERS: 01 Jan 1978
-    LeapSecond::new(2_492_985_600.0, 18.0, true),      // IERS: 01 Jan 1979
-    LeapSecond::new(2_524_521_600.0, 19.0, true),      // IERS: 01 Jan 1980
-    LeapSecond::new(2_571_782_400.0, 20.0, true),      // IERS: 01 Jul 1981
-    LeapSecond::new(2_603_318_400.0, 21.0, true),      // IERS: 01 Jul 1982
-    LeapSecond::new(2_634_854_400.0, 22.0, true),      // IERS: 01 Jul 1983
-    LeapSecond::new(2_698_012_800.0, 23.0, true),      // IERS: 01 Jul 1985
-    LeapSecond::new(2_776_982_400.0, 24.0, true),      // IERS: 01 Jan 1988
-    LeapSecond::new(2_840_140_800.0, 25.0, true),      // IERS: 01 Jan 1990
-    LeapSecond::new(2_871_676_800.0, 26.0, true),      // IERS: 01 Jan 1991
-    LeapSecond::new(2_918_937_600.0, 27.0, true),      // IERS: 01 Jul 1992
-    LeapSecond::new(2_950_473_600.0, 28.0, true),      // IERS: 01 Jul 1993
-    LeapSecond::new(2_982_009_600.0, 29.0, true),      // IERS: 01 Jul 1994
-    LeapSecond::new(3_029_443_200.0, 30.0, true),      // IERS: 01 Jan 1996
-    LeapSecond::new(3_076_704_000.0, 31.0, true),      // IERS: 01 Jul 1997
-    LeapSecond::new(3_124_137_600.0, 32.0, true),      // IERS: 01 Jan 1999
-    LeapSecond::new(3_345_062_400.0, 33.0, true),      // IERS: 01 Jan 2006
-    LeapSecond::new(3_439_756_800.0, 34.0, true),      // IERS: 01 Jan 2009
-    LeapSecond::new(3_550_089_600.0, 35.0, true),      // IERS: 01 Jul 2012
-    LeapSecond::new(3_644_697_600.0, 36.0, true),      // IERS: 01 Jul 2015
-    LeapSecond::new(3_692_217_600.0, 37.0, true),      // IERS: 01 Jan 2017
+/// Returns ΔAT from the provided leap seconds, sorted by increasing timestamp, at the provided duration past J1900 (None if before the first leap second).
+/// The duration is either in UTC or in TAI, as per `time_scale`: the drift of the pre-1972 offsets is then evaluated at that UTC date, or from that TAI date.
+pub(crate) fn delta_at_in(
+    leap_seconds: &[LeapSecond],
+    duration: Duration,
+    time_scale: TimeScale,
+    iers_only: bool,
+) -> Option<f64> {
+    if time_scale == TimeScale::UTC {
+        latest_leap_second_utc(leap_seconds, duration, iers_only)
+            .map(|leap_second| leap_second.delta_at_utc(duration.to_seconds()))
+    } else {
+        latest_leap_second_tai(leap_seconds, duration, iers_only)
+            .map(|leap_second| leap_second.delta_at_tai(duration.to_seconds()))
+    }
+}
+
+pub(crate) const LATEST_LEAP_SECONDS: [LeapSecond; 42] = [
+    LeapSecond::with_drift(1_893_369_600, 1.417818, 37300.0, 0.0012960), // SOFA: 01 Jan 1960
+    LeapSecond::with_drift(1_924_992_000, 1.422818, 37300.0, 0.0012960), // SOFA: 01 Jan 1961
+    LeapSecond::with_drift(1_943_308_800, 1.372818, 37300.0, 0.0012960), // SOFA: 01 Aug 1961
+    LeapSecond::with_drift(1_956_528_000, 1.845858, 37665.0, 0.0011232), // SOFA: 01 Jan 1962
+    LeapSecond::with_drift(2_014_329_600, 1.945858, 37665.0, 0.0011232), // SOFA: 01 Nov 1963
+    LeapSecond::with_drift(2_019_600_000, 3.24013, 38761.0, 0.0012960),  // SOFA: 01 Jan 1964
+    LeapSecond::with_drift(2_027_462_400, 3.34013, 38761.0, 0.0012960),  // SOFA: 01 Apr 1964
+    LeapSecond::with_drift(2_040_681_600, 3.44013, 38761.0, 0.0012960),  // SOFA: 01 Sep 1964
+    LeapSecond::with_drift(2_051_222_400, 3.54013, 38761.0, 0.0012960),  // SOFA: 01 Jan 1965
+    LeapSecond::with_drift(2_056_320_000, 3.64013, 38761.0, 0.0012960),  // SOFA: 01 Mar 1965
+    LeapSecond::with_drift(2_066_860_800, 3.74013, 38761.0, 0.0012960),  // SOFA: 01 Jul 1965
+    LeapSecond::with_drift(2_072_217_600, 3.84013, 38761.0, 0.0012960),  // SOFA: 01 Sep 1965
+    LeapSecond::with_drift(2_082_758_400, 4.31317, 39126.0, 0.0025920),  // SOFA: 01 Jan 1966
+    LeapSecond::with_drift(2_148_508_800, 4.21317, 39126.0, 0.0025920),  // SOFA: 01 Feb 1968
+    LeapSecond::new(2_272_060_800, 10.0, true),                          // IERS: 01 Jan 1972
+    LeapSecond::new(2_287_785_600, 11.0, true),                          // IERS: 01 Jul 1972
+    LeapSecond::new(2_303_683_200, 12.0, true),                          // IERS: 01 Jan 1973
+    LeapSecond::new(2_335_219_200, 13.0, true),                          // IERS: 01 Jan 1974
+    LeapSecond::new(2_366_755_200, 14.0, true),                          // IERS: 01 Jan 1975
+    LeapSecond::new(2_398_291_200, 15.0, true),                          // IERS: 01 Jan 1976
+    LeapSecond::new(2_429_913_600, 16.0, true),                          // IERS: 01 Jan 1977
+    LeapSecond::new(2_461_449_600, 17.0, true),                          // IERS: 01 Jan 1978
+    LeapSecond::new(2_492_985_600, 18.0, true),                          // IERS: 01 Jan 1979
+    LeapSecond::new(2_524_521_600, 19.0, true),                          // IERS: 01 Jan 1980
+    LeapSecond::new(2_571_782_400, 20.0, true),                          // IERS: 01 Jul 1981
+    LeapSecond::new(2_603_318_400, 21.0, true),                          // IERS: 01 Jul 1982
+    LeapSecond::new(2_634_854_400, 22.0, true),                          // IERS: 01 Jul 1983
+    LeapSecond::new(2_698_012_800, 23.0, true),                          // IERS: 01 Jul 1985
+    LeapSecond::new(2_776_982_400, 24.0, true),                          // IERS: 01 Jan 1988
+    LeapSecond::new(2_840_140_800, 25.0, true),                          // IERS: 01 Jan 1990
+    LeapSecond::new(2_871_676_800, 26.0, true),                          // IERS: 01 Jan 1991
+    LeapSecond::new(2_918_937_600, 27.0, true),                          // IERS: 01 Jul 1992
+    LeapSecond::new(2_950_473_600, 28.0, true),                          // IERS: 01 Jul 1993
+    LeapSecond::new(2_982_009_600, 29.0, true),                          // IERS: 01 Jul 1994
+    LeapSecond::new(3_029_443_200, 30.0, true),                          // IERS: 01 Jan 1996
+    LeapSecond::new(3_076_704_000, 31.0, true),                          // IERS: 01 Jul 1997
+    LeapSecond::new(3_124_137_600, 32.0, true),                          // IERS: 01 Jan 1999
+    LeapSecond::new(3_345_062_400, 33.0, true),                          // IERS: 01 Jan 2006
+    LeapSecond::new(3_439_756_800, 34.0, true),                          // IERS: 01 Jan 2009
+    LeapSecond::new(3_550_089_600, 35.0, true),                          // IERS: 01 Jul 2012
+    LeapSecond::new(3_644_697_600, 36.0, true),                          // IERS: 01 Jul 2015
+    LeapSecond::new(3_692_217_600, 37.0, true),                          // IERS: 01 Jan 2017
 ];
 
 /// List of leap seconds from https://www.ietf.org/timezones/data/leap-seconds.list .
@@ -92,18 +391,15 @@ const LATEST_LEAP_SECONDS: [LeapSecond; 42] = [
 /// The unannoucned leap seconds come from dat.c in the SOFA library.
 #[cfg_attr(feature = "python", pyclass)]
 #[cfg_attr(kani, derive(kani::Arbitrary))]
-#[derive(Clone, Debug)]
-pub struct LatestLeapSeconds {
-    data: [LeapSecond; 42],
-    iter_pos: usize,
-}
+#[derive(Copy, Clone, Debug, Default)]
+pub struct LatestLeapSeconds;
 
 #[cfg(feature = "python")]
 #[cfg_attr(feature = "python", pymethods)]
 impl LatestLeapSeconds {
     #[new]
     pub fn __new__() -> Self {
-        Self::default()
+        Self
     }
 
     fn __repr__(&self) -> String {
@@ -111,55 +407,112 @@ impl LatestLeapSeconds {
     }
 }
 
-impl Default for LatestLeapSeconds {
-    fn default() -> Self {
-        Self {
-            data: LATEST_LEAP_SECONDS,
-            iter_pos: 0,
-        }
+impl Index<usize> for LatestLeapSeconds {
+    type Output = LeapSecond;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        LATEST_LEAP_SECONDS.index(index)
     }
 }
 
-impl Iterator for LatestLeapSeconds {
-    type Item = LeapSecond;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.iter_pos += 1;
-        self.data.get(self.iter_pos - 1).copied()
+impl LeapSecondProvider for LatestLeapSeconds {
+    fn leap_seconds(&self) -> &[LeapSecond] {
+        &LATEST_LEAP_SECONDS
     }
 }
 
-impl DoubleEndedIterator for LatestLeapSeconds {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.iter_pos == self.data.len() {
-            None
-        } else {
-            self.iter_pos += 1;
-            self.data.get(self.data.len() - self.iter_pos).copied()
-        }
+/// A leap second provider which only accounts for the leap seconds announced by the IERS, such that UTC equals TAI before 1972,
+/// as in SPICE and in hifitime 4.0, cf. `LeapSecondProvider::iers_only`.
+///
+/// # Example
+/// ```
+/// use hifitime::prelude::*;
+/// use hifitime::leap_seconds::{IersOnly, LatestLeapSeconds};
+///
+/// let epoch = Epoch::from_gregorian_utc_at_midnight(1970, 1, 1);
+/// // UTC was 8.000082 seconds behind TAI...
+/// let tai = epoch.to_time_scale(TimeScale::TAI);
+/// assert_eq!(tai.duration - epoch.duration, 8.000_082.seconds());
+/// // ... unless only the leap seconds announced by the IERS are accounted for.
+/// let tai = epoch.to_time_scale_with(TimeScale::TAI, &IersOnly(LatestLeapSeconds));
+/// assert_eq!(tai.duration, epoch.duration);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct IersOnly<L>(pub L);
+
+impl<L: LeapSecondProvider> LeapSecondProvider for IersOnly<L> {
+    fn leap_seconds(&self) -> &[LeapSecond] {
+        self.0.leap_seconds()
     }
-}
 
-impl Index<usize> for LatestLeapSeconds {
-    type Output = LeapSecond;
+    fn expires(&self) -> Option<Epoch> {
+        self.0.expires()
+    }
 
-    fn index(&self, index: usize) -> &Self::Output {
-        self.data.index(index)
+    fn iers_only(&self) -> bool {
+        true
     }
 }
 
-impl LeapSecondProvider for LatestLeapSeconds {}
+/// Iterates over the leap seconds in increasing order, as `LatestLeapSeconds` used to be an iterator itself.
+impl IntoIterator for LatestLeapSeconds {
+    type Item = LeapSecond;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'static, LeapSecond>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LATEST_LEAP_SECONDS.iter().copied()
+    }
+}
 
 #[test]
 fn leap_second_fetch() {
-    let leap_seconds = LatestLeapSeconds::default();
+    let leap_seconds = LatestLeapSeconds;
 
     assert_eq!(
         leap_seconds[0],
-        LeapSecond::new(1_893_369_600.0, 1.417818, false),
+        LeapSecond::with_drift(1_893_369_600, 1.417818, 37_300.0, 0.001296),
+    );
+    assert_eq!(leap_seconds[41], LeapSecond::new(3_692_217_600, 37.0, true));
+    assert_eq!(leap_seconds.into_iter().count(), 42);
+    assert_eq!(leap_seconds.into_iter().last(), Some(leap_seconds[41]));
+}
+
+#[test]
+fn leap_second_binary_search() {
+    let leap_seconds = LatestLeapSeconds;
+
+    // Before the first UTC offset.
+    assert_eq!(
+        leap_seconds.leap_second_at_utc(1_893_369_599.seconds(), false),
+        None
+    );
+    // Exactly on a leap second in UTC, and one nanosecond before it.
+    assert_eq!(
+        leap_seconds.leap_second_at_utc(3_692_217_600.seconds(), true),
+        Some(&leap_seconds[41])
+    );
+    assert_eq!(
+        leap_seconds.leap_second_at_utc(3_692_217_600.seconds() - 1.nanoseconds(), true),
+        Some(&leap_seconds[40])
+    );
+    // In TAI, the inserted second 2016-12-31T23:59:60 UTC starts 36 seconds after that UTC timestamp.
+    assert_eq!(
+        leap_seconds.leap_second_at(3_692_217_636.seconds(), true),
+        Some(&leap_seconds[41])
+    );
+    assert_eq!(
+        leap_seconds.leap_second_at(3_692_217_636.seconds() - 1.nanoseconds(), true),
+        Some(&leap_seconds[40])
+    );
+    // The pre-1972 offsets are skipped when only considering the IERS leap seconds.
+    assert_eq!(
+        leap_seconds.leap_second_at(2_100_000_000.seconds(), false),
+        Some(&leap_seconds[12])
     );
     assert_eq!(
-        leap_seconds[41],
-        LeapSecond::new(3_692_217_600.0, 37.0, true)
+        leap_seconds.leap_second_at(2_100_000_000.seconds(), true),
+        None
     );
+    // Negative durations, i.e. before J1900, floor to the previous whole second.
+    assert_eq!(leap_seconds.leap_second_at(-1.nanoseconds(), false), None);
 }
@@ -11,13 +11,18 @@
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
-use std::{fs::File, io::Read, path::Path};
+use std::{
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
 
 use core::ops::Index;
+use core::str::FromStr;
 
 use crate::{
-    leap_seconds::{LeapSecond, LeapSecondProvider},
-    HifitimeError, ParsingError,
+    leap_seconds::{LatestLeapSeconds, LeapSecond, LeapSecondProvider},
+    Epoch, HifitimeError, MonthName, ParsingError, TimeUnits,
 };
 
 #[repr(C)]
@@ -25,84 +30,426 @@ use crate::{
 #[derive(Clone, Debug, Default)]
 /// A leap second provider that uses an IERS formatted leap seconds file.
 ///
+/// The expiration date (`#@` line) and the last update (`#$` line) of the file are available with `expires` and `last_update`.
+/// With the `leap-seconds-hash` feature, if the file includes its SHA-1 hash (`#h` line), the data is verified against it
+/// and corrupted files are rejected.
+///
 /// (Python documentation hints)
 /// :type path: str
 /// :rtype: LeapSecondsFile
 pub struct LeapSecondsFile {
     data: Vec<LeapSecond>,
-    iter_pos: usize,
+    expires: Option<Epoch>,
+    last_update: Option<Epoch>,
 }
 
 impl LeapSecondsFile {
     /// Builds a leap second provider from the provided Leap Seconds file in IERS format as found on <https://www.ietf.org/timezones/data/leap-seconds.list> .
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, HifitimeError> {
-        let mut f = match File::open(path) {
-            Ok(f) => f,
-            Err(e) => {
-                return Err(HifitimeError::Parse {
-                    source: ParsingError::InOut { err: e.kind() },
-                    details: "opening leap seconds file",
-                })
+        Self::from_str(&read_file(path)?)
+    }
+
+    /// Builds a leap second provider from the provided tzdata `leapseconds` file, e.g. `/usr/share/zoneinfo/leapseconds`.
+    pub fn from_tzdata_path<P: AsRef<Path>>(path: P) -> Result<Self, HifitimeError> {
+        Self::from_tzdata_str(&read_file(path)?)
+    }
+
+    /// Builds a leap second provider from the provided IERS Bulletin C, e.g. <https://hpiers.obspm.fr/iers/bul/bulc/bulletinc.dat> .
+    pub fn from_bulletin_c_path<P: AsRef<Path>>(path: P) -> Result<Self, HifitimeError> {
+        Self::from_bulletin_c_str(&read_file(path)?)
+    }
+
+    /// Parses the contents of a tzdata `leapseconds` file, made of `Leap YEAR MON DAY hh:mm:ss +/- S` lines.
+    ///
+    /// The expiration date is read from the `Expires` line (commented out or not) or from the `#expires` line, and the
+    /// last update from the `#updated` line.
+    pub fn from_tzdata_str(s: &str) -> Result<Self, HifitimeError> {
+        let invalid = |details| HifitimeError::Parse {
+            source: ParsingError::UnknownFormat,
+            details,
+        };
+
+        // The tzdata files do not list the start of the current definition of UTC.
+        let mut me = Self::default();
+        me.data
+            .push(LeapSecond::new(FIRST_IERS_LEAP_SECOND_S, 10.0, true));
+
+        for line in s.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["Leap", year, month, day, time, correction, ..] => {
+                    let details = "invalid Leap line in tzdata leap seconds file";
+                    let midnight = parse_utc_date(year, month, day).ok_or(invalid(details))?;
+                    let time = parse_time_of_day(time).ok_or(invalid(details))?;
+                    let prev_delta_at = me.data.last().map_or(10.0, |last| last.delta_at);
+                    // The correction is made at the given time: a positive leap second is 23:59:60,
+                    // whereas a negative one skips 23:59:59, so both take effect at midnight.
+                    let (timestamp_s, delta_at) = match *correction {
+                        "+" => (midnight + time, prev_delta_at + 1.0),
+                        "-" => (midnight + time + 1, prev_delta_at - 1.0),
+                        _ => return Err(invalid(details)),
+                    };
+                    me.data.push(LeapSecond::new(timestamp_s, delta_at, true));
+                }
+                ["Expires" | "#Expires", year, month, day, time, ..] => {
+                    let details = "invalid Expires line in tzdata leap seconds file";
+                    let midnight = parse_utc_date(year, month, day).ok_or(invalid(details))?;
+                    let time = parse_time_of_day(time).ok_or(invalid(details))?;
+                    me.expires = Some(Epoch::from_utc_duration((midnight + time).seconds()));
+                }
+                ["#expires", unix_s, ..] => {
+                    let unix_s: i64 = lexical_core::parse(unix_s.as_bytes()).map_err(|_| {
+                        invalid("invalid #expires line in tzdata leap seconds file")
+                    })?;
+                    me.expires = Some(Epoch::from_unix_seconds(unix_s as f64));
+                }
+                ["#updated", unix_s, ..] => {
+                    let unix_s: i64 = lexical_core::parse(unix_s.as_bytes()).map_err(|_| {
+                        invalid("invalid #updated line in tzdata leap seconds file")
+                    })?;
+                    me.last_update = Some(Epoch::from_unix_seconds(unix_s as f64));
+                }
+                _ => continue,
             }
+        }
+
+        Ok(me)
+    }
+
+    /// Parses the contents of an IERS Bulletin C, i.e. its `from YEAR MONTH DAY, 0h UTC, ... : UTC-TAI = -S s` lines.
+    ///
+    /// As a bulletin only specifies the current (and possibly the next) offset, the leap seconds prior to those of the bulletin
+    /// are those of `LatestLeapSeconds`. The bulletin announces whether a leap second will be introduced at the end of a month,
+    /// and the next one at the earliest six months later: like the IERS leap seconds file, this provider expires on the 28th of that month.
+    pub fn from_bulletin_c_str(s: &str) -> Result<Self, HifitimeError> {
+        let invalid = || HifitimeError::Parse {
+            source: ParsingError::UnknownFormat,
+            details: "invalid UTC-TAI line in Bulletin C",
         };
 
-        let mut contents = String::new();
-        if let Err(e) = f.read_to_string(&mut contents) {
+        let mut bulletin_data = Vec::new();
+        for line in s.lines().filter(|line| line.contains("UTC-TAI")) {
+            let (dates, offset) = line.split_once('=').ok_or_else(invalid)?;
+            let mut tokens = dates
+                .split_whitespace()
+                .map(|token| token.trim_end_matches(','))
+                .skip_while(|token| *token != "from")
+                .skip(1);
+            let (Some(year), Some(month), Some(day)) =
+                (tokens.next(), tokens.next(), tokens.next())
+            else {
+                continue;
+            };
+            let timestamp_s = parse_utc_date(year, month, day).ok_or_else(invalid)?;
+
+            // UTC-TAI is written as e.g. "-37 s" or "- 36s".
+            let offset: String = offset
+                .chars()
+                .filter(|c| !c.is_whitespace() && *c != 's')
+                .collect();
+            let utc_tai: f64 = offset.parse().map_err(|_| invalid())?;
+
+            bulletin_data.push(LeapSecond::new(timestamp_s, -utc_tai, true));
+        }
+
+        let Some(first) = bulletin_data.first() else {
             return Err(HifitimeError::Parse {
-                source: ParsingError::InOut { err: e.kind() },
-                details: "reading leap seconds file",
+                source: ParsingError::UnknownFormat,
+                details: "no UTC-TAI line in Bulletin C",
             });
+        };
+
+        let mut me = Self {
+            data: LatestLeapSeconds
+                .leap_seconds()
+                .iter()
+                .take_while(|leap_second| leap_second.timestamp_utc_s < first.timestamp_utc_s)
+                .copied()
+                .collect(),
+            ..Default::default()
+        };
+        me.data.extend(bulletin_data);
+
+        // Find "at the end of MONTH YEAR", which is when the bulletin announces whether there will be a leap second.
+        let tokens: Vec<&str> = s
+            .split_whitespace()
+            .map(|token| token.trim_end_matches(['.', ',']))
+            .collect();
+        me.expires = tokens.windows(4).find_map(|window| match window {
+            ["end", "of", month, year] => {
+                let month = MonthName::from_str(month).ok()? as u8 + 1;
+                let year: i32 = year.parse().ok()?;
+                let (year, month) = if month > 6 {
+                    (year + 1, month - 6)
+                } else {
+                    (year, month + 6)
+                };
+                Epoch::maybe_from_gregorian_utc(year, month, 28, 0, 0, 0, 0).ok()
+            }
+            _ => None,
+        });
+
+        Ok(me)
+    }
+
+    /// Looks for a leap seconds file in the usual system locations, i.e. the `TZDIR` directory and the zoneinfo directories, first
+    /// for an IERS `leap-seconds.list` file and then for a tzdata `leapseconds` file.
+    ///
+    /// Files which cannot be parsed (or are corrupted) and files which do not include the latest built-in leap second are skipped.
+    /// If no such file is found, this falls back to `LatestLeapSeconds`.
+    pub fn discover() -> Self {
+        let dirs = std::env::var_os("TZDIR")
+            .map(PathBuf::from)
+            .into_iter()
+            .chain(SYSTEM_ZONEINFO_DIRS.iter().map(PathBuf::from));
+        Self::discover_in(dirs)
+    }
+
+    /// Looks for a leap seconds file in the provided directories, in order, like `discover` does in the system locations.
+    pub fn discover_in<I, P>(dirs: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let latest_s = LatestLeapSeconds
+            .leap_seconds()
+            .last()
+            .map(|leap_second| leap_second.timestamp_utc_s);
+        let is_up_to_date = |me: &Self| {
+            me.data
+                .last()
+                .map(|leap_second| leap_second.timestamp_utc_s)
+                >= latest_s
+        };
+
+        for dir in dirs {
+            let dir = dir.as_ref();
+            if let Ok(me) = Self::from_path(dir.join("leap-seconds.list")) {
+                if is_up_to_date(&me) {
+                    return me;
+                }
+            }
+            if let Ok(me) = Self::from_tzdata_path(dir.join("leapseconds")) {
+                if is_up_to_date(&me) {
+                    return me;
+                }
+            }
         }
 
+        Self::from(LatestLeapSeconds)
+    }
+
+    /// Returns the date of the last update of the leap seconds in this file (`#$` line), if specified.
+    pub fn last_update(&self) -> Option<Epoch> {
+        self.last_update
+    }
+}
+
+impl From<LatestLeapSeconds> for LeapSecondsFile {
+    fn from(latest: LatestLeapSeconds) -> Self {
+        Self {
+            data: latest.leap_seconds().to_vec(),
+            ..Default::default()
+        }
+    }
+}
+
+impl FromStr for LeapSecondsFile {
+    type Err = HifitimeError;
+
+    /// Parses the contents of a leap seconds file in IERS format, verifying its SHA-1 hash if the file includes one
+    /// and the `leap-seconds-hash` feature is enabled.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut me = Self::default();
+        // As per the file itself, the hash covers the data lines, the last update and the expiration date, without whitespace nor comments.
+        let mut hashed_data = String::new();
+        let mut expected_hash = None;
 
-        for line in contents.lines() {
-            if let Some(first_char) = line.chars().next() {
-                if first_char == '#' {
+        for line in s.lines() {
+            if let Some(timestamp) = line.strip_prefix("#$") {
+                let (timestamp, last_update) =
+                    parse_ntp_timestamp(timestamp, "invalid last update (#$) timestamp")?;
+                hashed_data.push_str(timestamp);
+                me.last_update = Some(last_update);
+            } else if let Some(timestamp) = line.strip_prefix("#@") {
+                let (timestamp, expires) =
+                    parse_ntp_timestamp(timestamp, "invalid expiration (#@) timestamp")?;
+                hashed_data.push_str(timestamp);
+                me.expires = Some(expires);
+            } else if let Some(hash) = line.strip_prefix("#h") {
+                expected_hash = Some(parse_hash(hash)?);
+            } else if line.starts_with('#') {
+                continue;
+            } else if let Some(data_line) = line.split('#').next() {
+                // We have data of interest!
+                let data: Vec<&str> = data_line.split_whitespace().collect();
+                if data.is_empty() {
                     continue;
-                } else {
-                    // We have data of interest!
-                    let data: Vec<&str> = line.split_whitespace().collect();
-                    if data.len() < 2 {
+                } else if data.len() < 2 {
+                    return Err(HifitimeError::Parse {
+                        source: ParsingError::UnknownFormat,
+                        details: "leap seconds file should have two columns exactly",
+                    });
+                }
+
+                let timestamp_utc_s: u64 = match lexical_core::parse(data[0].as_bytes()) {
+                    Ok(val) => val,
+                    Err(_) => {
                         return Err(HifitimeError::Parse {
-                            source: ParsingError::UnknownFormat,
-                            details: "leap seconds file should have two columns exactly",
-                        });
+                            source: ParsingError::ValueError,
+                            details: "first column value is not numeric",
+                        })
                     }
+                };
 
-                    let timestamp_tai_s: u64 = match lexical_core::parse(data[0].as_bytes()) {
-                        Ok(val) => val,
-                        Err(_) => {
-                            return Err(HifitimeError::Parse {
-                                source: ParsingError::ValueError,
-                                details: "first column value is not numeric",
-                            })
-                        }
-                    };
+                // ΔAT may decrease with a negative leap second, and is signed in principle.
+                let delta_at: i16 = match lexical_core::parse(data[1].as_bytes()) {
+                    Ok(val) => val,
+                    Err(_) => {
+                        return Err(HifitimeError::Parse {
+                            source: ParsingError::ValueError,
+                            details: "second column value is not numeric",
+                        })
+                    }
+                };
 
-                    let delta_at: u8 = match lexical_core::parse(data[1].as_bytes()) {
-                        Ok(val) => val,
-                        Err(_) => {
-                            return Err(HifitimeError::Parse {
-                                source: ParsingError::ValueError,
-                                details: "second column value is not numeric",
-                            })
-                        }
-                    };
+                data.iter().for_each(|value| hashed_data.push_str(value));
 
-                    me.data.push(LeapSecond {
-                        timestamp_tai_s: (timestamp_tai_s as f64),
-                        delta_at: (delta_at as f64),
-                        announced_by_iers: true,
-                    });
-                }
+                me.data.push(LeapSecond::new(
+                    timestamp_utc_s as i64,
+                    f64::from(delta_at),
+                    true,
+                ));
             }
         }
 
+        // The lookups of the leap seconds are binary searches, which require them to be sorted.
+        me.data
+            .sort_by_key(|leap_second| leap_second.timestamp_utc_s);
+
+        if let Some(expected_hash) = expected_hash {
+            verify_hash(&hashed_data, expected_hash)?;
+        }
+
         Ok(me)
     }
 }
 
+/// Timestamp of the start of the current definition of UTC, 1972 January 01, with ΔAT = 10 s.
+const FIRST_IERS_LEAP_SECOND_S: i64 = 2_272_060_800;
+
+/// The usual locations of the time zone database, where system leap seconds files are found.
+const SYSTEM_ZONEINFO_DIRS: [&str; 3] = [
+    "/usr/share/zoneinfo",
+    "/usr/lib/zoneinfo",
+    "/usr/share/lib/zoneinfo",
+];
+
+/// Reads the provided leap seconds file.
+fn read_file<P: AsRef<Path>>(path: P) -> Result<String, HifitimeError> {
+    let mut f = match File::open(path) {
+        Ok(f) => f,
+        Err(e) => {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::InOut { err: e.kind() },
+                details: "opening leap seconds file",
+            })
+        }
+    };
+
+    let mut contents = String::new();
+    if let Err(e) = f.read_to_string(&mut contents) {
+        return Err(HifitimeError::Parse {
+            source: ParsingError::InOut { err: e.kind() },
+            details: "reading leap seconds file",
+        });
+    }
+
+    Ok(contents)
+}
+
+/// Returns the number of UTC seconds past J1900 at midnight of the provided date, where the month may be a name or a number.
+fn parse_utc_date(year: &str, month: &str, day: &str) -> Option<i64> {
+    let year: i32 = year.parse().ok()?;
+    let month = match month.parse::<u8>() {
+        Ok(month) => month,
+        Err(_) => MonthName::from_str(month).ok()? as u8 + 1,
+    };
+    let day: u8 = day.parse().ok()?;
+
+    Epoch::maybe_from_gregorian_utc(year, month, day, 0, 0, 0, 0)
+        .ok()
+        .map(|epoch| epoch.duration.to_seconds() as i64)
+}
+
+/// Returns the number of seconds of the provided `hh:mm:ss` time of day, where the seconds may be 60.
+fn parse_time_of_day(time: &str) -> Option<i64> {
+    let mut parts = time.split(':').map(|part| part.parse::<u8>().ok());
+    let (Some(Some(hours)), Some(Some(minutes)), Some(Some(seconds)), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return None;
+    };
+
+    Some(i64::from(hours) * 3600 + i64::from(minutes) * 60 + i64::from(seconds))
+}
+
+/// Parses an NTP timestamp, i.e. the number of seconds since 1900 January 01 at midnight UTC, and returns it as text and as a UTC epoch.
+fn parse_ntp_timestamp<'a>(
+    timestamp: &'a str,
+    details: &'static str,
+) -> Result<(&'a str, Epoch), HifitimeError> {
+    let timestamp = timestamp.trim();
+    match lexical_core::parse::<i64>(timestamp.as_bytes()) {
+        Ok(seconds) => Ok((timestamp, Epoch::from_utc_duration(seconds.seconds()))),
+        Err(_) => Err(HifitimeError::Parse {
+            source: ParsingError::ValueError,
+            details,
+        }),
+    }
+}
+
+/// Parses the SHA-1 hash of the `#h` line, written as five 32-bit words in hexadecimal without leading zeros.
+fn parse_hash(hash: &str) -> Result<[u8; 20], HifitimeError> {
+    let invalid = || HifitimeError::Parse {
+        source: ParsingError::ValueError,
+        details: "invalid hash (#h) line",
+    };
+
+    let mut bytes = [0; 20];
+    let mut words = hash.split_whitespace();
+    for chunk in bytes.chunks_exact_mut(4) {
+        let word = words.next().ok_or_else(invalid)?;
+        let word = u32::from_str_radix(word, 16).map_err(|_| invalid())?;
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+
+    if words.next().is_some() {
+        return Err(invalid());
+    }
+
+    Ok(bytes)
+}
+
+/// Verifies the SHA-1 hash of the data of a leap seconds file.
+#[cfg(feature = "leap-seconds-hash")]
+fn verify_hash(hashed_data: &str, expected_hash: [u8; 20]) -> Result<(), HifitimeError> {
+    if sha1_smol::Sha1::from(hashed_data).digest().bytes() != expected_hash {
+        return Err(HifitimeError::Parse {
+            source: ParsingError::HashMismatch,
+            details: "leap seconds file is corrupted",
+        });
+    }
+
+    Ok(())
+}
+
+/// Without the `leap-seconds-hash` feature, the hash of a leap seconds file is parsed but not verified.
+#[cfg(not(feature = "leap-seconds-hash"))]
+fn verify_hash(_hashed_data: &str, _expected_hash: [u8; 20]) -> Result<(), HifitimeError> {
+    Ok(())
+}
+
 #[cfg(feature = "python")]
 #[cfg_attr(feature = "python", pymethods)]
 impl LeapSecondsFile {
@@ -114,25 +461,59 @@ impl LeapSecondsFile {
     fn __repr__(&self) -> String {
         format!("{self:?} @ {self:p}")
     }
-}
 
-impl Iterator for LeapSecondsFile {
-    type Item = LeapSecond;
+    /// Builds a leap second provider from the provided tzdata `leapseconds` file, e.g. `/usr/share/zoneinfo/leapseconds`.
+    ///
+    /// :type path: str
+    /// :rtype: LeapSecondsFile
+    #[staticmethod]
+    #[pyo3(name = "from_tzdata_path")]
+    fn py_from_tzdata_path(path: String) -> Result<Self, HifitimeError> {
+        Self::from_tzdata_path(path)
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.iter_pos += 1;
-        self.data.get(self.iter_pos - 1).copied()
+    /// Builds a leap second provider from the provided IERS Bulletin C.
+    ///
+    /// :type path: str
+    /// :rtype: LeapSecondsFile
+    #[staticmethod]
+    #[pyo3(name = "from_bulletin_c_path")]
+    fn py_from_bulletin_c_path(path: String) -> Result<Self, HifitimeError> {
+        Self::from_bulletin_c_path(path)
     }
-}
 
-impl DoubleEndedIterator for LeapSecondsFile {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.iter_pos == self.data.len() {
-            None
-        } else {
-            self.iter_pos += 1;
-            self.data.get(self.data.len() - self.iter_pos).copied()
-        }
+    /// Looks for a leap seconds file in the usual system locations, and falls back to the built-in leap seconds.
+    ///
+    /// :rtype: LeapSecondsFile
+    #[staticmethod]
+    #[pyo3(name = "discover")]
+    fn py_discover() -> Self {
+        Self::discover()
+    }
+
+    /// Returns the expiration date of this file (`#@` line), after which a leap second may have been announced.
+    ///
+    /// :rtype: Epoch
+    #[pyo3(name = "expires")]
+    fn py_expires(&self) -> Option<Epoch> {
+        self.expires
+    }
+
+    /// Returns the date of the last update of the leap seconds in this file (`#$` line), if specified.
+    ///
+    /// :rtype: Epoch
+    #[pyo3(name = "last_update")]
+    fn py_last_update(&self) -> Option<Epoch> {
+        self.last_update
+    }
+
+    /// Returns whether this file has expired at the provided epoch, i.e. whether a leap second may have been announced since.
+    ///
+    /// :type epoch: Epoch
+    /// :rtype: bool
+    #[pyo3(name = "is_expired_at")]
+    fn py_is_expired_at(&self, epoch: Epoch) -> bool {
+        self.is_expired_at(epoch)
     }
 }
 
@@ -144,14 +525,32 @@ impl Index<usize> for LeapSecondsFile {
     }
 }
 
-impl LeapSecondProvider for LeapSecondsFile {}
+/// Iterates over the leap seconds in increasing order, as `LeapSecondsFile` used to be an iterator itself.
+impl<'a> IntoIterator for &'a LeapSecondsFile {
+    type Item = LeapSecond;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, LeapSecond>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter().copied()
+    }
+}
+
+impl LeapSecondProvider for LeapSecondsFile {
+    fn leap_seconds(&self) -> &[LeapSecond] {
+        &self.data
+    }
+
+    fn expires(&self) -> Option<Epoch> {
+        self.expires
+    }
+}
 
 #[test]
 fn leap_second_fetch() {
     use crate::leap_seconds::LatestLeapSeconds;
     use std::env;
     use std::path::PathBuf;
-    let latest_leap_seconds = LatestLeapSeconds::default();
+    let latest_leap_seconds = LatestLeapSeconds;
 
     // Load the IERS data
     let path = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
@@ -159,16 +558,10 @@ fn leap_second_fetch() {
         .join("leap-seconds.list");
     let leap_seconds = LeapSecondsFile::from_path(path.to_str().unwrap()).unwrap();
 
-    assert_eq!(
-        leap_seconds[0],
-        LeapSecond::new(2_272_060_800.0, 10.0, true),
-    );
-    assert_eq!(
-        leap_seconds[27],
-        LeapSecond::new(3_692_217_600.0, 37.0, true)
-    );
+    assert_eq!(leap_seconds[0], LeapSecond::new(2_272_060_800, 10.0, true),);
+    assert_eq!(leap_seconds[27], LeapSecond::new(3_692_217_600, 37.0, true));
 
-    for (lsi, leap_second) in leap_seconds.enumerate() {
+    for (lsi, leap_second) in (&leap_seconds).into_iter().enumerate() {
         // The index offset is because the latest leap seconds include those not announced by the IERS, but the IERS file does not.
         assert_eq!(leap_second, latest_leap_seconds[lsi + 14]);
     }
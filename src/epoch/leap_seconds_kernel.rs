@@ -0,0 +1,352 @@
+/*
+* Hifitime
+* Copyright (C) 2017-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+* This Source Code Form is subject to the terms of the Mozilla Public
+* License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at https://mozilla.org/MPL/2.0/.
+*
+* Documentation: https://nyxspace.com/
+*/
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use std::{fs::File, io::Read, path::Path};
+
+use core::ops::Index;
+use core::str::FromStr;
+
+use crate::{
+    leap_seconds::{LeapSecond, LeapSecondProvider},
+    Epoch, EtModel, HifitimeError, MonthName, ParsingError, TimeScale,
+};
+
+#[repr(C)]
+#[cfg_attr(feature = "python", pyclass)]
+#[derive(Clone, Debug, Default)]
+/// A leap second provider that uses a NASA/NAIF SPICE leap seconds kernel (LSK), e.g. `naif0012.tls`.
+///
+/// The leap seconds are read from `DELTET/DELTA_AT`, and the ET model from the other `DELTET` variables, cf. `et_model`.
+///
+/// (Python documentation hints)
+/// :type path: str
+/// :rtype: LeapSecondsKernel
+pub struct LeapSecondsKernel {
+    data: Vec<LeapSecond>,
+    et_model: EtModel,
+}
+
+impl LeapSecondsKernel {
+    /// Builds a leap second provider from the provided NAIF leap seconds kernel (text kernel).
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, HifitimeError> {
+        let mut f = match File::open(path) {
+            Ok(f) => f,
+            Err(e) => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::InOut { err: e.kind() },
+                    details: "opening leap seconds kernel",
+                })
+            }
+        };
+
+        let mut contents = String::new();
+        if let Err(e) = f.read_to_string(&mut contents) {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::InOut { err: e.kind() },
+                details: "reading leap seconds kernel",
+            });
+        }
+
+        Self::from_str(&contents)
+    }
+
+    /// Returns the ET model defined by the `DELTET` variables of this kernel, to be used with `Epoch::to_time_scale_with_et_model`
+    /// or `Epoch::to_et_duration_with`.
+    pub fn et_model(&self) -> EtModel {
+        self.et_model
+    }
+}
+
+impl FromStr for LeapSecondsKernel {
+    type Err = HifitimeError;
+
+    /// Parses the contents of a NAIF leap seconds kernel, where only the data blocks (between `\begindata` and `\begintext`) are read.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut data = String::new();
+        let mut in_data = false;
+        for line in s.lines() {
+            let line = line.trim();
+            if line.starts_with("\\begindata") {
+                in_data = true;
+            } else if line.starts_with("\\begintext") {
+                in_data = false;
+            } else if in_data {
+                data.push_str(line);
+                data.push(' ');
+            }
+        }
+
+        let variables = parse_assignments(&data)?;
+        let variable = |name: &str| {
+            variables
+                .iter()
+                .find(|(var_name, _)| *var_name == name)
+                .map(|(_, values)| values.as_slice())
+        };
+
+        let scalar = |name: &str, details: &'static str| match variable(name) {
+            Some([value]) => parse_kernel_f64(value, details),
+            _ => Err(HifitimeError::Parse {
+                source: ParsingError::UnknownFormat,
+                details,
+            }),
+        };
+
+        let et_model = match variable("DELTET/M") {
+            Some([m0, m1]) => EtModel {
+                delta_t_a: scalar("DELTET/DELTA_T_A", "invalid or missing DELTET/DELTA_T_A")?,
+                k: scalar("DELTET/K", "invalid or missing DELTET/K")?,
+                eb: scalar("DELTET/EB", "invalid or missing DELTET/EB")?,
+                m0: parse_kernel_f64(m0, "invalid DELTET/M")?,
+                m1: parse_kernel_f64(m1, "invalid DELTET/M")?,
+            },
+            _ => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::UnknownFormat,
+                    details: "invalid or missing DELTET/M",
+                })
+            }
+        };
+
+        let delta_at = match variable("DELTET/DELTA_AT") {
+            Some(values) if !values.is_empty() && values.len() % 2 == 0 => values,
+            _ => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::UnknownFormat,
+                    details: "DELTET/DELTA_AT should be pairs of leap seconds and dates",
+                })
+            }
+        };
+
+        let mut me = Self {
+            et_model,
+            ..Default::default()
+        };
+
+        for pair in delta_at.chunks_exact(2) {
+            let delta_at = parse_kernel_f64(pair[0], "DELTET/DELTA_AT leap second is not numeric")?;
+            let timestamp_utc_s = parse_kernel_date(pair[1])?;
+
+            me.data
+                .push(LeapSecond::new(timestamp_utc_s, delta_at, true));
+        }
+
+        // The lookups of the leap seconds are binary searches, which require them to be sorted.
+        me.data
+            .sort_by_key(|leap_second| leap_second.timestamp_utc_s);
+
+        Ok(me)
+    }
+}
+
+/// Splits the data of a text kernel into its variable assignments, e.g. `DELTET/K = 1.657D-3` or `DELTET/M = ( 6.239996D0 1.99096871D-7 )`.
+/// Values appended with `+=` are added to the previous ones of that variable.
+fn parse_assignments(data: &str) -> Result<Vec<(&str, Vec<&str>)>, HifitimeError> {
+    let mut tokens = tokenize(data).into_iter();
+    let mut variables: Vec<(&str, Vec<&str>)> = Vec::new();
+
+    while let Some(name) = tokens.next() {
+        let append = match tokens.next() {
+            Some("=") => false,
+            Some("+=") => true,
+            _ => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::UnknownFormat,
+                    details: "expected an assignment in leap seconds kernel",
+                })
+            }
+        };
+
+        let mut values = Vec::new();
+        match tokens.next() {
+            Some("(") => loop {
+                match tokens.next() {
+                    Some(")") => break,
+                    Some(value) => values.push(value),
+                    None => {
+                        return Err(HifitimeError::Parse {
+                            source: ParsingError::UnknownFormat,
+                            details: "unterminated list in leap seconds kernel",
+                        })
+                    }
+                }
+            },
+            Some(value) => values.push(value),
+            None => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::UnknownFormat,
+                    details: "missing value in leap seconds kernel",
+                })
+            }
+        }
+
+        match variables.iter_mut().find(|(var_name, _)| *var_name == name) {
+            Some((_, prev_values)) if append => prev_values.extend(values),
+            Some((_, prev_values)) => *prev_values = values,
+            None => variables.push((name, values)),
+        }
+    }
+
+    Ok(variables)
+}
+
+/// Splits the data of a text kernel into names, values, parentheses and assignment operators.
+fn tokenize(data: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    let mut in_quotes = false;
+
+    for (idx, c) in data.char_indices() {
+        if in_quotes {
+            if c == '\'' {
+                in_quotes = false;
+            }
+            continue;
+        }
+
+        let is_delimiter = c.is_whitespace() || c == ',';
+        let is_single = c == '(' || c == ')' || c == '=';
+        let is_append = c == '+' && data[idx + 1..].starts_with('=');
+
+        if is_delimiter || is_single || is_append {
+            if let Some(token_start) = start.take() {
+                tokens.push(&data[token_start..idx]);
+            }
+            if is_single {
+                // The `=` of a `+=` was already consumed as part of that operator.
+                if c == '=' && tokens.last() == Some(&"+") {
+                    tokens.pop();
+                    tokens.push(&data[idx - 1..=idx]);
+                } else {
+                    tokens.push(&data[idx..=idx]);
+                }
+            } else if is_append {
+                tokens.push(&data[idx..=idx]);
+            }
+        } else {
+            if c == '\'' {
+                in_quotes = true;
+            }
+            start.get_or_insert(idx);
+        }
+    }
+
+    if let Some(token_start) = start {
+        tokens.push(&data[token_start..]);
+    }
+
+    tokens
+}
+
+/// Parses a number of a text kernel, which may use `D` as the exponent character, e.g. `1.657D-3`.
+fn parse_kernel_f64(value: &str, details: &'static str) -> Result<f64, HifitimeError> {
+    value
+        .replace(['D', 'd'], "E")
+        .parse()
+        .map_err(|_| HifitimeError::Parse {
+            source: ParsingError::ValueError,
+            details,
+        })
+}
+
+/// Parses a date of a text kernel, e.g. `@1972-JAN-1`, into the number of UTC seconds past J1900 at midnight of that date.
+fn parse_kernel_date(value: &str) -> Result<i64, HifitimeError> {
+    let invalid = || HifitimeError::Parse {
+        source: ParsingError::ValueError,
+        details: "DELTET/DELTA_AT date should be formatted as @YYYY-MON-DD",
+    };
+
+    let mut parts = value.strip_prefix('@').ok_or_else(invalid)?.split('-');
+
+    let (Some(year), Some(month), Some(day), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+
+    let year: i32 = year.parse().map_err(|_| invalid())?;
+    let month = match month.parse::<u8>() {
+        Ok(month) => month,
+        Err(_) => MonthName::from_str(month).map_err(|_| invalid())? as u8 + 1,
+    };
+    let day: u8 = day.parse().map_err(|_| invalid())?;
+
+    Ok(
+        Epoch::maybe_from_gregorian(year, month, day, 0, 0, 0, 0, TimeScale::UTC)?
+            .duration
+            .to_seconds() as i64,
+    )
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(feature = "python", pymethods)]
+impl LeapSecondsKernel {
+    #[new]
+    pub fn __new__(path: String) -> Result<Self, HifitimeError> {
+        Self::from_path(&path)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?} @ {self:p}")
+    }
+
+    /// Returns the ET model defined by the `DELTET` variables of this kernel.
+    ///
+    /// :rtype: EtModel
+    #[pyo3(name = "et_model")]
+    fn py_et_model(&self) -> EtModel {
+        self.et_model
+    }
+}
+
+impl Index<usize> for LeapSecondsKernel {
+    type Output = LeapSecond;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.data.index(index)
+    }
+}
+
+/// Iterates over the leap seconds in increasing order, like the other leap second providers.
+impl<'a> IntoIterator for &'a LeapSecondsKernel {
+    type Item = LeapSecond;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, LeapSecond>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter().copied()
+    }
+}
+
+impl LeapSecondProvider for LeapSecondsKernel {
+    fn leap_seconds(&self) -> &[LeapSecond] {
+        &self.data
+    }
+}
+
+#[test]
+fn leap_second_kernel_fetch() {
+    use crate::leap_seconds::LeapSecondsFile;
+    use std::env;
+    use std::path::PathBuf;
+
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let kernel = LeapSecondsKernel::from_path(root.join("naif0012.txt")).unwrap();
+    let leap_seconds_file =
+        LeapSecondsFile::from_path(root.join("data").join("leap-seconds.list")).unwrap();
+
+    assert_eq!(kernel.et_model(), EtModel::NAIF0012);
+    assert_eq!(kernel[0], LeapSecond::new(2_272_060_800, 10.0, true),);
+
+    assert_eq!(kernel.leap_seconds(), leap_seconds_file.leap_seconds());
+    assert_eq!(kernel.leap_seconds().len(), 28);
+}
@@ -8,16 +8,28 @@
 * Documentation: https://nyxspace.com/
 */
 
+mod delta_t;
+mod et_model;
 mod formatting;
 mod gregorian;
 pub mod initializers;
+mod observer;
 mod ops;
+mod sidereal;
+mod solar_time;
+mod tdb_model;
 mod with_funcs;
 
 #[cfg(feature = "std")]
 mod leap_seconds_file;
 #[cfg(feature = "std")]
+mod leap_seconds_kernel;
+#[cfg(feature = "std")]
+mod proper_time;
+#[cfg(feature = "std")]
 mod system_time;
+#[cfg(feature = "std")]
+mod time_ephemeris;
 
 #[cfg(kani)]
 mod kani_verif;
@@ -30,15 +42,26 @@ pub mod leap_seconds;
 
 use crate::duration::{Duration, Unit};
 use crate::errors::{DurationError, ParseSnafu};
-use crate::leap_seconds::{LatestLeapSeconds, LeapSecondProvider};
+use crate::leap_seconds::{LeapSecondProvider, LATEST_LEAP_SECONDS};
 use crate::{
-    HifitimeError, MonthName, TimeScale, TimeUnits, BDT_REF_EPOCH, ET_EPOCH_S, GPST_REF_EPOCH,
-    GST_REF_EPOCH, MJD_J1900, MJD_OFFSET, QZSST_REF_EPOCH, UNIX_REF_EPOCH,
+    HifitimeError, MonthName, TimeScale, TimeUnits, BDT_REF_EPOCH, ET_EPOCH_S,
+    GLONASST_UTC_OFFSET_HOURS, GPST_REF_EPOCH, GST_REF_EPOCH, IAU_L_B, IAU_L_G, IAU_TDB0_S,
+    IAU_TIME_REF_EPOCH, IRNSST_REF_EPOCH, MJD_J1900, MJD_OFFSET, NANOSECONDS_PER_DAY,
+    NANOSECONDS_PER_SECOND, QZSST_REF_EPOCH, UNIX_REF_EPOCH,
 };
 use core::cmp::Eq;
 use core::str::FromStr;
+pub use delta_t::DeltaTModel;
+pub use et_model::EtModel;
 pub use gregorian::is_gregorian_valid;
+pub use observer::{Observer, ObserverFrame};
+#[cfg(feature = "std")]
+pub use proper_time::{ProperTime, TrajectorySample};
 use snafu::ResultExt;
+pub use tdb_model::TdbModel;
+use tdb_model::TdbSource;
+#[cfg(feature = "std")]
+pub use time_ephemeris::TimeEphemeris;
 
 #[cfg(not(kani))]
 use crate::ParsingError;
@@ -62,6 +85,8 @@ use num_traits::Float;
 
 pub(crate) const TT_OFFSET_MS: i64 = 32_184;
 pub(crate) const ET_OFFSET_US: i64 = 32_184_935;
+/// Number of days in a GLONASS four-year interval (N4), starting on a leap year.
+pub(crate) const GLONASST_DAYS_PER_N4: i64 = 1_461;
 
 /// NAIF leap second kernel data for M_0 used to calculate the mean anomaly of the heliocentric orbit of the Earth-Moon barycenter.
 pub const NAIF_M0: f64 = 6.239996;
@@ -122,19 +147,126 @@ impl Epoch {
     ///
     /// # Why does this function return an `Option` when the other returns a value
     /// This is to match the `iauDat` function of SOFA (src/dat.c). That function will return a warning and give up if the start date is before 1960.
-    pub fn leap_seconds_with<L: LeapSecondProvider>(
+    pub fn leap_seconds_with<L: LeapSecondProvider + ?Sized>(
         &self,
         iers_only: bool,
-        provider: L,
+        provider: &L,
     ) -> Option<f64> {
-        for leap_second in provider.rev() {
-            if self.to_tai_duration().to_seconds() >= leap_second.timestamp_tai_s
-                && (!iers_only || leap_second.announced_by_iers)
-            {
-                return Some(leap_second.delta_at);
+        let (duration, time_scale) = self.leap_seconds_lookup();
+        leap_seconds::delta_at_in(provider.leap_seconds(), duration, time_scale, iers_only)
+    }
+
+    /// Converts self to another time scale using the leap seconds of the provided LeapSecondProvider instead of the default ones.
+    ///
+    /// # Panics
+    /// Like `to_time_scale`, if UT1 is involved and the registered UT1 provider does not cover this epoch.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::prelude::*;
+    /// use hifitime::leap_seconds::LatestLeapSeconds;
+    ///
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+    /// let tai = epoch.to_time_scale_with(TimeScale::TAI, &LatestLeapSeconds);
+    /// assert_eq!(tai, epoch.to_time_scale(TimeScale::TAI));
+    /// ```
+    #[must_use]
+    pub fn to_time_scale_with<L: LeapSecondProvider + ?Sized>(
+        &self,
+        ts: TimeScale,
+        provider: &L,
+    ) -> Self {
+        let iers_only = provider.iers_only();
+        let delta_at = |duration: Duration, time_scale: TimeScale| {
+            leap_seconds::delta_at_duration(leap_seconds::delta_at_in(
+                provider.leap_seconds(),
+                duration,
+                time_scale,
+                iers_only,
+            ))
+        };
+        Self::expect_ut1(self.to_time_scale_inner(
+            ts,
+            &delta_at,
+            &Self::try_registered_delta_tai_ut1,
+            EtModel::NAIF0012,
+            &TdbModel::Esa,
+        ))
+    }
+
+    #[must_use]
+    /// Returns this time in a Duration past J1900 counted in UTC, using the leap seconds of the provided LeapSecondProvider
+    /// instead of the default ones.
+    pub fn to_utc_duration_with<L: LeapSecondProvider + ?Sized>(&self, provider: &L) -> Duration {
+        self.to_time_scale_with(TimeScale::UTC, provider).duration
+    }
+
+    /// Converts self to another time scale using the leap seconds of the provided LeapSecondProvider, like `to_time_scale_with`,
+    /// but returns an error if leap seconds are involved and this epoch is past the expiration date of the provider, cf. `LeapSecondProvider::expires`.
+    ///
+    /// # Example
+    /// ```
+    /// #[cfg(feature = "std")]
+    /// {
+    /// use hifitime::prelude::*;
+    /// use hifitime::leap_seconds::LeapSecondsFile;
+    ///
+    /// let provider = LeapSecondsFile::from_path("data/leap-seconds.list").unwrap();
+    /// // This file expires on 2023-06-28.
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    /// assert!(epoch.try_to_time_scale_with(TimeScale::TAI, &provider).is_ok());
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2024, 1, 1);
+    /// assert!(epoch.try_to_time_scale_with(TimeScale::TAI, &provider).is_err());
+    /// // Leap seconds are not involved in the conversion to GPST of a TAI epoch.
+    /// assert!(Epoch::from_gregorian_tai_at_midnight(2024, 1, 1).try_to_time_scale_with(TimeScale::GPST, &provider).is_ok());
+    /// }
+    /// ```
+    pub fn try_to_time_scale_with<L: LeapSecondProvider + ?Sized>(
+        &self,
+        ts: TimeScale,
+        provider: &L,
+    ) -> Result<Self, HifitimeError> {
+        if self.time_scale.uses_leap_seconds() || ts.uses_leap_seconds() {
+            if let Some(expires) = provider.expires() {
+                if *self > expires {
+                    return Err(HifitimeError::ExpiredLeapSeconds {
+                        expires,
+                        epoch: *self,
+                    });
+                }
             }
         }
-        None
+        Ok(self.to_time_scale_with(ts, provider))
+    }
+
+    #[must_use]
+    /// Returns the Ephemeris Time duration past J2000 of this epoch using the provided ET model instead of `EtModel::NAIF0012`,
+    /// e.g. the model of a leap seconds kernel with `LeapSecondsKernel::et_model`.
+    pub fn to_et_duration_with(&self, model: EtModel) -> Duration {
+        model.tai_to_et(self.to_tai_duration())
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Ephemeris Time duration past J2000 using the provided ET model instead of `EtModel::NAIF0012`.
+    ///
+    /// The returned epoch is in TAI so that its subsequent conversions do not use `EtModel::NAIF0012` for this ET.
+    pub fn from_et_duration_with(duration_since_j2000: Duration, model: EtModel) -> Self {
+        Self::from_tai_duration(model.et_to_tai(duration_since_j2000))
+    }
+
+    #[must_use]
+    /// Returns the Barycentric Dynamical Time duration past J2000 of this epoch using the provided TDB model instead of `TdbModel::Esa`,
+    /// e.g. `TdbModel::FairheadBretagnon` for a nanosecond TDB.
+    pub fn to_tdb_duration_with(&self, model: TdbModel) -> Duration {
+        model.tai_to_tdb(self.to_tai_duration())
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Barycentric Dynamical Time duration past J2000 using the provided TDB model instead of `TdbModel::Esa`.
+    ///
+    /// The returned epoch is in TAI so that its subsequent conversions do not use `TdbModel::Esa` for this TDB.
+    pub fn from_tdb_duration_with(duration_since_j2000: Duration, model: TdbModel) -> Self {
+        Self::from_tai_duration(model.tdb_to_tai(duration_since_j2000))
     }
 
     /// Creates an epoch from given duration expressed in given timescale, i.e. since the given time scale's reference epoch.
@@ -149,151 +281,449 @@ impl Epoch {
         }
     }
 
-    fn delta_et_tai(seconds: f64) -> f64 {
-        // Calculate M, the mean anomaly.4
-        let m = NAIF_M0 + seconds * NAIF_M1;
-        // Calculate eccentric anomaly
-        let e = m + NAIF_EB * m.sin();
-
-        (TT_OFFSET_MS * Unit::Millisecond).to_seconds() + NAIF_K * e.sin()
-    }
-
     fn inner_g(seconds: f64) -> f64 {
         use core::f64::consts::TAU;
         let g = TAU / 360.0 * 357.528 + 1.990_910_018_065_731e-7 * seconds;
         // Return gamma
         1.658e-3 * (g + 1.67e-2 * g.sin()).sin()
     }
-}
 
-#[cfg_attr(feature = "python", pymethods)]
-impl Epoch {
-    #[must_use]
-    /// Converts self to another time scale
-    ///
-    /// As per the [Rust naming convention](https://rust-lang.github.io/api-guidelines/naming.html#ad-hoc-conversions-follow-as_-to_-into_-conventions-c-conv),
-    /// this borrows an Epoch and returns an owned Epoch.
-    ///
-    /// :type ts: TimeScale
-    /// :rtype: Epoch
-    pub fn to_time_scale(&self, ts: TimeScale) -> Self {
+    /// Returns the T0 epoch of the IAU coordinate time relations, 1977-01-01T00:00:32.184, as a duration past J1900 in TT (or TCG).
+    fn iau_t0_j1900() -> Duration {
+        IAU_TIME_REF_EPOCH.duration + TT_OFFSET_MS.milliseconds()
+    }
+
+    /// Returns the T0 epoch of the IAU coordinate time relations, 1977-01-01T00:00:32.184, as a duration past J2000 in TCB.
+    fn iau_t0_j2000() -> Duration {
+        Self::iau_t0_j1900() - TimeScale::TCB.prime_epoch_offset()
+    }
+
+    /// Converts a TCG duration past J1900 into a TT duration past J1900 (IAU 2000 Resolution B1.9).
+    fn tcg_to_tt(tcg: Duration) -> Duration {
+        tcg - ((tcg - Self::iau_t0_j1900()).to_seconds() * IAU_L_G).seconds()
+    }
+
+    /// Converts a TT duration past J1900 into a TCG duration past J1900 (inverse of IAU 2000 Resolution B1.9).
+    fn tt_to_tcg(tt: Duration) -> Duration {
+        tt + ((tt - Self::iau_t0_j1900()).to_seconds() * IAU_L_G / (1.0 - IAU_L_G)).seconds()
+    }
+
+    /// Converts a TCB duration past J2000 into a TDB duration past J2000 (IAU 2006 Resolution B3).
+    fn tcb_to_tdb(tcb: Duration) -> Duration {
+        tcb - ((tcb - Self::iau_t0_j2000()).to_seconds() * IAU_L_B).seconds() + IAU_TDB0_S.seconds()
+    }
+
+    /// Converts a TDB duration past J2000 into a TCB duration past J2000 (inverse of IAU 2006 Resolution B3).
+    fn tdb_to_tcb(tdb: Duration) -> Duration {
+        let tdb_no_offset = tdb - IAU_TDB0_S.seconds();
+        tdb_no_offset
+            + ((tdb_no_offset - Self::iau_t0_j2000()).to_seconds() * IAU_L_B / (1.0 - IAU_L_B))
+                .seconds()
+    }
+
+    /// Returns the duration past J1900 at which to look up the leap seconds of this epoch and its time scale:
+    /// the UTC duration if this epoch is in UTC, and the TAI duration otherwise.
+    fn leap_seconds_lookup(&self) -> (Duration, TimeScale) {
+        if self.time_scale == TimeScale::UTC {
+            (self.duration, TimeScale::UTC)
+        } else {
+            (self.to_tai_duration(), TimeScale::TAI)
+        }
+    }
+
+    /// Returns whether this epoch is a UTC (or GLONASST) date which never existed because its second was skipped by a negative
+    /// leap second (i.e. a decrease of ΔAT), where `delta_at` returns ΔAT as in `to_time_scale_inner`.
+    fn is_skipped_by_leap_second_with<L>(&self, delta_at: L) -> bool
+    where
+        L: Fn(Duration, TimeScale) -> Duration,
+    {
+        let Some(utc) = self.skippable_utc_duration() else {
+            return false;
+        };
+        // A negative leap second skips the last second of a minute, usually 23:59:59.
+        if utc.to_parts().1 / NANOSECONDS_PER_SECOND % 60 != 59 {
+            return false;
+        }
+        // The TAI instant of a skipped second is already past the decrease of ΔAT, whereas that of any other second is not.
+        let delta_at_utc = delta_at(utc, TimeScale::UTC);
+        delta_at(utc + delta_at_utc, TimeScale::TAI) != delta_at_utc
+    }
+
+    /// Returns the UTC duration past J1900 of this epoch if it is a UTC (or GLONASST) date, i.e. a date which may have been
+    /// skipped by a negative leap second.
+    pub(crate) fn skippable_utc_duration(&self) -> Option<Duration> {
+        match self.time_scale {
+            TimeScale::UTC => Some(self.duration),
+            TimeScale::GLONASST => Some(
+                self.duration + self.time_scale.prime_epoch_offset()
+                    - GLONASST_UTC_OFFSET_HOURS.hours(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Returns whether this epoch is a UTC (or GLONASST) date which never existed because of a negative leap second
+    /// of the default leap seconds.
+    pub(crate) fn is_skipped_by_leap_second(&self) -> bool {
+        self.is_skipped_by_leap_second_with(|duration, time_scale| {
+            Self::default_delta_at(duration, time_scale, true)
+                .unwrap_or(0.0)
+                .seconds()
+        })
+    }
+
+    /// Returns ΔAT from the default leap seconds, i.e. those set with `leap_seconds::set_default_provider` or `LatestLeapSeconds`,
+    /// cf. `leap_seconds::delta_at_in` for the arguments.
+    fn default_delta_at(duration: Duration, time_scale: TimeScale, iers_only: bool) -> Option<f64> {
+        #[cfg(feature = "std")]
+        if let Some(delta_at) = leap_seconds::default_delta_at(duration, time_scale, iers_only) {
+            return delta_at;
+        }
+        leap_seconds::delta_at_in(&LATEST_LEAP_SECONDS, duration, time_scale, iers_only)
+    }
+
+    /// Returns ΔAT as used by `to_time_scale`: from the default leap seconds, including the UTC offsets from 1960 to 1972
+    /// unless the default provider only accounts for the IERS leap seconds, cf. `to_time_scale_inner` for the arguments.
+    pub(crate) fn conversion_delta_at(duration: Duration, time_scale: TimeScale) -> Duration {
+        leap_seconds::delta_at_duration(Self::default_delta_at(
+            duration,
+            time_scale,
+            leap_seconds::default_iers_only(),
+        ))
+    }
+
+    /// Returns the UT1 duration of this epoch from the registered UT1 provider, or an error if it does not cover this epoch.
+    pub(crate) fn try_ut1_duration(&self) -> Result<Duration, HifitimeError> {
+        Ok(self.try_to_time_scale(TimeScale::UT1)?.duration)
+    }
+
+    /// Returns TAI - UT1 at the provided TAI duration past J1900 from the registered UT1 provider,
+    /// or a `MissingUt1Data` error if it does not cover that epoch.
+    fn try_registered_delta_tai_ut1(tai: Duration) -> Result<Duration, HifitimeError> {
+        Self::registered_delta_tai_ut1(tai).ok_or(HifitimeError::MissingUt1Data {
+            epoch: Self::from_tai_duration(tai),
+        })
+    }
+
+    /// Returns the epoch converted by the infallible conversions, which can only fail for lack of UT1 data, or panics with
+    /// a message pointing to the fallible conversions.
+    fn expect_ut1(converted: Result<Self, HifitimeError>) -> Self {
+        converted.unwrap_or_else(|err| {
+            panic!(
+                "{err}: provide the UT1 data with `Epoch::to_time_scale_with_ut1` (requires the `ut1` feature), \
+                or use `Epoch::try_to_time_scale` to get an error instead"
+            )
+        })
+    }
+
+    /// Returns TAI - UT1 at the provided TAI duration past J1900, from the registered UT1 provider, if it covers that epoch.
+    #[cfg(feature = "ut1")]
+    fn registered_delta_tai_ut1(tai: Duration) -> Option<Duration> {
+        use ut1::Ut1Source;
+        ut1::Ut1Provider::registered()?.delta_tai_ut1(&Self::from_tai_duration(tai))
+    }
+
+    /// Without the `ut1` feature, no UT1 provider can be registered.
+    #[cfg(not(feature = "ut1"))]
+    fn registered_delta_tai_ut1(_tai: Duration) -> Option<Duration> {
+        None
+    }
+
+    /// Converts self to another time scale, where `delta_at` returns TAI - UTC (the accumulated leap seconds) and
+    /// `delta_tai_ut1` returns TAI - UT1, both at the provided TAI duration past J1900.
+    /// When converting from UTC, `delta_at` is called with the UTC duration and `TimeScale::UTC`, so that the leap seconds
+    /// (and the drift of the pre-1972 UTC offsets) are looked up at that UTC date; otherwise it is called with `TimeScale::TAI`.
+    /// `delta_tai_ut1` is only called if the source or the target time scale is UT1, `et_model` is used if either is ET,
+    /// and `tdb` if either is TDB or TCB, returning a `MissingTimeEphemerisData` error if it does not cover this epoch.
+    /// The closures are borrowed such that the conversions of the custom time scales, through their base time scale, reuse them.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_time_scale_inner<L, F, T>(
+        &self,
+        ts: TimeScale,
+        delta_at: &L,
+        delta_tai_ut1: &F,
+        et_model: EtModel,
+        tdb: &T,
+    ) -> Result<Self, HifitimeError>
+    where
+        L: Fn(Duration, TimeScale) -> Duration,
+        F: Fn(Duration) -> Result<Duration, HifitimeError>,
+        T: TdbSource + ?Sized,
+    {
+        let missing_tdb = || HifitimeError::MissingTimeEphemerisData { epoch: *self };
         if ts == self.time_scale {
             // Do nothing, just return a copy
-            *self
+            Ok(*self)
         } else {
             // Now we need to convert from the current time scale into the desired time scale.
             // Let's first compute this epoch from its current time scale into TAI.
             let prime_epoch_offset = match self.time_scale {
                 TimeScale::TAI => self.duration,
                 TimeScale::TT => self.duration - TT_OFFSET_MS.milliseconds(),
-                TimeScale::ET => {
-                    // Run a Newton Raphston to convert find the correct value of the
-                    let mut seconds_j2000 = self.duration.to_seconds();
-                    for _ in 0..5 {
-                        seconds_j2000 += -NAIF_K
-                            * (NAIF_M0
-                                + NAIF_M1 * seconds_j2000
-                                + NAIF_EB * (NAIF_M0 + NAIF_M1 * seconds_j2000).sin())
-                            .sin();
-                    }
-
-                    // At this point, we have a good estimate of the number of seconds of this epoch.
-                    // Reverse the algorithm:
-                    let delta_et_tai = Self::delta_et_tai(
-                        seconds_j2000 - (TT_OFFSET_MS * Unit::Millisecond).to_seconds(),
-                    );
-
-                    // Match SPICE by changing the UTC definition.
-                    self.duration - delta_et_tai.seconds() + self.time_scale.prime_epoch_offset()
-                }
-                TimeScale::TDB => {
-                    let gamma = Self::inner_g(self.duration.to_seconds());
-
-                    let delta_tdb_tai = gamma * Unit::Second + TT_OFFSET_MS * Unit::Millisecond;
-
-                    // Offset back to J1900.
-                    self.duration - delta_tdb_tai + self.time_scale.prime_epoch_offset()
-                }
+                TimeScale::ET => et_model.et_to_tai(self.duration),
+                TimeScale::TDB => tdb.try_tdb_to_tai(self.duration).ok_or_else(missing_tdb)?,
                 TimeScale::UTC => {
                     // Assume this is TAI
-                    let mut tai_assumption = *self;
-                    tai_assumption.time_scale = TimeScale::TAI;
-                    self.duration + tai_assumption.leap_seconds(true).unwrap_or(0.0).seconds()
+                    self.duration + delta_at(self.duration, TimeScale::UTC)
                 }
                 TimeScale::GPST => self.duration + GPST_REF_EPOCH.to_tai_duration(),
                 TimeScale::GST => self.duration + GST_REF_EPOCH.to_tai_duration(),
                 TimeScale::BDT => self.duration + BDT_REF_EPOCH.to_tai_duration(),
                 TimeScale::QZSST => self.duration + QZSST_REF_EPOCH.to_tai_duration(),
+                TimeScale::GLONASST => {
+                    // GLONASST is UTC(SU) + 3 hours, so compute the UTC duration and assume this is TAI
+                    let utc_duration = self.duration + self.time_scale.prime_epoch_offset()
+                        - GLONASST_UTC_OFFSET_HOURS.hours();
+                    utc_duration + delta_at(utc_duration, TimeScale::UTC)
+                }
+                TimeScale::IRNSST => self.duration + IRNSST_REF_EPOCH.to_tai_duration(),
+                TimeScale::TCG => Self::tcg_to_tt(self.duration) - TT_OFFSET_MS.milliseconds(),
+                TimeScale::TCB => {
+                    // Compute the TDB duration and use its conversion to TAI
+                    tdb.try_tdb_to_tai(Self::tcb_to_tdb(self.duration))
+                        .ok_or_else(missing_tdb)?
+                }
+                TimeScale::UT1 => {
+                    // TAI = UT1 + (TAI - UT1), but the offset is a function of TAI: start from the UT1 duration
+                    // and refine it. Once suffices for the EOP data, where UT1 drifts slowly, but a ΔT model
+                    // drifts by minutes per year tens of millennia away, hence the extra iterations.
+                    let mut tai = self.duration + delta_tai_ut1(self.duration)?;
+                    for _ in 0..3 {
+                        let next = self.duration + delta_tai_ut1(tai)?;
+                        if next == tai {
+                            break;
+                        }
+                        tai = next;
+                    }
+                    tai
+                }
+                TimeScale::Custom(_) => {
+                    #[cfg(feature = "std")]
+                    if let Some(custom) = crate::CustomTimeScale::from_time_scale(self.time_scale) {
+                        // Compute the epoch in the base time scale and use its conversion to TAI
+                        let base = custom.from_duration(self.duration);
+                        return base
+                            .to_time_scale_inner(
+                                TimeScale::TAI,
+                                delta_at,
+                                delta_tai_ut1,
+                                et_model,
+                                tdb,
+                            )?
+                            .to_time_scale_inner(ts, delta_at, delta_tai_ut1, et_model, tdb);
+                    }
+                    self.duration
+                }
             };
 
             // Convert to the desired time scale from the TAI duration
             let ts_ref_offset = match ts {
                 TimeScale::TAI => prime_epoch_offset,
                 TimeScale::TT => prime_epoch_offset + TT_OFFSET_MS.milliseconds(),
-                TimeScale::ET => {
-                    // Run a Newton Raphston to convert find the correct value of the ... ?!
-
-                    let mut seconds = (prime_epoch_offset - ts.prime_epoch_offset()).to_seconds();
-                    for _ in 0..5 {
-                        seconds -= -NAIF_K
-                            * (NAIF_M0
-                                + NAIF_M1 * seconds
-                                + NAIF_EB * (NAIF_M0 + NAIF_M1 * seconds).sin())
-                            .sin();
-                    }
-
-                    // At this point, we have a good estimate of the number of seconds of this epoch.
-                    // Reverse the algorithm:
-                    let delta_et_tai = Self::delta_et_tai(
-                        seconds + (TT_OFFSET_MS * Unit::Millisecond).to_seconds(),
-                    );
-
-                    // Match SPICE by changing the UTC definition.
-                    prime_epoch_offset + delta_et_tai.seconds() - ts.prime_epoch_offset()
-                }
-                TimeScale::TDB => {
-                    // Iterate to convert find the correct value of the
-                    let mut seconds = (prime_epoch_offset - ts.prime_epoch_offset()).to_seconds();
-                    let mut delta = 1e8; // Arbitrary large number, greater than first step of Newton Raphson.
-                    for _ in 0..5 {
-                        let next = seconds - Self::inner_g(seconds);
-                        let new_delta = (next - seconds).abs();
-                        if (new_delta - delta).abs() < 1e-9 {
-                            break;
-                        }
-                        seconds = next; // Loop
-                        delta = new_delta;
-                    }
-
-                    // At this point, we have a good estimate of the number of seconds of this epoch.
-                    // Reverse the algorithm:
-                    let gamma =
-                        Self::inner_g(seconds + (TT_OFFSET_MS * Unit::Millisecond).to_seconds());
-                    let delta_tdb_tai = gamma.seconds() + TT_OFFSET_MS.milliseconds();
-
-                    prime_epoch_offset + delta_tdb_tai - ts.prime_epoch_offset()
-                }
+                TimeScale::ET => et_model.tai_to_et(prime_epoch_offset),
+                TimeScale::TDB => tdb
+                    .try_tai_to_tdb(prime_epoch_offset)
+                    .ok_or_else(missing_tdb)?,
                 TimeScale::UTC => {
-                    // Assume it's TAI
-                    let epoch = Self {
-                        duration: prime_epoch_offset,
-                        time_scale: TimeScale::TAI,
-                    };
                     // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
-                    prime_epoch_offset - epoch.leap_seconds(true).unwrap_or(0.0).seconds()
+                    prime_epoch_offset - delta_at(prime_epoch_offset, TimeScale::TAI)
                 }
                 TimeScale::GPST => prime_epoch_offset - GPST_REF_EPOCH.to_tai_duration(),
                 TimeScale::GST => prime_epoch_offset - GST_REF_EPOCH.to_tai_duration(),
                 TimeScale::BDT => prime_epoch_offset - BDT_REF_EPOCH.to_tai_duration(),
                 TimeScale::QZSST => prime_epoch_offset - QZSST_REF_EPOCH.to_tai_duration(),
+                TimeScale::GLONASST => {
+                    // GLONASST = UTC + 3 hours = TAI - leap_seconds + 3 hours
+                    prime_epoch_offset - delta_at(prime_epoch_offset, TimeScale::TAI)
+                        + GLONASST_UTC_OFFSET_HOURS.hours()
+                        - ts.prime_epoch_offset()
+                }
+                TimeScale::IRNSST => prime_epoch_offset - IRNSST_REF_EPOCH.to_tai_duration(),
+                TimeScale::TCG => Self::tt_to_tcg(prime_epoch_offset + TT_OFFSET_MS.milliseconds()),
+                TimeScale::TCB => {
+                    // Compute the TDB duration first, and apply the linear relation to TCB
+                    Self::tdb_to_tcb(
+                        tdb.try_tai_to_tdb(prime_epoch_offset)
+                            .ok_or_else(missing_tdb)?,
+                    )
+                }
+                TimeScale::UT1 => prime_epoch_offset - delta_tai_ut1(prime_epoch_offset)?,
+                TimeScale::Custom(_) => {
+                    #[cfg(feature = "std")]
+                    if let Some(custom) = crate::CustomTimeScale::from_time_scale(ts) {
+                        // Compute the epoch in the base time scale, and read the custom clock at that epoch
+                        let base = Self::from_tai_duration(prime_epoch_offset)
+                            .to_time_scale_inner(
+                                custom.base(),
+                                delta_at,
+                                delta_tai_ut1,
+                                et_model,
+                                tdb,
+                            )?;
+                        return Ok(Self {
+                            duration: custom.reading(base.duration),
+                            time_scale: ts,
+                        });
+                    }
+                    prime_epoch_offset
+                }
             };
 
-            Self {
+            Ok(Self {
                 duration: ts_ref_offset,
                 time_scale: ts,
-            }
+            })
         }
     }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Epoch {
+    #[must_use]
+    /// Converts self to another time scale
+    ///
+    /// As per the [Rust naming convention](https://rust-lang.github.io/api-guidelines/naming.html#ad-hoc-conversions-follow-as_-to_-into_-conventions-c-conv),
+    /// this borrows an Epoch and returns an owned Epoch.
+    ///
+    /// # Panics
+    /// **If UT1 is involved and no registered `Ut1Provider` covers this epoch**, since UT1 cannot be computed without Earth
+    /// Orientation Parameters. To convert to or from UT1, provide the UT1 data with `to_time_scale_with_ut1` (requires the `ut1`
+    /// feature), or check `has_ut1_data`, or use `try_to_time_scale` to get an error instead. The conversions which do not involve
+    /// UT1 never panic.
+    ///
+    /// # UTC before 1972
+    /// UTC follows the SOFA definition, i.e. the UTC offsets from 1960 to 1972 and their drift, exactly as the `iauDat` function
+    /// of SOFA (src/dat.c), such that UTC and TAI differ by about 1 to 10 seconds over that period. Prior to 1960, UTC is not defined
+    /// and is equal to TAI. Use `to_time_scale_with` and `leap_seconds::IersOnly` to only account for the leap seconds announced
+    /// by the IERS, such that UTC equals TAI before 1972.
+    ///
+    /// :type ts: TimeScale
+    /// :rtype: Epoch
+    pub fn to_time_scale(&self, ts: TimeScale) -> Self {
+        Self::expect_ut1(self.to_time_scale_inner(
+            ts,
+            &Self::conversion_delta_at,
+            &Self::try_registered_delta_tai_ut1,
+            EtModel::NAIF0012,
+            &TdbModel::Esa,
+        ))
+    }
+
+    /// Converts self to another time scale, returning an error if UT1 is involved and no UT1 data covers this epoch.
+    ///
+    /// The UT1 data is that of the `Ut1Provider` registered with `Ut1Provider::register` (requires the `ut1` feature), if any:
+    /// use `to_time_scale_with_ut1` to provide it instead. Unlike `to_time_scale`, this never panics.
+    ///
+    /// :type ts: TimeScale
+    /// :rtype: Epoch
+    pub fn try_to_time_scale(&self, ts: TimeScale) -> Result<Self, HifitimeError> {
+        self.to_time_scale_inner(
+            ts,
+            &Self::conversion_delta_at,
+            &Self::try_registered_delta_tai_ut1,
+            EtModel::NAIF0012,
+            &TdbModel::Esa,
+        )
+    }
+
+    #[must_use]
+    /// Returns true if the registered `Ut1Provider` covers this epoch, i.e. if `to_time_scale` and the computations which depend on UT1
+    /// (the sidereal and solar times) can convert this epoch to or from UT1 instead of panicking.
+    ///
+    /// :rtype: bool
+    pub fn has_ut1_data(&self) -> bool {
+        // A UT1 epoch is covered if and only if it can be converted to TAI.
+        self.try_to_time_scale(TimeScale::TAI)
+            .is_ok_and(|tai| Self::registered_delta_tai_ut1(tai.duration).is_some())
+    }
+
+    #[must_use]
+    /// Converts self to another time scale using the SOFA definition of UTC, i.e. accounting for the UTC offsets from 1960 to 1972
+    /// and their drift, exactly as the `iauDat` function of SOFA (src/dat.c).
+    ///
+    /// This is `to_time_scale`, unless the default leap seconds were set to a provider which only accounts for the leap seconds
+    /// announced by the IERS, cf. `leap_seconds::IersOnly`. Prior to 1960, UTC is not defined and is equal to TAI.
+    ///
+    /// **Like `to_time_scale`, this panics if UT1 is involved and no registered `Ut1Provider` covers this epoch.**
+    ///
+    /// :type ts: TimeScale
+    /// :rtype: Epoch
+    pub fn to_time_scale_sofa(&self, ts: TimeScale) -> Self {
+        let delta_at = |duration: Duration, time_scale: TimeScale| {
+            leap_seconds::delta_at_duration(Self::default_delta_at(duration, time_scale, false))
+        };
+        Self::expect_ut1(self.to_time_scale_inner(
+            ts,
+            &delta_at,
+            &Self::try_registered_delta_tai_ut1,
+            EtModel::NAIF0012,
+            &TdbModel::Esa,
+        ))
+    }
+
+    #[must_use]
+    /// Converts self to another time scale using the provided ET model instead of `EtModel::NAIF0012` for Ephemeris Time,
+    /// e.g. the model of the leap seconds kernel loaded in SPICE with `LeapSecondsKernel::et_model`.
+    ///
+    /// **Like `to_time_scale`, this panics if UT1 is involved and no registered `Ut1Provider` covers this epoch.**
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::prelude::*;
+    /// use hifitime::EtModel;
+    ///
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+    /// let model = EtModel { k: 1.6e-3, ..EtModel::NAIF0012 };
+    /// let et = epoch.to_time_scale_with_et_model(TimeScale::ET, model);
+    /// assert_eq!(et.duration, epoch.to_et_duration_with(model));
+    /// // And back to UTC with the same model, within the accuracy of the ET round trip.
+    /// assert!((et.to_time_scale_with_et_model(TimeScale::UTC, model) - epoch).abs() <= 10.nanoseconds());
+    /// ```
+    ///
+    /// :type ts: TimeScale
+    /// :type model: EtModel
+    /// :rtype: Epoch
+    pub fn to_time_scale_with_et_model(&self, ts: TimeScale, model: EtModel) -> Self {
+        Self::expect_ut1(self.to_time_scale_inner(
+            ts,
+            &Self::conversion_delta_at,
+            &Self::try_registered_delta_tai_ut1,
+            model,
+            &TdbModel::Esa,
+        ))
+    }
+
+    #[must_use]
+    /// Converts self to another time scale using the provided TDB model instead of `TdbModel::Esa` for TDB and TCB,
+    /// e.g. `TdbModel::FairheadBretagnon` for a nanosecond TDB. The ET conversions are unaffected.
+    ///
+    /// **Like `to_time_scale`, this panics if UT1 is involved and no registered `Ut1Provider` covers this epoch.**
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::prelude::*;
+    /// use hifitime::TdbModel;
+    ///
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2024, 1, 1);
+    /// let model = TdbModel::FairheadBretagnon;
+    /// let tdb = epoch.to_time_scale_with_tdb_model(TimeScale::TDB, model);
+    /// assert_eq!(tdb.duration, epoch.to_tdb_duration_with(model));
+    /// // And back to UTC with the same model.
+    /// assert!((tdb.to_time_scale_with_tdb_model(TimeScale::UTC, model) - epoch).abs() < 1.nanoseconds());
+    /// ```
+    ///
+    /// :type ts: TimeScale
+    /// :type model: TdbModel
+    /// :rtype: Epoch
+    pub fn to_time_scale_with_tdb_model(&self, ts: TimeScale, model: TdbModel) -> Self {
+        Self::expect_ut1(self.to_time_scale_inner(
+            ts,
+            &Self::conversion_delta_at,
+            &Self::try_registered_delta_tai_ut1,
+            EtModel::NAIF0012,
+            &model,
+        ))
+    }
 
     #[must_use]
     /// Get the accumulated number of leap seconds up to this Epoch accounting only for the IERS leap seconds.
@@ -310,10 +740,17 @@ impl Epoch {
     ///
     /// # Why does this function return an `Option` when the other returns a value
     /// This is to match the `iauDat` function of SOFA (src/dat.c). That function will return a warning and give up if the start date is before 1960.
+    ///
+    /// For an epoch which is not in UTC, a leap second is accounted for once it occurred, i.e. ΔAT seconds after the midnight TAI
+    /// following it: 1972-07-01T00:00:00 TAI is 1972-06-30T23:59:50 UTC, and has 10 leap seconds.
+    ///
+    /// # Default provider
+    /// The leap seconds are those of `LatestLeapSeconds`, unless another provider was set with `leap_seconds::set_default_provider` (requires the `std` feature).
     /// :type iers_only: bool
     /// :rtype: float
     pub fn leap_seconds(&self, iers_only: bool) -> Option<f64> {
-        self.leap_seconds_with(iers_only, LatestLeapSeconds::default())
+        let (duration, time_scale) = self.leap_seconds_lookup();
+        Self::default_delta_at(duration, time_scale, iers_only)
     }
 
     #[cfg(feature = "std")]
@@ -694,6 +1131,90 @@ impl Epoch {
         self.to_nanoseconds_in_time_scale(TimeScale::BDT)
     }
 
+    #[must_use]
+    /// Returns seconds past GLONASST (GLONASS) Time Epoch, defined as 1996 January 01 midnight GLONASST (UTC(SU) + 3 hours).
+    /// :rtype: float
+    pub fn to_glonasst_seconds(&self) -> f64 {
+        self.to_glonasst_duration().to_seconds()
+    }
+
+    #[must_use]
+    /// Returns `Duration` past GLONASST (GLONASS) time Epoch.
+    /// :rtype: Duration
+    pub fn to_glonasst_duration(&self) -> Duration {
+        self.to_time_scale(TimeScale::GLONASST).duration
+    }
+
+    #[must_use]
+    /// Returns days past GLONASST (GLONASS) Time Epoch, defined as 1996 January 01 midnight GLONASST (UTC(SU) + 3 hours).
+    /// :rtype: float
+    pub fn to_glonasst_days(&self) -> f64 {
+        self.to_glonasst_duration().to_unit(Unit::Day)
+    }
+
+    /// Returns nanoseconds past GLONASST (GLONASS) Time Epoch, defined as 1996 January 01 midnight GLONASST (UTC(SU) + 3 hours).
+    /// NOTE: This function will return an error if the centuries past GLONASST time are not zero.
+    /// :rtype: int
+    pub fn to_glonasst_nanoseconds(&self) -> Result<u64, HifitimeError> {
+        self.to_nanoseconds_in_time_scale(TimeScale::GLONASST)
+    }
+
+    /// Returns this epoch as broadcast in the GLONASS navigation message: the four-year interval number `N4`
+    /// (1 for 1996 to 1999), the calendar day number `NT` within that four-year interval (1 on January 1st of the leap year),
+    /// and the nanoseconds elapsed in that GLONASST day.
+    /// NOTE: This function will return an error if `N4` is not within 1 to 255, i.e. before 1996 January 01 GLONASST
+    /// or from 3016 January 01 GLONASST onward.
+    /// :rtype: typing.Tuple
+    pub fn to_glonasst_n4_nt(&self) -> Result<(u8, u16, u64), HifitimeError> {
+        let total_nanoseconds = self.to_glonasst_duration().total_nanoseconds();
+        let days = total_nanoseconds.div_euclid(i128::from(NANOSECONDS_PER_DAY));
+        let nanoseconds = total_nanoseconds.rem_euclid(i128::from(NANOSECONDS_PER_DAY));
+        let n4 = days.div_euclid(i128::from(GLONASST_DAYS_PER_N4)) + 1;
+        let nt = days.rem_euclid(i128::from(GLONASST_DAYS_PER_N4)) + 1;
+        let n4 = match u8::try_from(n4) {
+            Ok(n4) if n4 > 0 => n4,
+            _ => {
+                return Err(HifitimeError::Duration {
+                    source: if n4 > 0 {
+                        DurationError::Overflow
+                    } else {
+                        DurationError::Underflow
+                    },
+                })
+            }
+        };
+        // NT is at most 1461 and the nanoseconds are within a day, so these conversions are lossless.
+        Ok((n4, nt as u16, nanoseconds as u64))
+    }
+
+    #[must_use]
+    /// Returns seconds past IRNSST (NavIC) Time Epoch
+    /// :rtype: float
+    pub fn to_irnsst_seconds(&self) -> f64 {
+        self.to_irnsst_duration().to_seconds()
+    }
+
+    #[must_use]
+    /// Returns `Duration` past IRNSST (NavIC) time Epoch.
+    /// :rtype: Duration
+    pub fn to_irnsst_duration(&self) -> Duration {
+        self.to_time_scale(TimeScale::IRNSST).duration
+    }
+
+    #[must_use]
+    /// Returns days past IRNSST (NavIC) Time Epoch, starting on August 22nd 1999 midnight GPST, i.e. 13 seconds before midnight UTC, like Galileo.
+    /// :rtype: float
+    pub fn to_irnsst_days(&self) -> f64 {
+        self.to_irnsst_duration().to_unit(Unit::Day)
+    }
+
+    /// Returns nanoseconds past IRNSST (NavIC) Time Epoch, starting on August 22nd 1999 midnight GPST, i.e. 13 seconds before midnight UTC, like Galileo.
+    /// NOTE: This function will return an error if the centuries past IRNSST time are not zero.
+    /// :rtype: int
+    pub fn to_irnsst_nanoseconds(&self) -> Result<u64, HifitimeError> {
+        self.to_nanoseconds_in_time_scale(TimeScale::IRNSST)
+    }
+
     #[allow(clippy::wrong_self_convention)]
     #[must_use]
     /// Returns the Duration since the UNIX epoch UTC midnight 01 Jan 1970.
@@ -780,6 +1301,62 @@ impl Epoch {
         self.to_tdb_duration().to_seconds()
     }
 
+    #[must_use]
+    /// Returns `Duration` past J1900 in Geocentric Coordinate Time (TCG), like TT.
+    /// :rtype: Duration
+    pub fn to_tcg_duration(&self) -> Duration {
+        self.to_time_scale(TimeScale::TCG).duration
+    }
+
+    #[must_use]
+    /// Returns seconds past J1900 in Geocentric Coordinate Time (TCG), like TT.
+    /// :rtype: float
+    pub fn to_tcg_seconds(&self) -> f64 {
+        self.to_tcg_duration().to_seconds()
+    }
+
+    #[must_use]
+    /// Returns days past Julian epoch in Geocentric Coordinate Time (TCG)
+    /// :rtype: float
+    pub fn to_jde_tcg_days(&self) -> f64 {
+        self.to_jde_tcg_duration().to_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// :rtype: Duration
+    pub fn to_jde_tcg_duration(&self) -> Duration {
+        self.to_tcg_duration() + Unit::Day * (MJD_J1900 + MJD_OFFSET)
+    }
+
+    #[must_use]
+    /// Returns `Duration` past J2000 in Barycentric Coordinate Time (TCB), like TDB.
+    /// :rtype: Duration
+    pub fn to_tcb_duration(&self) -> Duration {
+        self.to_time_scale(TimeScale::TCB).duration
+    }
+
+    #[must_use]
+    /// Returns seconds past J2000 in Barycentric Coordinate Time (TCB), like TDB.
+    /// :rtype: float
+    pub fn to_tcb_seconds(&self) -> f64 {
+        self.to_tcb_duration().to_seconds()
+    }
+
+    #[must_use]
+    /// Returns days past Julian epoch in Barycentric Coordinate Time (TCB)
+    /// :rtype: float
+    pub fn to_jde_tcb_days(&self) -> f64 {
+        self.to_jde_tcb_duration().to_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// :rtype: Duration
+    pub fn to_jde_tcb_duration(&self) -> Duration {
+        self.to_tcb_duration()
+            + Unit::Day * (MJD_J1900 + MJD_OFFSET)
+            + TimeScale::TCB.prime_epoch_offset()
+    }
+
     #[must_use]
     /// Returns the Ephemeris Time JDE past epoch
     /// :rtype: float
@@ -947,6 +1524,9 @@ impl FromStr for Epoch {
     ///  + JD: Julian days
     ///  + MJD: Modified Julian days
     ///  + SEC: Seconds past a given epoch (e.g. SEC 17.2 TAI is 17.2 seconds past TAI Epoch)
+    ///
+    /// Epochs suffixed by the name of a registered `CustomTimeScale` are returned in that custom time scale,
+    /// either from seconds (e.g. SEC 17.2 MET) or from a Gregorian date in that time scale.
     /// # Example
     /// ```
     /// use hifitime::Epoch;
@@ -981,13 +1561,18 @@ impl FromStr for Epoch {
             };
 
             // This is a valid numerical format.
-            // Parse the time scale from the last three characters (TS trims white spaces).
-            let ts = TimeScale::from_str(&s[s.len() - 3..]).with_context(|_| ParseSnafu {
+            // Parse the time scale from the last word, which may be longer than three characters (e.g. GLONASST).
+            let (num_str, ts_str) = s[format.len()..]
+                .trim()
+                .rsplit_once(char::is_whitespace)
+                .ok_or(HifitimeError::Parse {
+                    source: ParsingError::TimeSystem,
+                    details: "missing time scale",
+                })?;
+            let ts = TimeScale::from_str(ts_str).with_context(|_| ParseSnafu {
                 details: "parsing from string",
             })?;
-            // Iterate through the string to figure out where the numeric data starts and ends.
-            let start_idx = format.len();
-            let num_str = s[start_idx..s.len() - ts.formatted_len()].trim();
+            let num_str = num_str.trim();
             let value: f64 = match lexical_core::parse(num_str.as_bytes()) {
                 Ok(val) => val,
                 Err(_) => {
@@ -1011,9 +1596,11 @@ impl FromStr for Epoch {
                 },
                 "MJD" => match ts {
                     TimeScale::TAI => Ok(Self::from_mjd_tai(value)),
-                    TimeScale::UTC | TimeScale::GPST | TimeScale::BDT | TimeScale::GST => {
-                        Ok(Self::from_mjd_in_time_scale(value, ts))
-                    }
+                    TimeScale::UTC
+                    | TimeScale::GPST
+                    | TimeScale::BDT
+                    | TimeScale::GST
+                    | TimeScale::IRNSST => Ok(Self::from_mjd_in_time_scale(value, ts)),
                     _ => Err(HifitimeError::Parse {
                         source: ParsingError::UnsupportedTimeSystem,
                         details: "for Modified Julian Date",
@@ -0,0 +1,281 @@
+/*
+* Hifitime
+* Copyright (C) 2017-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+* This Source Code Form is subject to the terms of the Mozilla Public
+* License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at https://mozilla.org/MPL/2.0/.
+*
+* Documentation: https://nyxspace.com/
+*/
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)] // Import is indeed used.
+use num_traits::Float;
+
+use super::sidereal::ARCSEC_TO_RAD;
+use super::TdbModel;
+use crate::{Duration, Epoch, TimeUnits, DAYS_PER_CENTURY, SECONDS_PER_DAY};
+
+/// Speed of light in vacuum, in km/s.
+pub(crate) const SPEED_OF_LIGHT_KM_S: f64 = 299_792.458;
+/// Heliocentric gravitational constant, in km^3/s^2.
+const GM_SUN_KM3_S2: f64 = 1.327_124_400_41e11;
+/// Astronomical unit, in km.
+const AU_KM: f64 = 149_597_870.7;
+/// Obliquity of the ecliptic at J2000 (IAU 2006), in arcseconds.
+const OBLIQUITY_J2000_ARCSEC: f64 = 84381.406;
+/// Nominal rotation rate of the Earth, in rad/s.
+const EARTH_ROTATION_RATE_RAD_S: f64 = 7.292_115e-5;
+
+/// The frame of the geocentric position (and velocity) of an `Observer`.
+#[cfg_attr(kani, derive(kani::Arbitrary))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+pub enum ObserverFrame {
+    /// A frame rotating with the Earth, e.g. ITRF, where ground stations are fixed.
+    /// The polar motion (a few meters at the surface) and the nutation are neglected.
+    #[default]
+    EarthFixed,
+    /// A geocentric inertial frame aligned with the ICRF (i.e. GCRF, or EME2000 within the frame bias).
+    Inertial,
+}
+
+/// An observer on or near the Earth, e.g. a ground station or an Earth orbiter, for the topocentric TDB conversions.
+///
+/// Away from the geocenter, TDB - TT gains the term v_E · x / c^2, where v_E is the barycentric velocity of the Earth and x the
+/// geocentric position of the observer: this is a diurnal term of about 2 µs for a ground station, and up to about 130 µs at the distance of the Moon.
+/// The velocity of the Earth comes from its mean Keplerian orbit, accurate to about 0.1 %, i.e. about a nanosecond for a ground station.
+///
+/// (Python documentation hints)
+/// :type frame: ObserverFrame
+/// :type position_km: list
+/// :type velocity_km_s: list, optional
+/// :rtype: Observer
+#[cfg_attr(feature = "python", pyclass)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Observer {
+    /// The frame of the position and velocity.
+    pub frame: ObserverFrame,
+    /// The geocentric position of the observer, in km.
+    pub position_km: [f64; 3],
+    /// The geocentric velocity of the observer, in km/s, only needed for the rate of TDB - TT.
+    pub velocity_km_s: Option<[f64; 3]>,
+}
+
+impl Observer {
+    /// Initializes an observer from its position in an Earth-fixed frame, in km, e.g. that of a ground station.
+    pub const fn earth_fixed(position_km: [f64; 3]) -> Self {
+        Self {
+            frame: ObserverFrame::EarthFixed,
+            position_km,
+            velocity_km_s: None,
+        }
+    }
+
+    /// Initializes an observer from its geocentric position in an inertial frame, in km, e.g. that of an Earth orbiter.
+    pub const fn inertial(position_km: [f64; 3]) -> Self {
+        Self {
+            frame: ObserverFrame::Inertial,
+            position_km,
+            velocity_km_s: None,
+        }
+    }
+
+    /// Returns a copy of this observer with the provided velocity in its frame, in km/s.
+    pub const fn with_velocity(mut self, velocity_km_s: [f64; 3]) -> Self {
+        self.velocity_km_s = Some(velocity_km_s);
+        self
+    }
+
+    /// Returns the topocentric term of TDB - TT at the provided epoch, i.e. v_E · x / c^2, to add to the geocentric TDB - TT.
+    pub fn topocentric_offset(&self, epoch: &Epoch) -> Duration {
+        let (_, velocity_earth) = earth_heliocentric_state(epoch);
+        let position = self.inertial_state(epoch).0;
+        (dot(velocity_earth, position) / SPEED_OF_LIGHT_KM_S.powi(2)).seconds()
+    }
+
+    /// Returns the rate of the topocentric term of TDB - TT at the provided epoch, in seconds per second, i.e.
+    /// (a_E · x + v_E · v) / c^2, e.g. to correct the frequency of a clock of this observer.
+    /// Returns None if the velocity of the observer is unknown.
+    pub fn topocentric_rate(&self, epoch: &Epoch) -> Option<f64> {
+        let (position_earth, velocity_earth) = earth_heliocentric_state(epoch);
+        let (position, velocity) = self.inertial_state(epoch);
+        let velocity = velocity?;
+        // The acceleration of the Earth is dominated by the attraction of the Sun.
+        let distance = dot(position_earth, position_earth).sqrt();
+        let acceleration_earth = position_earth.map(|x| -GM_SUN_KM3_S2 * x / distance.powi(3));
+        Some(
+            (dot(acceleration_earth, position) + dot(velocity_earth, velocity))
+                / SPEED_OF_LIGHT_KM_S.powi(2),
+        )
+    }
+
+    /// Returns the position and velocity of this observer in the inertial frame.
+    fn inertial_state(&self, epoch: &Epoch) -> ([f64; 3], Option<[f64; 3]>) {
+        match self.frame {
+            ObserverFrame::Inertial => (self.position_km, self.velocity_km_s),
+            ObserverFrame::EarthFixed => {
+                // Rotate by the sidereal time into the mean equator and equinox of date, then precess to J2000.
+                // The sidereal time is computed from UTC, which is within 0.9 s of UT1: this displaces the observer by
+                // at most 0.5 km, i.e. changes the topocentric term by less than 0.2 ns, so this never requires UT1 data.
+                let t = epoch.to_tt_centuries_j2k();
+                let gmst = Epoch::gmst_at(epoch.to_utc_duration(), t);
+                let to_inertial = |vector: [f64; 3]| unprecess(rotate_z(vector, gmst), t);
+                let velocity = self.velocity_km_s.map(|velocity| {
+                    // Add the velocity due to the rotation of the Earth, ω × x.
+                    let [x, y, _] = self.position_km;
+                    let rotation = [
+                        -EARTH_ROTATION_RATE_RAD_S * y,
+                        EARTH_ROTATION_RATE_RAD_S * x,
+                        0.0,
+                    ];
+                    to_inertial([
+                        velocity[0] + rotation[0],
+                        velocity[1] + rotation[1],
+                        velocity[2] + rotation[2],
+                    ])
+                });
+                (to_inertial(self.position_km), velocity)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(feature = "python", pymethods)]
+impl Observer {
+    #[new]
+    #[pyo3(signature=(frame, position_km, velocity_km_s=None))]
+    pub fn __new__(
+        frame: ObserverFrame,
+        position_km: [f64; 3],
+        velocity_km_s: Option<[f64; 3]>,
+    ) -> Self {
+        Self {
+            frame,
+            position_km,
+            velocity_km_s,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?} @ {self:p}")
+    }
+
+    /// Returns the topocentric term of TDB - TT at the provided epoch, i.e. v_E · x / c^2, to add to the geocentric TDB - TT.
+    ///
+    /// :type epoch: Epoch
+    /// :rtype: Duration
+    #[pyo3(name = "topocentric_offset")]
+    fn py_topocentric_offset(&self, epoch: Epoch) -> Duration {
+        self.topocentric_offset(&epoch)
+    }
+
+    /// Returns the rate of the topocentric term of TDB - TT at the provided epoch, in seconds per second, i.e.
+    /// (a_E · x + v_E · v) / c^2, e.g. to correct the frequency of a clock of this observer.
+    /// Returns None if the velocity of the observer is unknown.
+    ///
+    /// :type epoch: Epoch
+    /// :rtype: float
+    #[pyo3(name = "topocentric_rate")]
+    fn py_topocentric_rate(&self, epoch: Epoch) -> Option<f64> {
+        self.topocentric_rate(&epoch)
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Epoch {
+    #[must_use]
+    /// Returns the Barycentric Dynamical Time (TDB) duration past J2000 of this epoch at the provided observer, i.e. the geocentric
+    /// TDB of the provided model plus the topocentric term of `Observer::topocentric_offset`.
+    ///
+    /// :type observer: Observer
+    /// :type model: TdbModel
+    /// :rtype: Duration
+    pub fn to_tdb_duration_at(&self, observer: &Observer, model: TdbModel) -> Duration {
+        self.to_tdb_duration_with(model) + observer.topocentric_offset(self)
+    }
+}
+
+impl Epoch {
+    #[must_use]
+    /// Initialize an Epoch from the provided Barycentric Dynamical Time (TDB) duration past J2000 at the provided observer,
+    /// with the provided model for the geocentric TDB, i.e. the reverse of `to_tdb_duration_at`.
+    ///
+    /// The returned epoch is in TAI so that its subsequent conversions do not depend on the observer.
+    pub fn from_tdb_duration_at(
+        duration_since_j2000: Duration,
+        observer: &Observer,
+        model: TdbModel,
+    ) -> Self {
+        // The topocentric term varies by less than a nanosecond over its own amplitude: evaluating it once suffices.
+        let geocentric = Self::from_tai_duration(model.tdb_to_tai(duration_since_j2000));
+        Self::from_tai_duration(
+            model.tdb_to_tai(duration_since_j2000 - observer.topocentric_offset(&geocentric)),
+        )
+    }
+}
+
+/// Returns the heliocentric position (km) and velocity (km/s) of the Earth-Moon barycenter, in the equator and equinox of J2000,
+/// from its mean Keplerian elements (Standish, valid from 1800 to 2050 within a few arcseconds).
+fn earth_heliocentric_state(epoch: &Epoch) -> ([f64; 3], [f64; 3]) {
+    let t = epoch.to_tt_centuries_j2k();
+    let a = 1.000_002_61 * AU_KM;
+    let e = 0.016_711_23 - 0.000_043_92 * t;
+    let perihelion = (102.937_681_93 + 0.323_273_64 * t).to_radians();
+    let mean_longitude = (100.464_571_66 + 35_999.372_449_81 * t).to_radians();
+    let mean_motion = 35_999.372_449_81_f64.to_radians() / (DAYS_PER_CENTURY * SECONDS_PER_DAY);
+
+    // Solve Kepler's equation for the eccentric anomaly.
+    let mean_anomaly = mean_longitude - perihelion;
+    let mut anomaly = mean_anomaly;
+    for _ in 0..5 {
+        anomaly -= (anomaly - e * anomaly.sin() - mean_anomaly) / (1.0 - e * anomaly.cos());
+    }
+    let (sin_e, cos_e) = anomaly.sin_cos();
+    let sqrt = (1.0 - e * e).sqrt();
+
+    // In the orbital plane, with the x axis towards the perihelion, then in the ecliptic and the equator of J2000.
+    let position = [a * (cos_e - e), a * sqrt * sin_e, 0.0];
+    let rate = a * mean_motion / (1.0 - e * cos_e);
+    let velocity = [-rate * sin_e, rate * sqrt * cos_e, 0.0];
+
+    let obliquity = OBLIQUITY_J2000_ARCSEC * ARCSEC_TO_RAD;
+    let to_equator = |vector: [f64; 3]| rotate_x(rotate_z(vector, perihelion), obliquity);
+    (to_equator(position), to_equator(velocity))
+}
+
+/// Rotates the provided vector of the mean equator and equinox of date to those of J2000, with the IAU 2006 precession angles,
+/// at the provided number of Julian centuries of TT past J2000.
+fn unprecess(vector: [f64; 3], t: f64) -> [f64; 3] {
+    let zeta = (2.650545 + (2306.083227 + (0.2988499 + 0.01801828 * t) * t) * t) * ARCSEC_TO_RAD;
+    let z = (-2.650545 + (2306.077181 + (1.0927348 + 0.01826837 * t) * t) * t) * ARCSEC_TO_RAD;
+    let theta = ((2004.191903 + (-0.4294934 - 0.04182264 * t) * t) * t) * ARCSEC_TO_RAD;
+    // The precession rotates J2000 to date by R3(-z) R2(θ) R3(-ζ): apply its transpose.
+    rotate_z(rotate_y(rotate_z(vector, -z), theta), -zeta)
+}
+
+/// Rotates the provided vector by the provided angle about the x axis.
+fn rotate_x([x, y, z]: [f64; 3], angle: f64) -> [f64; 3] {
+    let (sin, cos) = angle.sin_cos();
+    [x, cos * y - sin * z, sin * y + cos * z]
+}
+
+/// Rotates the provided vector by the provided angle about the y axis.
+fn rotate_y([x, y, z]: [f64; 3], angle: f64) -> [f64; 3] {
+    let (sin, cos) = angle.sin_cos();
+    [cos * x + sin * z, y, -sin * x + cos * z]
+}
+
+/// Rotates the provided vector by the provided angle about the z axis.
+fn rotate_z([x, y, z]: [f64; 3], angle: f64) -> [f64; 3] {
+    let (sin, cos) = angle.sin_cos();
+    [cos * x - sin * y, sin * x + cos * y, z]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
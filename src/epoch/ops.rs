@@ -244,7 +244,8 @@ impl Sub for Epoch {
     type Output = Duration;
 
     fn sub(self, other: Self) -> Duration {
-        self.duration - other.to_time_scale(self.time_scale).duration
+        let (duration, other_duration) = self.comparable_durations(&other);
+        duration - other_duration
     }
 }
 
@@ -342,33 +343,51 @@ impl PartialEq for Epoch {
             // If one of the two time scales does not include leap seconds,
             // we always convert the time scale with leap seconds into the
             // time scale that does NOT have leap seconds.
-            if self.time_scale.uses_leap_seconds() != other.time_scale.uses_leap_seconds() {
-                if self.time_scale.uses_leap_seconds() {
-                    self.to_time_scale(other.time_scale).duration == other.duration
+            let (duration, other_duration) =
+                if self.time_scale.uses_leap_seconds() && !other.time_scale.uses_leap_seconds() {
+                    let (other_duration, duration) = other.comparable_durations(self);
+                    (duration, other_duration)
                 } else {
-                    self.duration == other.to_time_scale(self.time_scale).duration
-                }
-            } else {
-                // Otherwise it does not matter
-                self.duration == other.to_time_scale(self.time_scale).duration
-            }
+                    // Otherwise it does not matter
+                    self.comparable_durations(other)
+                };
+            duration == other_duration
         }
     }
 }
 
 impl PartialOrd for Epoch {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(
-            self.duration
-                .cmp(&other.to_time_scale(self.time_scale).duration),
-        )
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Epoch {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.duration
-            .cmp(&other.to_time_scale(self.time_scale).duration)
+        let (duration, other_duration) = self.comparable_durations(other);
+        duration.cmp(&other_duration)
+    }
+}
+
+impl Epoch {
+    /// Returns the durations of self and of the other epoch in the time scale of self, to compare and subtract them.
+    ///
+    /// These operators never panic: if UT1 is involved and the registered UT1 provider does not cover these epochs,
+    /// both are compared in UTC instead, where the duration of a UT1 epoch is used as is, since UT1 - UTC is within 0.9 s.
+    fn comparable_durations(&self, other: &Self) -> (Duration, Duration) {
+        match other.try_to_time_scale(self.time_scale) {
+            Ok(other) => (self.duration, other.duration),
+            Err(_) => (
+                self.approximate_utc_duration(),
+                other.approximate_utc_duration(),
+            ),
+        }
+    }
+
+    /// Returns the duration of this epoch in UTC, approximating UT1 by UTC if there is no UT1 data for it.
+    fn approximate_utc_duration(&self) -> Duration {
+        self.try_to_time_scale(TimeScale::UTC)
+            .map_or(self.duration, |utc| utc.duration)
     }
 }
 
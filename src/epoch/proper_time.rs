@@ -0,0 +1,316 @@
+/*
+* Hifitime
+* Copyright (C) 2017-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+* This Source Code Form is subject to the terms of the Mozilla Public
+* License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at https://mozilla.org/MPL/2.0/.
+*
+* Documentation: https://nyxspace.com/
+*/
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use super::observer::SPEED_OF_LIGHT_KM_S;
+use crate::{Duration, Epoch, HifitimeError, TimeScale, TimeSeries, TimeUnits, IAU_L_B, IAU_L_G};
+
+/// A sample of the trajectory of a clock, e.g. that of a spacecraft, for the integration of its proper time.
+///
+/// (Python documentation hints)
+/// :type epoch: Epoch
+/// :type potential_km2_s2: float
+/// :type speed_km_s: float
+/// :rtype: TrajectorySample
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TrajectorySample {
+    pub epoch: Epoch,
+    /// The (positive) gravitational potential at the clock, in km^2/s^2, e.g. GM/r for a point mass: geocentric (plus the tidal
+    /// potentials) for TT and TCG, barycentric for TDB and TCB.
+    pub potential_km2_s2: f64,
+    /// The speed of the clock, in km/s: geocentric for TT and TCG, barycentric for TDB and TCB.
+    pub speed_km_s: f64,
+}
+
+impl TrajectorySample {
+    pub const fn new(epoch: Epoch, potential_km2_s2: f64, speed_km_s: f64) -> Self {
+        Self {
+            epoch,
+            potential_km2_s2,
+            speed_km_s,
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(feature = "python", pymethods)]
+impl TrajectorySample {
+    #[new]
+    fn __new__(epoch: Epoch, potential_km2_s2: f64, speed_km_s: f64) -> Self {
+        Self::new(epoch, potential_km2_s2, speed_km_s)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?} @ {self:p}")
+    }
+}
+
+/// The proper time τ of a clock along its trajectory, i.e. the time it would measure, integrated from
+/// dτ/dt = 1 - (U/c^2 + v^2/(2 c^2)) in the coordinate time t of TCG or TCB, to first order in 1/c^2.
+///
+/// The coordinate time scale is one of TT, TCG, TDB or TCB: TT and TDB run slower than TCG and TCB by `IAU_L_G` and `IAU_L_B`,
+/// which are accounted for, such that a clock on the geoid has the same rate as TT.
+/// The proper time is a duration past the reference epoch of that time scale, equal to the coordinate time plus the initial offset
+/// at the first sample. Between the samples, the rate of the proper time is interpolated linearly, and integrated exactly.
+///
+/// (Python documentation hints)
+/// :type samples: list
+/// :type time_scale: TimeScale
+/// :type initial_offset: Duration
+/// :rtype: ProperTime
+#[cfg_attr(feature = "python", pyclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProperTime {
+    time_scale: TimeScale,
+    /// Durations of the samples in the coordinate time scale.
+    durations: Vec<Duration>,
+    /// τ - t at each sample, in seconds.
+    offsets: Vec<f64>,
+    /// d(τ - t)/dt at each sample.
+    rates: Vec<f64>,
+}
+
+impl ProperTime {
+    /// Integrates the proper time along the provided trajectory samples, in any order, in the provided coordinate time scale
+    /// (TT, TCG, TDB or TCB), where τ - t is `initial_offset` at the first sample.
+    ///
+    /// Returns an error if the time scale is not supported, if there are fewer than two samples, or if two samples are at the same epoch.
+    pub fn from_samples(
+        samples: &[TrajectorySample],
+        time_scale: TimeScale,
+        initial_offset: Duration,
+    ) -> Result<Self, HifitimeError> {
+        // Coordinate time scales other than TCG and TCB run slower by a constant rate.
+        let reference_rate = match time_scale {
+            TimeScale::TCG | TimeScale::TCB => 0.0,
+            TimeScale::TT => IAU_L_G,
+            TimeScale::TDB => IAU_L_B,
+            _ => {
+                return Err(HifitimeError::InvalidTrajectory {
+                    details: "proper time requires TT, TCG, TDB or TCB",
+                })
+            }
+        };
+        if samples.len() < 2 {
+            return Err(HifitimeError::InvalidTrajectory {
+                details: "proper time requires at least two samples",
+            });
+        }
+
+        let mut samples: Vec<(Duration, f64)> = samples
+            .iter()
+            .map(|sample| {
+                let rate = reference_rate
+                    - (sample.potential_km2_s2 + sample.speed_km_s.powi(2) / 2.0)
+                        / SPEED_OF_LIGHT_KM_S.powi(2);
+                (sample.epoch.to_time_scale(time_scale).duration, rate)
+            })
+            .collect();
+        samples.sort_by_key(|(duration, _)| *duration);
+        if samples.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            return Err(HifitimeError::InvalidTrajectory {
+                details: "proper time samples must be at distinct epochs",
+            });
+        }
+
+        let (durations, rates): (Vec<Duration>, Vec<f64>) = samples.into_iter().unzip();
+        // Trapezoidal integration of the rate.
+        let mut offsets = vec![initial_offset.to_seconds()];
+        for i in 1..durations.len() {
+            let step = (durations[i] - durations[i - 1]).to_seconds();
+            offsets.push(offsets[i - 1] + (rates[i - 1] + rates[i]) * step / 2.0);
+        }
+
+        Ok(Self {
+            time_scale,
+            durations,
+            offsets,
+            rates,
+        })
+    }
+
+    /// Integrates the proper time from `start` to `end` every `step`, where the callback returns the potential (km^2/s^2)
+    /// and the speed (km/s) of the clock at the provided epoch, cf. `from_samples`.
+    ///
+    /// Returns an `InvalidTrajectory` error if `step` is not strictly positive.
+    pub fn from_fn<F: FnMut(Epoch) -> (f64, f64)>(
+        start: Epoch,
+        end: Epoch,
+        step: Duration,
+        time_scale: TimeScale,
+        initial_offset: Duration,
+        mut trajectory: F,
+    ) -> Result<Self, HifitimeError> {
+        if step <= Duration::ZERO {
+            return Err(HifitimeError::InvalidTrajectory {
+                details: "proper time step must be positive",
+            });
+        }
+        let start = start.to_time_scale(time_scale);
+        let end = end.to_time_scale(time_scale);
+        let mut epochs: Vec<Epoch> = TimeSeries::inclusive(start, end, step).collect();
+        if epochs.last().is_some_and(|last| *last < end) {
+            epochs.push(end);
+        }
+
+        let samples: Vec<TrajectorySample> = epochs
+            .into_iter()
+            .map(|epoch| {
+                let (potential_km2_s2, speed_km_s) = trajectory(epoch);
+                TrajectorySample::new(epoch, potential_km2_s2, speed_km_s)
+            })
+            .collect();
+        Self::from_samples(&samples, time_scale, initial_offset)
+    }
+
+    /// Returns the coordinate time scale of this proper time.
+    pub fn time_scale(&self) -> TimeScale {
+        self.time_scale
+    }
+
+    /// Returns the first epoch of the trajectory, in the coordinate time scale.
+    pub fn start(&self) -> Epoch {
+        Epoch::from_duration(self.durations[0], self.time_scale)
+    }
+
+    /// Returns the last epoch of the trajectory, in the coordinate time scale.
+    pub fn end(&self) -> Epoch {
+        Epoch::from_duration(self.durations[self.durations.len() - 1], self.time_scale)
+    }
+
+    /// Returns τ - t at the provided duration of the coordinate time scale, if the trajectory covers it.
+    fn offset_at(&self, duration: Duration) -> Option<f64> {
+        let last = self.durations.len() - 1;
+        if duration < self.durations[0] || duration > self.durations[last] {
+            return None;
+        }
+        let i = (self.durations.partition_point(|sample| *sample <= duration) - 1).min(last - 1);
+        let elapsed = (duration - self.durations[i]).to_seconds();
+        let step = (self.durations[i + 1] - self.durations[i]).to_seconds();
+        Some(
+            self.offsets[i]
+                + self.rates[i] * elapsed
+                + (self.rates[i + 1] - self.rates[i]) * elapsed.powi(2) / (2.0 * step),
+        )
+    }
+
+    /// Returns the offset of the proper time from the coordinate time, τ - t, at the provided epoch.
+    /// Returns an error if the trajectory does not cover this epoch.
+    pub fn offset(&self, epoch: &Epoch) -> Result<Duration, HifitimeError> {
+        self.offset_at(epoch.to_time_scale(self.time_scale).duration)
+            .map(|offset| offset.seconds())
+            .ok_or(HifitimeError::MissingProperTimeData { epoch: *epoch })
+    }
+
+    /// Returns the proper time at the provided epoch, as a duration past the reference epoch of the coordinate time scale.
+    /// Returns an error if the trajectory does not cover this epoch.
+    pub fn to_proper_time(&self, epoch: &Epoch) -> Result<Duration, HifitimeError> {
+        let coordinate = epoch.to_time_scale(self.time_scale).duration;
+        Ok(coordinate + self.offset(epoch)?)
+    }
+
+    /// Returns the epoch, in the coordinate time scale, at which the clock reads the provided proper time.
+    /// Returns an error if the trajectory does not cover that epoch.
+    pub fn from_proper_time(&self, proper_time: Duration) -> Result<Epoch, HifitimeError> {
+        // τ - t varies by nanoseconds per second at most: the fixed point iteration converges immediately.
+        let mut coordinate = proper_time;
+        for _ in 0..3 {
+            let offset =
+                self.offset_at(coordinate)
+                    .ok_or(HifitimeError::MissingProperTimeData {
+                        epoch: Epoch::from_duration(coordinate, self.time_scale),
+                    })?;
+            coordinate = proper_time - offset.seconds();
+        }
+        let epoch = Epoch::from_duration(coordinate, self.time_scale);
+        // The last iteration may have stepped just outside of the trajectory.
+        self.offset(&epoch)?;
+        Ok(epoch)
+    }
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(feature = "python", pymethods)]
+impl ProperTime {
+    #[new]
+    fn __new__(
+        samples: Vec<TrajectorySample>,
+        time_scale: TimeScale,
+        initial_offset: Duration,
+    ) -> Result<Self, HifitimeError> {
+        Self::from_samples(&samples, time_scale, initial_offset)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ProperTime in {:?} from {} to {} @ {self:p}",
+            self.time_scale,
+            self.start(),
+            self.end()
+        )
+    }
+
+    /// Returns the coordinate time scale of this proper time.
+    ///
+    /// :rtype: TimeScale
+    #[pyo3(name = "time_scale")]
+    fn py_time_scale(&self) -> TimeScale {
+        self.time_scale()
+    }
+
+    /// Returns the first epoch of the trajectory, in the coordinate time scale.
+    ///
+    /// :rtype: Epoch
+    #[pyo3(name = "start")]
+    fn py_start(&self) -> Epoch {
+        self.start()
+    }
+
+    /// Returns the last epoch of the trajectory, in the coordinate time scale.
+    ///
+    /// :rtype: Epoch
+    #[pyo3(name = "end")]
+    fn py_end(&self) -> Epoch {
+        self.end()
+    }
+
+    /// Returns the offset of the proper time from the coordinate time, τ - t, at the provided epoch.
+    /// Returns an error if the trajectory does not cover this epoch.
+    ///
+    /// :type epoch: Epoch
+    /// :rtype: Duration
+    #[pyo3(name = "offset")]
+    fn py_offset(&self, epoch: Epoch) -> Result<Duration, HifitimeError> {
+        self.offset(&epoch)
+    }
+
+    /// Returns the proper time at the provided epoch, as a duration past the reference epoch of the coordinate time scale.
+    /// Returns an error if the trajectory does not cover this epoch.
+    ///
+    /// :type epoch: Epoch
+    /// :rtype: Duration
+    #[pyo3(name = "to_proper_time")]
+    fn py_to_proper_time(&self, epoch: Epoch) -> Result<Duration, HifitimeError> {
+        self.to_proper_time(&epoch)
+    }
+
+    /// Returns the epoch, in the coordinate time scale, at which the clock reads the provided proper time.
+    /// Returns an error if the trajectory does not cover that epoch.
+    ///
+    /// :type proper_time: Duration
+    /// :rtype: Epoch
+    #[pyo3(name = "from_proper_time")]
+    fn py_from_proper_time(&self, proper_time: Duration) -> Result<Epoch, HifitimeError> {
+        self.from_proper_time(proper_time)
+    }
+}
@@ -15,6 +15,7 @@ use crate::{prelude::Format, Duration, Epoch, HifitimeError, TimeScale};
 use core::str::FromStr;
 
 use crate::epoch::leap_seconds_file::LeapSecondsFile;
+use crate::ut1::Ut1Provider;
 use pyo3::prelude::*;
 use pyo3::pyclass::CompareOp;
 use pyo3::types::{PyDateAccess, PyDateTime, PyTimeAccess, PyType, PyTzInfoAccess};
@@ -152,6 +153,38 @@ impl Epoch {
         Self::from_tdb_duration(duration_since_j2000)
     }
 
+    #[classmethod]
+    /// Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) seconds past J1900, like TT.
+    /// :type seconds: float
+    /// :rtype: Epoch
+    fn init_from_tcg_seconds(_cls: &Bound<'_, PyType>, seconds: f64) -> Self {
+        Self::from_tcg_seconds(seconds)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) duration past J1900, like TT.
+    /// :type duration: Duration
+    /// :rtype: Epoch
+    fn init_from_tcg_duration(_cls: &Bound<'_, PyType>, duration: Duration) -> Self {
+        Self::from_tcg_duration(duration)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) seconds past J2000, like TDB.
+    /// :type seconds_j2000: float
+    /// :rtype: Epoch
+    fn init_from_tcb_seconds(_cls: &Bound<'_, PyType>, seconds_j2000: f64) -> Self {
+        Self::from_tcb_seconds(seconds_j2000)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) duration past J2000, like TDB.
+    /// :type duration_since_j2000: Duration
+    /// :rtype: Epoch
+    fn init_from_tcb_duration(_cls: &Bound<'_, PyType>, duration_since_j2000: Duration) -> Self {
+        Self::from_tcb_duration(duration_since_j2000)
+    }
+
     #[classmethod]
     /// Initialize from the JDE days
     /// :type days: float
@@ -283,6 +316,78 @@ impl Epoch {
         Self::from_bdt_nanoseconds(nanoseconds)
     }
 
+    #[classmethod]
+    /// Initialize an Epoch from the number of seconds since the GLONASS Time Epoch,
+    /// defined as 1996 January 1st midnight GLONASST, i.e. 1995 December 31st at 21:00:00 UTC.
+    /// :type seconds: float
+    /// :rtype: Epoch
+    fn init_from_glonasst_seconds(_cls: &Bound<'_, PyType>, seconds: f64) -> Self {
+        Self::from_glonasst_seconds(seconds)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the number of days since the GLONASS Time Epoch,
+    /// defined as 1996 January 1st midnight GLONASST, i.e. 1995 December 31st at 21:00:00 UTC.
+    /// :type days: float
+    /// :rtype: Epoch
+    fn init_from_glonasst_days(_cls: &Bound<'_, PyType>, days: f64) -> Self {
+        Self::from_glonasst_days(days)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the number of nanoseconds since the GLONASS Time Epoch,
+    /// defined as 1996 January 1st midnight GLONASST, i.e. 1995 December 31st at 21:00:00 UTC.
+    /// This may be useful for time keeping devices that use GLONASS as a time source.
+    /// :type nanoseconds: int
+    /// :rtype: Epoch
+    fn init_from_glonasst_nanoseconds(_cls: &Bound<'_, PyType>, nanoseconds: u64) -> Self {
+        Self::from_glonasst_nanoseconds(nanoseconds)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch in GLONASST from the four-year interval number N4 (1 for 1996 to 1999),
+    /// the calendar day number NT within that interval, and the nanoseconds elapsed in that day.
+    /// :type n4: int
+    /// :type nt: int
+    /// :type nanoseconds: int
+    /// :rtype: Epoch
+    fn init_from_glonasst_n4_nt(
+        _cls: &Bound<'_, PyType>,
+        n4: u8,
+        nt: u16,
+        nanoseconds: u64,
+    ) -> Result<Self, HifitimeError> {
+        Self::from_glonasst_n4_nt(n4, nt, nanoseconds)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the number of seconds since the NavIC (IRNSS) Time Epoch,
+    /// starting on August 22nd 1999 Midnight UT.
+    /// :type seconds: float
+    /// :rtype: Epoch
+    fn init_from_irnsst_seconds(_cls: &Bound<'_, PyType>, seconds: f64) -> Self {
+        Self::from_irnsst_seconds(seconds)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the number of days since the NavIC (IRNSS) Time Epoch,
+    /// starting on August 22nd 1999 Midnight UT.
+    /// :type days: float
+    /// :rtype: Epoch
+    fn init_from_irnsst_days(_cls: &Bound<'_, PyType>, days: f64) -> Self {
+        Self::from_irnsst_days(days)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the number of nanoseconds since the NavIC (IRNSS) Time Epoch,
+    /// starting on August 22nd 1999 Midnight UT.
+    /// This may be useful for time keeping devices that use NavIC as a time source.
+    /// :type nanoseconds: int
+    /// :rtype: Epoch
+    fn init_from_irnsst_nanoseconds(_cls: &Bound<'_, PyType>, nanoseconds: u64) -> Self {
+        Self::from_irnsst_nanoseconds(nanoseconds)
+    }
+
     #[classmethod]
     /// Initialize an Epoch from the provided UNIX second timestamp since UTC midnight 1970 January 01.
     /// :type seconds: float
@@ -428,11 +533,37 @@ impl Epoch {
     pub fn leap_seconds_with_file(
         &self,
         iers_only: bool,
-        provider: LeapSecondsFile,
+        provider: &LeapSecondsFile,
     ) -> Option<f64> {
         self.leap_seconds_with(iers_only, provider)
     }
 
+    /// Converts self to another time scale using the leap seconds of the provided LeapSecondsFile instead of the default ones.
+    ///
+    /// :type ts: TimeScale
+    /// :type provider: LeapSecondsFile
+    /// :rtype: Epoch
+    #[cfg(feature = "python")]
+    pub fn to_time_scale_with_file(&self, ts: TimeScale, provider: &LeapSecondsFile) -> Self {
+        self.to_time_scale_with(ts, provider)
+    }
+
+    /// Converts self to another time scale using the UT1 data of the provided Ut1Provider instead of the registered one.
+    /// Returns an error if UT1 is involved and the provider does not cover this epoch.
+    ///
+    /// :type ts: TimeScale
+    /// :type provider: Ut1Provider
+    /// :rtype: Epoch
+    #[cfg(feature = "python")]
+    #[pyo3(name = "to_time_scale_with_ut1")]
+    fn py_to_time_scale_with_ut1(
+        &self,
+        ts: TimeScale,
+        provider: &Ut1Provider,
+    ) -> Result<Self, HifitimeError> {
+        self.to_time_scale_with_ut1(ts, provider)
+    }
+
     fn __getnewargs__(&self) -> Result<(String,), PyErr> {
         Ok((format!("{self:?}"),))
     }
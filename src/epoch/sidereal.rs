@@ -0,0 +1,395 @@
+/*
+* Hifitime
+* Copyright (C) 2017-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+* This Source Code Form is subject to the terms of the Mozilla Public
+* License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at https://mozilla.org/MPL/2.0/.
+*
+* Documentation: https://nyxspace.com/
+*/
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)] // Import is indeed used.
+use num_traits::Float;
+
+use core::f64::consts::TAU;
+
+use crate::{Duration, Epoch, HifitimeError, TimeScale, Unit, ET_EPOCH_S};
+
+/// Arcseconds to radians.
+pub(crate) const ARCSEC_TO_RAD: f64 = TAU / 1296000.0;
+
+/// The IAU 2000B nutation series (McCarthy & Luzum 2003), as in the `iauNut00b` function of SOFA.
+///
+/// Each row holds the multipliers of the fundamental arguments l, l', F, D and Ω, followed by the coefficients of
+/// the nutation in longitude (sin, t sin, cos) and in obliquity (cos, t cos, sin), in units of 0.1 microarcsecond.
+#[rustfmt::skip]
+const NUTATION_IAU2000B: [[f64; 11]; 77] = [
+    [0.0, 0.0, 0.0, 0.0, 1.0, -172064161.0, -174666.0, 33386.0, 92052331.0, 9086.0, 15377.0],
+    [0.0, 0.0, 2.0, -2.0, 2.0, -13170906.0, -1675.0, -13696.0, 5730336.0, -3015.0, -4587.0],
+    [0.0, 0.0, 2.0, 0.0, 2.0, -2276413.0, -234.0, 2796.0, 978459.0, -485.0, 1374.0],
+    [0.0, 0.0, 0.0, 0.0, 2.0, 2074554.0, 207.0, -698.0, -897492.0, 470.0, -291.0],
+    [0.0, 1.0, 0.0, 0.0, 0.0, 1475877.0, -3633.0, 11817.0, 73871.0, -184.0, -1924.0],
+    [0.0, 1.0, 2.0, -2.0, 2.0, -516821.0, 1226.0, -524.0, 224386.0, -677.0, -174.0],
+    [1.0, 0.0, 0.0, 0.0, 0.0, 711159.0, 73.0, -872.0, -6750.0, 0.0, 358.0],
+    [0.0, 0.0, 2.0, 0.0, 1.0, -387298.0, -367.0, 380.0, 200728.0, 18.0, 318.0],
+    [1.0, 0.0, 2.0, 0.0, 2.0, -301461.0, -36.0, 816.0, 129025.0, -63.0, 367.0],
+    [0.0, -1.0, 2.0, -2.0, 2.0, 215829.0, -494.0, 111.0, -95929.0, 299.0, 132.0],
+    [0.0, 0.0, 2.0, -2.0, 1.0, 128227.0, 137.0, 181.0, -68982.0, -9.0, 39.0],
+    [-1.0, 0.0, 2.0, 0.0, 2.0, 123457.0, 11.0, 19.0, -53311.0, 32.0, -4.0],
+    [-1.0, 0.0, 0.0, 2.0, 0.0, 156994.0, 10.0, -168.0, -1235.0, 0.0, 82.0],
+    [1.0, 0.0, 0.0, 0.0, 1.0, 63110.0, 63.0, 27.0, -33228.0, 0.0, -9.0],
+    [-1.0, 0.0, 0.0, 0.0, 1.0, -57976.0, -63.0, -189.0, 31429.0, 0.0, -75.0],
+    [-1.0, 0.0, 2.0, 2.0, 2.0, -59641.0, -11.0, 149.0, 25543.0, -11.0, 66.0],
+    [1.0, 0.0, 2.0, 0.0, 1.0, -51613.0, -42.0, 129.0, 26366.0, 0.0, 78.0],
+    [-2.0, 0.0, 2.0, 0.0, 1.0, 45893.0, 50.0, 31.0, -24236.0, -10.0, 20.0],
+    [0.0, 0.0, 0.0, 2.0, 0.0, 63384.0, 11.0, -150.0, -1220.0, 0.0, 29.0],
+    [0.0, 0.0, 2.0, 2.0, 2.0, -38571.0, -1.0, 158.0, 16452.0, -11.0, 68.0],
+    [0.0, -2.0, 2.0, -2.0, 2.0, 32481.0, 0.0, 0.0, -13870.0, 0.0, 0.0],
+    [-2.0, 0.0, 0.0, 2.0, 0.0, -47722.0, 0.0, -18.0, 477.0, 0.0, -25.0],
+    [2.0, 0.0, 2.0, 0.0, 2.0, -31046.0, -1.0, 131.0, 13238.0, -11.0, 59.0],
+    [1.0, 0.0, 2.0, -2.0, 2.0, 28593.0, 0.0, -1.0, -12338.0, 10.0, -3.0],
+    [-1.0, 0.0, 2.0, 0.0, 1.0, 20441.0, 21.0, 10.0, -10758.0, 0.0, -3.0],
+    [2.0, 0.0, 0.0, 0.0, 0.0, 29243.0, 0.0, -74.0, -609.0, 0.0, 13.0],
+    [0.0, 0.0, 2.0, 0.0, 0.0, 25887.0, 0.0, -66.0, -550.0, 0.0, 11.0],
+    [0.0, 1.0, 0.0, 0.0, 1.0, -14053.0, -25.0, 79.0, 8551.0, -2.0, -45.0],
+    [-1.0, 0.0, 0.0, 2.0, 1.0, 15164.0, 10.0, 11.0, -8001.0, 0.0, -1.0],
+    [0.0, 2.0, 2.0, -2.0, 2.0, -15794.0, 72.0, -16.0, 6850.0, -42.0, -5.0],
+    [0.0, 0.0, -2.0, 2.0, 0.0, 21783.0, 0.0, 13.0, -167.0, 0.0, 13.0],
+    [1.0, 0.0, 0.0, -2.0, 1.0, -12873.0, -10.0, -37.0, 6953.0, 0.0, -14.0],
+    [0.0, -1.0, 0.0, 0.0, 1.0, -12654.0, 11.0, 63.0, 6415.0, 0.0, 26.0],
+    [-1.0, 0.0, 2.0, 2.0, 1.0, -10204.0, 0.0, 25.0, 5222.0, 0.0, 15.0],
+    [0.0, 2.0, 0.0, 0.0, 0.0, 16707.0, -85.0, -10.0, 168.0, -1.0, 10.0],
+    [1.0, 0.0, 2.0, 2.0, 2.0, -7691.0, 0.0, 44.0, 3268.0, 0.0, 19.0],
+    [-2.0, 0.0, 2.0, 0.0, 0.0, -11024.0, 0.0, -14.0, 104.0, 0.0, 2.0],
+    [0.0, 1.0, 2.0, 0.0, 2.0, 7566.0, -21.0, -11.0, -3250.0, 0.0, -5.0],
+    [0.0, 0.0, 2.0, 2.0, 1.0, -6637.0, -11.0, 25.0, 3353.0, 0.0, 14.0],
+    [0.0, -1.0, 2.0, 0.0, 2.0, -7141.0, 21.0, 8.0, 3070.0, 0.0, 4.0],
+    [0.0, 0.0, 0.0, 2.0, 1.0, -6302.0, -11.0, 2.0, 3272.0, 0.0, 4.0],
+    [1.0, 0.0, 2.0, -2.0, 1.0, 5800.0, 10.0, 2.0, -3045.0, 0.0, -1.0],
+    [2.0, 0.0, 2.0, -2.0, 2.0, 6443.0, 0.0, -7.0, -2768.0, 0.0, -4.0],
+    [-2.0, 0.0, 0.0, 2.0, 1.0, -5774.0, -11.0, -15.0, 3041.0, 0.0, -5.0],
+    [2.0, 0.0, 2.0, 0.0, 1.0, -5350.0, 0.0, 21.0, 2695.0, 0.0, 12.0],
+    [0.0, -1.0, 2.0, -2.0, 1.0, -4752.0, -11.0, -3.0, 2719.0, 0.0, -3.0],
+    [0.0, 0.0, 0.0, -2.0, 1.0, -4940.0, -11.0, -21.0, 2720.0, 0.0, -9.0],
+    [-1.0, -1.0, 0.0, 2.0, 0.0, 7350.0, 0.0, -8.0, -51.0, 0.0, 4.0],
+    [2.0, 0.0, 0.0, -2.0, 1.0, 4065.0, 0.0, 6.0, -2206.0, 0.0, 1.0],
+    [1.0, 0.0, 0.0, 2.0, 0.0, 6579.0, 0.0, -24.0, -199.0, 0.0, 2.0],
+    [0.0, 1.0, 2.0, -2.0, 1.0, 3579.0, 0.0, 5.0, -1900.0, 0.0, 1.0],
+    [1.0, -1.0, 0.0, 0.0, 0.0, 4725.0, 0.0, -6.0, -41.0, 0.0, 3.0],
+    [-2.0, 0.0, 2.0, 0.0, 2.0, -3075.0, 0.0, -2.0, 1313.0, 0.0, -1.0],
+    [3.0, 0.0, 2.0, 0.0, 2.0, -2904.0, 0.0, 15.0, 1233.0, 0.0, 7.0],
+    [0.0, -1.0, 0.0, 2.0, 0.0, 4348.0, 0.0, -10.0, -81.0, 0.0, 2.0],
+    [1.0, -1.0, 2.0, 0.0, 2.0, -2878.0, 0.0, 8.0, 1232.0, 0.0, 4.0],
+    [0.0, 0.0, 0.0, 1.0, 0.0, -4230.0, 0.0, 5.0, -20.0, 0.0, -2.0],
+    [-1.0, -1.0, 2.0, 2.0, 2.0, -2819.0, 0.0, 7.0, 1207.0, 0.0, 3.0],
+    [-1.0, 0.0, 2.0, 0.0, 0.0, -4056.0, 0.0, 5.0, 40.0, 0.0, -2.0],
+    [0.0, -1.0, 2.0, 2.0, 2.0, -2647.0, 0.0, 11.0, 1129.0, 0.0, 5.0],
+    [-2.0, 0.0, 0.0, 0.0, 1.0, -2294.0, 0.0, -10.0, 1266.0, 0.0, -4.0],
+    [1.0, 1.0, 2.0, 0.0, 2.0, 2481.0, 0.0, -7.0, -1062.0, 0.0, -3.0],
+    [2.0, 0.0, 0.0, 0.0, 1.0, 2179.0, 0.0, -2.0, -1129.0, 0.0, -2.0],
+    [-1.0, 1.0, 0.0, 1.0, 0.0, 3276.0, 0.0, 1.0, -9.0, 0.0, 0.0],
+    [1.0, 1.0, 0.0, 0.0, 0.0, -3389.0, 0.0, 5.0, 35.0, 0.0, -2.0],
+    [1.0, 0.0, 2.0, 0.0, 0.0, 3339.0, 0.0, -13.0, -107.0, 0.0, 1.0],
+    [-1.0, 0.0, 2.0, -2.0, 1.0, -1987.0, 0.0, -6.0, 1073.0, 0.0, -2.0],
+    [1.0, 0.0, 0.0, 0.0, 2.0, -1981.0, 0.0, 0.0, 854.0, 0.0, 0.0],
+    [-1.0, 0.0, 0.0, 1.0, 0.0, 4026.0, 0.0, -353.0, -553.0, 0.0, -139.0],
+    [0.0, 0.0, 2.0, 1.0, 2.0, 1660.0, 0.0, -5.0, -710.0, 0.0, -2.0],
+    [-1.0, 0.0, 2.0, 4.0, 2.0, -1521.0, 0.0, 9.0, 647.0, 0.0, 4.0],
+    [-1.0, 1.0, 0.0, 1.0, 1.0, 1314.0, 0.0, 0.0, -700.0, 0.0, 0.0],
+    [0.0, -2.0, 2.0, -2.0, 1.0, -1283.0, 0.0, 0.0, 672.0, 0.0, 0.0],
+    [1.0, 0.0, 2.0, 2.0, 1.0, -1331.0, 0.0, 8.0, 663.0, 0.0, 4.0],
+    [-2.0, 0.0, 2.0, 2.0, 2.0, 1383.0, 0.0, -2.0, -594.0, 0.0, -2.0],
+    [-1.0, 0.0, 0.0, 0.0, 2.0, 1405.0, 0.0, 4.0, -610.0, 0.0, 2.0],
+    [1.0, 1.0, 2.0, -2.0, 2.0, 1290.0, 0.0, 0.0, -556.0, 0.0, 0.0],
+];
+
+/// The complementary terms of the equation of the equinoxes of the IERS Conventions (2003), as in the `iauEect00` function of SOFA.
+///
+/// Each row holds the multipliers of the fundamental arguments l, l', F, D, Ω, the mean longitudes of Venus and of the Earth
+/// and the general precession in longitude, followed by the sine and cosine coefficients in microarcseconds.
+#[rustfmt::skip]
+const EECT00_T0: [[f64; 10]; 33] = [
+    [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 2640.96, -0.39],
+    [0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 63.52, -0.02],
+    [0.0, 0.0, 2.0, -2.0, 3.0, 0.0, 0.0, 0.0, 11.75, 0.01],
+    [0.0, 0.0, 2.0, -2.0, 1.0, 0.0, 0.0, 0.0, 11.21, 0.01],
+    [0.0, 0.0, 2.0, -2.0, 2.0, 0.0, 0.0, 0.0, -4.55, 0.0],
+    [0.0, 0.0, 2.0, 0.0, 3.0, 0.0, 0.0, 0.0, 2.02, 0.0],
+    [0.0, 0.0, 2.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.98, 0.0],
+    [0.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, -1.72, 0.0],
+    [0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.41, -0.01],
+    [0.0, 1.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, -1.26, -0.01],
+    [1.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, -0.63, 0.0],
+    [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -0.63, 0.0],
+    [0.0, 1.0, 2.0, -2.0, 3.0, 0.0, 0.0, 0.0, 0.46, 0.0],
+    [0.0, 1.0, 2.0, -2.0, 1.0, 0.0, 0.0, 0.0, 0.45, 0.0],
+    [0.0, 0.0, 4.0, -4.0, 4.0, 0.0, 0.0, 0.0, 0.36, 0.0],
+    [0.0, 0.0, 1.0, -1.0, 1.0, -8.0, 12.0, 0.0, -0.24, -0.12],
+    [0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.32, 0.0],
+    [0.0, 0.0, 2.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.28, 0.0],
+    [1.0, 0.0, 2.0, 0.0, 3.0, 0.0, 0.0, 0.0, 0.27, 0.0],
+    [1.0, 0.0, 2.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.26, 0.0],
+    [0.0, 0.0, 2.0, -2.0, 0.0, 0.0, 0.0, 0.0, -0.21, 0.0],
+    [0.0, 1.0, -2.0, 2.0, -3.0, 0.0, 0.0, 0.0, 0.19, 0.0],
+    [0.0, 1.0, -2.0, 2.0, -1.0, 0.0, 0.0, 0.0, 0.18, 0.0],
+    [0.0, 0.0, 0.0, 0.0, 0.0, 8.0, -13.0, -1.0, -0.1, 0.05],
+    [0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.15, 0.0],
+    [2.0, 0.0, -2.0, 0.0, -1.0, 0.0, 0.0, 0.0, -0.14, 0.0],
+    [1.0, 0.0, 0.0, -2.0, 1.0, 0.0, 0.0, 0.0, 0.14, 0.0],
+    [0.0, 1.0, 2.0, -2.0, 2.0, 0.0, 0.0, 0.0, -0.14, 0.0],
+    [1.0, 0.0, 0.0, -2.0, -1.0, 0.0, 0.0, 0.0, 0.14, 0.0],
+    [0.0, 0.0, 4.0, -2.0, 4.0, 0.0, 0.0, 0.0, 0.13, 0.0],
+    [0.0, 0.0, 2.0, -2.0, 4.0, 0.0, 0.0, 0.0, -0.11, 0.0],
+    [1.0, 0.0, -2.0, 0.0, -3.0, 0.0, 0.0, 0.0, 0.11, 0.0],
+    [1.0, 0.0, -2.0, 0.0, -1.0, 0.0, 0.0, 0.0, 0.11, 0.0],
+];
+
+/// Wraps the provided angle into [0, 2π).
+pub(crate) fn normalize_rad(angle: f64) -> f64 {
+    let wrapped = angle % TAU;
+    if wrapped < 0.0 {
+        wrapped + TAU
+    } else {
+        wrapped
+    }
+}
+
+/// Returns the nutation in longitude and in obliquity (Δψ, Δε), in radians, of the IAU 2000B model at the provided
+/// number of Julian centuries of TT past J2000.
+///
+/// This truncated model agrees with the full IAU 2000A model to about a milliarcsecond between 1995 and 2050.
+pub(crate) fn nutation_iau2000b(t: f64) -> (f64, f64) {
+    // Fundamental arguments of Simon et al. (1994), in radians.
+    let arguments = [
+        (485868.249036 + 1717915923.2178 * t) % 1296000.0,
+        (1287104.79305 + 129596581.0481 * t) % 1296000.0,
+        (335779.526232 + 1739527262.8478 * t) % 1296000.0,
+        (1072260.70369 + 1602961601.2090 * t) % 1296000.0,
+        (450160.398036 - 6962890.5431 * t) % 1296000.0,
+    ]
+    .map(|arcsec| arcsec * ARCSEC_TO_RAD);
+
+    // Sum the smallest terms first.
+    let (dpsi, deps) = NUTATION_IAU2000B
+        .iter()
+        .rev()
+        .fold((0.0, 0.0), |(dpsi, deps), term| {
+            let argument = (0..5).map(|i| term[i] * arguments[i]).sum::<f64>() % TAU;
+            let (sin, cos) = (argument.sin(), argument.cos());
+            (
+                dpsi + (term[5] + term[6] * t) * sin + term[7] * cos,
+                deps + (term[8] + term[9] * t) * cos + term[10] * sin,
+            )
+        });
+
+    // Convert from 0.1 microarcsecond and add the fixed offsets standing for the planetary nutation.
+    let to_rad = ARCSEC_TO_RAD / 1e7;
+    (
+        dpsi * to_rad - 0.135e-3 * ARCSEC_TO_RAD,
+        deps * to_rad + 0.388e-3 * ARCSEC_TO_RAD,
+    )
+}
+
+/// Returns the mean obliquity of the ecliptic (IAU 2006), in radians, at the provided number of Julian centuries of TT past J2000.
+pub(crate) fn mean_obliquity_rad(t: f64) -> f64 {
+    (84381.406
+        + (-46.836769
+            + (-0.0001831 + (0.00200340 + (-0.000000576 - 0.0000000434 * t) * t) * t) * t)
+            * t)
+        * ARCSEC_TO_RAD
+}
+
+impl Epoch {
+    /// Returns the Earth Rotation Angle (IAU 2000), in radians in [0, 2π), at the provided UT1 duration past J1900.
+    pub(crate) fn earth_rotation_angle_at(ut1: Duration) -> f64 {
+        let days = (ut1 - Unit::Second * ET_EPOCH_S).to_unit(Unit::Day);
+        // J2000 is at noon, so the fraction of the Julian Date is that of the days past J2000.
+        normalize_rad(TAU * (days.fract() + 0.779057273264 + 0.00273781191135448 * days))
+    }
+
+    /// Returns the Greenwich Mean Sidereal Time (IAU 2006), in radians in [0, 2π), at the provided UT1 duration past J1900
+    /// and number of Julian centuries of TT past J2000.
+    pub(crate) fn gmst_at(ut1: Duration, t: f64) -> f64 {
+        let precession_arcsec = 0.014506
+            + (4612.156534
+                + (1.3915817 + (-0.00000044 + (-0.000029956 - 0.0000000368 * t) * t) * t) * t)
+                * t;
+        normalize_rad(Self::earth_rotation_angle_at(ut1) + precession_arcsec * ARCSEC_TO_RAD)
+    }
+
+    /// Returns the Greenwich Mean Sidereal Time (IAU 2000) of SOFA `iauGmst00`, in radians in [0, 2π), at the provided UT1
+    /// duration past J1900 and number of Julian centuries of TT past J2000.
+    pub(crate) fn gmst_2000_at(ut1: Duration, t: f64) -> f64 {
+        let precession_arcsec =
+            0.014506 + (4612.15739966 + (1.39667721 + (-0.00009344 + 0.00001882 * t) * t) * t) * t;
+        normalize_rad(Self::earth_rotation_angle_at(ut1) + precession_arcsec * ARCSEC_TO_RAD)
+    }
+
+    /// Returns the equation of the equinoxes (IAU 2000B) of SOFA `iauEe00b`, in radians, at the provided number of Julian
+    /// centuries of TT past J2000: the nutation in right ascension Δψ cos ε with the IAU 2000B nutation and the IAU 1980
+    /// mean obliquity corrected for the IAU 2000 precession rate, plus the complementary terms of `iauEect00`.
+    pub(crate) fn equation_of_the_equinoxes_at(t: f64) -> f64 {
+        let (dpsi, _) = nutation_iau2000b(t);
+        let mean_obliquity_arcsec =
+            84381.448 + (-46.8150 + (-0.00059 + 0.001813 * t) * t) * t - 0.02524 * t;
+        dpsi * (mean_obliquity_arcsec * ARCSEC_TO_RAD).cos() + Self::complementary_terms_at(t)
+    }
+
+    /// Returns the complementary terms of the equation of the equinoxes of SOFA `iauEect00`, in radians,
+    /// at the provided number of Julian centuries of TT past J2000.
+    fn complementary_terms_at(t: f64) -> f64 {
+        // Fundamental arguments of the IERS Conventions (2003), in radians.
+        let arguments = [
+            (485868.249036
+                + (1717915923.2178 + (31.8792 + (0.051635 - 0.00024470 * t) * t) * t) * t)
+                % 1296000.0
+                * ARCSEC_TO_RAD,
+            (1287104.793048
+                + (129596581.0481 + (-0.5532 + (0.000136 - 0.00001149 * t) * t) * t) * t)
+                % 1296000.0
+                * ARCSEC_TO_RAD,
+            (335779.526232
+                + (1739527262.8478 + (-12.7512 + (-0.001037 + 0.00000417 * t) * t) * t) * t)
+                % 1296000.0
+                * ARCSEC_TO_RAD,
+            (1072260.703692
+                + (1602961601.2090 + (-6.3706 + (0.006593 - 0.00003169 * t) * t) * t) * t)
+                % 1296000.0
+                * ARCSEC_TO_RAD,
+            (450160.398036 + (-6962890.5431 + (7.4722 + (0.007702 - 0.00005939 * t) * t) * t) * t)
+                % 1296000.0
+                * ARCSEC_TO_RAD,
+            (3.176146697 + 1021.3285546211 * t) % TAU,
+            (1.753470314 + 628.3075849991 * t) % TAU,
+            (0.024381750 + 0.00000538691 * t) * t,
+        ];
+
+        // Sum the smallest terms first.
+        let constant_uas = EECT00_T0.iter().rev().fold(0.0, |sum, term| {
+            let argument = (0..8).map(|i| term[i] * arguments[i]).sum::<f64>();
+            sum + term[8] * argument.sin() + term[9] * argument.cos()
+        });
+        // The only term proportional to t has the argument Ω.
+        let linear_uas = -0.87 * arguments[4].sin();
+
+        (constant_uas + linear_uas * t) * 1e-6 * ARCSEC_TO_RAD
+    }
+
+    /// Returns the Greenwich Apparent Sidereal Time (IAU 2000B) of SOFA `iauGst00b`, in radians in [0, 2π), at the provided
+    /// UT1 duration past J1900 and number of Julian centuries of TT past J2000.
+    pub(crate) fn gast_at(ut1: Duration, t: f64) -> f64 {
+        normalize_rad(Self::gmst_2000_at(ut1, t) + Self::equation_of_the_equinoxes_at(t))
+    }
+
+    /// Returns the UT1 duration past J1900 and the TT centuries past J2000 of this epoch, or an error if the registered
+    /// UT1 provider does not cover it, which is also required to convert a UT1 epoch to TT.
+    fn try_ut1_and_tt_centuries(&self) -> Result<(Duration, f64), HifitimeError> {
+        let tt = self.try_to_time_scale(TimeScale::TT)?;
+        Ok((self.try_ut1_duration()?, tt.to_tt_centuries_j2k()))
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Epoch {
+    #[must_use]
+    /// Returns the Earth Rotation Angle (IAU 2000) of this epoch, in radians in [0, 2π).
+    ///
+    /// UT1 comes from the registered UT1 provider, and **this panics if it does not cover this epoch**, like `to_time_scale`:
+    /// use `try_earth_rotation_angle_rad` to get an error instead, or `earth_rotation_angle_rad_with_ut1` (`ut1` feature)
+    /// to provide the UT1 data.
+    ///
+    /// :rtype: float
+    pub fn earth_rotation_angle_rad(&self) -> f64 {
+        Self::earth_rotation_angle_at(self.to_time_scale(TimeScale::UT1).duration)
+    }
+
+    #[must_use]
+    /// Returns the Greenwich Mean Sidereal Time (IAU 2006) of this epoch, in radians in [0, 2π).
+    ///
+    /// UT1 comes from the registered UT1 provider, and **this panics if it does not cover this epoch**, cf. `earth_rotation_angle_rad`.
+    ///
+    /// :rtype: float
+    pub fn gmst_rad(&self) -> f64 {
+        Self::gmst_at(
+            self.to_time_scale(TimeScale::UT1).duration,
+            self.to_tt_centuries_j2k(),
+        )
+    }
+
+    #[must_use]
+    /// Returns the Greenwich Apparent Sidereal Time (IAU 2000B) of this epoch, in radians in [0, 2π), as SOFA `iauGst00b`,
+    /// i.e. the Greenwich Mean Sidereal Time (IAU 2000) plus the equation of the equinoxes of `equation_of_the_equinoxes_2000b_rad`.
+    ///
+    /// This is not the IAU 2006/2000A model of SOFA `iauGst06a`, from which it differs by up to about a milliarcsecond
+    /// between 1995 and 2050, nor is its mean sidereal time that of `gmst_rad` (IAU 2006).
+    ///
+    /// UT1 comes from the registered UT1 provider, and **this panics if it does not cover this epoch**, cf. `earth_rotation_angle_rad`.
+    ///
+    /// :rtype: float
+    pub fn gast_2000b_rad(&self) -> f64 {
+        Self::gast_at(
+            self.to_time_scale(TimeScale::UT1).duration,
+            self.to_tt_centuries_j2k(),
+        )
+    }
+
+    #[must_use]
+    /// Returns the equation of the equinoxes (IAU 2000B) of this epoch, in radians, as SOFA `iauEe00b`, i.e. the Greenwich
+    /// Apparent minus Mean Sidereal Time of `gast_2000b_rad`.
+    ///
+    /// :rtype: float
+    pub fn equation_of_the_equinoxes_2000b_rad(&self) -> f64 {
+        Self::equation_of_the_equinoxes_at(self.to_tt_centuries_j2k())
+    }
+
+    #[must_use]
+    /// Returns the local apparent sidereal time of this epoch at the provided longitude (positive east), both in radians,
+    /// i.e. the Greenwich Apparent Sidereal Time of `gast_2000b_rad` plus that longitude, in [0, 2π).
+    ///
+    /// UT1 comes from the registered UT1 provider, and **this panics if it does not cover this epoch**, cf. `earth_rotation_angle_rad`.
+    ///
+    /// :type longitude_rad: float
+    /// :rtype: float
+    pub fn local_sidereal_time_rad(&self, longitude_rad: f64) -> f64 {
+        normalize_rad(self.gast_2000b_rad() + longitude_rad)
+    }
+
+    /// Returns the Earth Rotation Angle (IAU 2000) of this epoch, in radians in [0, 2π), like `earth_rotation_angle_rad`,
+    /// but returns an error instead of panicking if the registered UT1 provider does not cover this epoch.
+    ///
+    /// :rtype: float
+    pub fn try_earth_rotation_angle_rad(&self) -> Result<f64, HifitimeError> {
+        Ok(Self::earth_rotation_angle_at(self.try_ut1_duration()?))
+    }
+
+    /// Returns the Greenwich Mean Sidereal Time (IAU 2006) of this epoch, in radians in [0, 2π), like `gmst_rad`,
+    /// but returns an error instead of panicking if the registered UT1 provider does not cover this epoch.
+    ///
+    /// :rtype: float
+    pub fn try_gmst_rad(&self) -> Result<f64, HifitimeError> {
+        let (ut1, t) = self.try_ut1_and_tt_centuries()?;
+        Ok(Self::gmst_at(ut1, t))
+    }
+
+    /// Returns the Greenwich Apparent Sidereal Time (IAU 2000B) of this epoch, in radians in [0, 2π), like `gast_2000b_rad`,
+    /// but returns an error instead of panicking if the registered UT1 provider does not cover this epoch.
+    ///
+    /// :rtype: float
+    pub fn try_gast_2000b_rad(&self) -> Result<f64, HifitimeError> {
+        let (ut1, t) = self.try_ut1_and_tt_centuries()?;
+        Ok(Self::gast_at(ut1, t))
+    }
+
+    /// Returns the local apparent sidereal time of this epoch at the provided longitude (positive east), both in radians,
+    /// like `local_sidereal_time_rad`, but returns an error instead of panicking if the registered UT1 provider does not cover this epoch.
+    ///
+    /// :type longitude_rad: float
+    /// :rtype: float
+    pub fn try_local_sidereal_time_rad(&self, longitude_rad: f64) -> Result<f64, HifitimeError> {
+        Ok(normalize_rad(self.try_gast_2000b_rad()? + longitude_rad))
+    }
+}
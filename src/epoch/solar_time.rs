@@ -0,0 +1,129 @@
+/*
+* Hifitime
+* Copyright (C) 2017-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+* This Source Code Form is subject to the terms of the Mozilla Public
+* License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at https://mozilla.org/MPL/2.0/.
+*
+* Documentation: https://nyxspace.com/
+*/
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)] // Import is indeed used.
+use num_traits::Float;
+
+use core::f64::consts::TAU;
+
+use super::sidereal::mean_obliquity_rad;
+use crate::{Duration, Epoch, HifitimeError, TimeScale, TimeUnits, Unit};
+
+impl Epoch {
+    /// Returns the offset of the local mean solar time at the provided longitude (positive east, in radians) from UT1,
+    /// i.e. one hour per 15 degrees of longitude.
+    pub(crate) fn mean_solar_time_offset(longitude_rad: f64) -> Duration {
+        (longitude_rad / TAU).days()
+    }
+
+    /// Returns the time of day of the provided duration past J1900, which starts at midnight.
+    pub(crate) fn time_of_day(duration: Duration) -> Duration {
+        let time_of_day = duration - duration.floor(Unit::Day * 1);
+        // The floor rounds towards zero, i.e. up before J1900.
+        if time_of_day.is_negative() {
+            time_of_day + Unit::Day * 1
+        } else {
+            time_of_day
+        }
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Epoch {
+    #[must_use]
+    /// Returns the equation of time of this epoch, i.e. the apparent minus the mean solar time, which varies between
+    /// about -14 and +16 minutes over the year.
+    ///
+    /// This is the formula of Smart (1956), as given by Meeus (Astronomical Algorithms, chapter 28), with the mean orbital
+    /// elements of the Sun: it is accurate to a few seconds, which is plenty for the apparent solar time.
+    ///
+    /// :rtype: Duration
+    pub fn equation_of_time(&self) -> Duration {
+        let t = self.to_tt_centuries_j2k();
+        // Geometric mean longitude and mean anomaly of the Sun, and eccentricity of the orbit of the Earth.
+        let l0 = (280.46646 + (36000.76983 + 0.0003032 * t) * t).to_radians();
+        let m = (357.52911 + (35999.05029 - 0.0001537 * t) * t).to_radians();
+        let e = 0.016708634 - (0.000042037 + 0.0000001267 * t) * t;
+        let y = (mean_obliquity_rad(t) / 2.0).tan().powi(2);
+
+        let equation_rad = y * (2.0 * l0).sin() - 2.0 * e * m.sin()
+            + 4.0 * e * y * m.sin() * (2.0 * l0).cos()
+            - 0.5 * y * y * (4.0 * l0).sin()
+            - 1.25 * e * e * (2.0 * m).sin();
+
+        (equation_rad / TAU).days()
+    }
+
+    #[must_use]
+    /// Returns the local mean solar time of this epoch at the provided longitude (positive east, in radians),
+    /// as the time of day since the local mean midnight, i.e. UT1 shifted by one hour per 15 degrees of longitude.
+    ///
+    /// UT1 comes from the registered UT1 provider, and **this panics if it does not cover this epoch**, like `to_time_scale`:
+    /// use `try_local_mean_solar_time` to get an error instead, or `local_mean_solar_time_with_ut1` (`ut1` feature) to provide
+    /// the UT1 data. This never panics for an epoch in the UT1 time scale. Use `Formatter::with_local_mean_solar_time` to format the local mean solar date and time.
+    ///
+    /// :type longitude_rad: float
+    /// :rtype: Duration
+    pub fn local_mean_solar_time(&self, longitude_rad: f64) -> Duration {
+        Self::time_of_day(
+            self.to_time_scale(TimeScale::UT1).duration
+                + Self::mean_solar_time_offset(longitude_rad),
+        )
+    }
+
+    #[must_use]
+    /// Returns the local apparent solar time of this epoch at the provided longitude (positive east, in radians),
+    /// as the time of day since the local apparent midnight, i.e. the time of a sundial: the local mean solar time
+    /// plus the equation of time.
+    ///
+    /// UT1 comes from the registered UT1 provider, and **this panics if it does not cover this epoch**, cf. `local_mean_solar_time`.
+    /// Use `Formatter::with_local_apparent_solar_time` to format the local apparent solar date and time.
+    ///
+    /// :type longitude_rad: float
+    /// :rtype: Duration
+    pub fn local_apparent_solar_time(&self, longitude_rad: f64) -> Duration {
+        Self::time_of_day(
+            self.to_time_scale(TimeScale::UT1).duration
+                + Self::mean_solar_time_offset(longitude_rad)
+                + self.equation_of_time(),
+        )
+    }
+
+    /// Returns the local mean solar time of this epoch at the provided longitude (positive east, in radians), like
+    /// `local_mean_solar_time`, but returns an error instead of panicking if the registered UT1 provider does not cover this epoch.
+    ///
+    /// :type longitude_rad: float
+    /// :rtype: Duration
+    pub fn try_local_mean_solar_time(&self, longitude_rad: f64) -> Result<Duration, HifitimeError> {
+        Ok(Self::time_of_day(
+            self.try_ut1_duration()? + Self::mean_solar_time_offset(longitude_rad),
+        ))
+    }
+
+    /// Returns the local apparent solar time of this epoch at the provided longitude (positive east, in radians), like
+    /// `local_apparent_solar_time`, but returns an error instead of panicking if the registered UT1 provider does not cover this epoch.
+    ///
+    /// :type longitude_rad: float
+    /// :rtype: Duration
+    pub fn try_local_apparent_solar_time(
+        &self,
+        longitude_rad: f64,
+    ) -> Result<Duration, HifitimeError> {
+        // The equation of time depends on TT, which also requires UT1 data for a UT1 epoch.
+        let equation = self.try_to_time_scale(TimeScale::TT)?.equation_of_time();
+        Ok(Self::time_of_day(
+            self.try_ut1_duration()? + Self::mean_solar_time_offset(longitude_rad) + equation,
+        ))
+    }
+}
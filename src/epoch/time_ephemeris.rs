@@ -0,0 +1,414 @@
+/*
+* Hifitime
+* Copyright (C) 2017-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+* This Source Code Form is subject to the terms of the Mozilla Public
+* License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at https://mozilla.org/MPL/2.0/.
+*
+* Documentation: https://nyxspace.com/
+*/
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use std::{fs::File, io::Read, path::Path};
+
+use super::tdb_model::TdbSource;
+use super::TT_OFFSET_MS;
+use crate::{Duration, Epoch, EtModel, HifitimeError, ParsingError, TimeScale, TimeUnits};
+
+/// NAIF ID of the center of the time ephemeris segments, i.e. the geocenter in the time dimension.
+const TIME_CENTER_ID: i32 = 1_000_000_000;
+/// NAIF ID of the TT - TDB time ephemeris segments.
+const TT_MINUS_TDB_ID: i32 = 1_000_000_001;
+/// Size of a DAF record in bytes.
+const DAF_RECORD_LEN: usize = 1024;
+
+/// A Chebyshev segment of TT - TDB, where each record covers `interval` seconds from `init`.
+#[derive(Clone, Debug, PartialEq)]
+struct TimeSegment {
+    /// Start of the segment, in TDB seconds past J2000.
+    start: f64,
+    /// End of the segment, in TDB seconds past J2000.
+    end: f64,
+    init: f64,
+    interval: f64,
+    /// Number of coefficients of TT - TDB in each record.
+    degree_plus_one: usize,
+    /// Number of doubles of each record.
+    record_len: usize,
+    /// All the records, each made of the midpoint, the radius and the coefficients of the record.
+    records: Vec<f64>,
+}
+
+impl TimeSegment {
+    /// Evaluates TT - TDB in seconds at the provided TDB seconds past J2000, which must be within this segment.
+    fn evaluate(&self, seconds: f64) -> f64 {
+        let num_records = self.records.len() / self.record_len;
+        let index = (((seconds - self.init) / self.interval) as usize).min(num_records - 1);
+        let record = &self.records[index * self.record_len..(index + 1) * self.record_len];
+        let (mid, radius) = (record[0], record[1]);
+        let coefficients = &record[2..2 + self.degree_plus_one];
+
+        // Clenshaw recurrence of the Chebyshev series at the normalized time.
+        let s = (seconds - mid) / radius;
+        let (b1, b2) = coefficients[1..]
+            .iter()
+            .rev()
+            .fold((0.0, 0.0), |(b1, b2), c| (2.0 * s * b1 - b2 + c, b1));
+        coefficients[0] + s * b1 - b2
+    }
+}
+
+/// A time ephemeris of TT - TDB read from an SPK file of the JPL (e.g. `de440t.bsp`) or INPOP ephemerides, which include
+/// its numerical integration alongside that of the bodies of the solar system: this is the reference TDB of orbit determination.
+///
+/// Only the TT - TDB segments are read, i.e. the Chebyshev segments (SPK types 2 and 3) of the target 1000000001 relative to
+/// the center 1000000000, in seconds, as a function of TDB. Use it per conversion with `Epoch::try_to_time_scale_with_ephemeris`,
+/// `Epoch::to_tdb_duration_with_ephemeris` or `Epoch::from_tdb_duration_with_ephemeris`, which return an error outside of it.
+///
+/// (Python documentation hints)
+/// :type path: str
+/// :rtype: TimeEphemeris
+#[cfg_attr(feature = "python", pyclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeEphemeris {
+    segments: Vec<TimeSegment>,
+}
+
+impl TimeEphemeris {
+    /// Reads the TT - TDB segments of the provided SPK file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, HifitimeError> {
+        let mut f = match File::open(path) {
+            Ok(f) => f,
+            Err(e) => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::InOut { err: e.kind() },
+                    details: "opening time ephemeris",
+                })
+            }
+        };
+
+        let mut bytes = Vec::new();
+        if let Err(e) = f.read_to_end(&mut bytes) {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::InOut { err: e.kind() },
+                details: "reading time ephemeris",
+            });
+        }
+
+        Self::from_bytes(&bytes)
+    }
+
+    /// Reads the TT - TDB segments of the provided contents of an SPK file, in either endianness.
+    /// Returns an error if it is not a DAF file or if it has no TT - TDB segment.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HifitimeError> {
+        let daf = Daf::new(bytes)?;
+
+        let mut segments = Vec::new();
+        let mut record = daf.first_summary_record;
+        // The summary records are a linked list: bound the walk by the number of records of the file.
+        for _ in 0..bytes.len() / DAF_RECORD_LEN {
+            if record == 0 {
+                break;
+            }
+            let summaries = daf.doubles(record_address(record), 3)?;
+            let (next, count) = (summaries[0] as usize, summaries[2] as usize);
+            for i in 0..count {
+                let address = record_address(record) + 3 + i * daf.summary_len;
+                if let Some(segment) = daf.time_segment(address)? {
+                    segments.push(segment);
+                }
+            }
+            record = next;
+        }
+
+        if segments.is_empty() {
+            return Err(invalid("no TT - TDB segment in time ephemeris"));
+        }
+        Ok(Self { segments })
+    }
+
+    /// Returns TT - TDB at the provided TDB duration past J2000, if this ephemeris covers it.
+    pub fn tt_minus_tdb(&self, tdb_duration_since_j2000: Duration) -> Option<Duration> {
+        let seconds = tdb_duration_since_j2000.to_seconds();
+        // The last segments supersede the first ones, as in SPICE.
+        self.segments
+            .iter()
+            .rev()
+            .find(|segment| segment.start <= seconds && seconds <= segment.end)
+            .map(|segment| segment.evaluate(seconds).seconds())
+    }
+
+    /// Returns the first epoch covered by this ephemeris, in TDB.
+    pub fn start(&self) -> Epoch {
+        let start = self
+            .segments
+            .iter()
+            .map(|segment| segment.start)
+            .fold(f64::INFINITY, f64::min);
+        Epoch::from_tdb_seconds(start)
+    }
+
+    /// Returns the last epoch covered by this ephemeris, in TDB.
+    pub fn end(&self) -> Epoch {
+        let end = self
+            .segments
+            .iter()
+            .map(|segment| segment.end)
+            .fold(f64::NEG_INFINITY, f64::max);
+        Epoch::from_tdb_seconds(end)
+    }
+
+    /// Returns the TAI duration past J1900 of the provided TDB duration past J2000, if this ephemeris covers it.
+    pub(crate) fn tdb_to_tai(&self, tdb: Duration) -> Option<Duration> {
+        let tt = tdb + self.tt_minus_tdb(tdb)?;
+        Some(tt - TT_OFFSET_MS.milliseconds() + TimeScale::TDB.prime_epoch_offset())
+    }
+
+    /// Returns the TDB duration past J2000 of the provided TAI duration past J1900, if this ephemeris covers it.
+    pub(crate) fn tai_to_tdb(&self, tai: Duration) -> Option<Duration> {
+        let tt = tai + TT_OFFSET_MS.milliseconds() - TimeScale::TDB.prime_epoch_offset();
+        // TT - TDB is a function of TDB: refine it once from TT, which is plenty given its rate of 1e-10.
+        let tdb = tt - self.tt_minus_tdb(tt)?;
+        Some(tt - self.tt_minus_tdb(tdb)?)
+    }
+}
+
+impl TdbSource for TimeEphemeris {
+    fn try_tai_to_tdb(&self, tai: Duration) -> Option<Duration> {
+        self.tai_to_tdb(tai)
+    }
+
+    fn try_tdb_to_tai(&self, tdb: Duration) -> Option<Duration> {
+        self.tdb_to_tai(tdb)
+    }
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(feature = "python", pymethods)]
+impl TimeEphemeris {
+    #[new]
+    pub fn __new__(path: String) -> Result<Self, HifitimeError> {
+        Self::from_path(&path)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TimeEphemeris from {} to {} @ {self:p}",
+            self.start(),
+            self.end()
+        )
+    }
+
+    /// Returns TT - TDB at the provided TDB duration past J2000, if this ephemeris covers it.
+    ///
+    /// :type tdb_duration_since_j2000: Duration
+    /// :rtype: Duration
+    #[pyo3(name = "tt_minus_tdb")]
+    fn py_tt_minus_tdb(&self, tdb_duration_since_j2000: Duration) -> Option<Duration> {
+        self.tt_minus_tdb(tdb_duration_since_j2000)
+    }
+
+    /// Returns the first epoch covered by this ephemeris, in TDB.
+    ///
+    /// :rtype: Epoch
+    #[pyo3(name = "start")]
+    fn py_start(&self) -> Epoch {
+        self.start()
+    }
+
+    /// Returns the last epoch covered by this ephemeris, in TDB.
+    ///
+    /// :rtype: Epoch
+    #[pyo3(name = "end")]
+    fn py_end(&self) -> Epoch {
+        self.end()
+    }
+}
+
+#[cfg_attr(feature = "python", pymethods)]
+impl Epoch {
+    /// Returns the Barycentric Dynamical Time (TDB) duration past J2000 of this epoch using the provided time ephemeris.
+    /// Returns an error if the ephemeris does not cover this epoch.
+    ///
+    /// :type ephemeris: TimeEphemeris
+    /// :rtype: Duration
+    pub fn to_tdb_duration_with_ephemeris(
+        &self,
+        ephemeris: &TimeEphemeris,
+    ) -> Result<Duration, HifitimeError> {
+        ephemeris
+            .tai_to_tdb(self.to_tai_duration())
+            .ok_or(HifitimeError::MissingTimeEphemerisData { epoch: *self })
+    }
+
+    /// Converts self to another time scale using the provided time ephemeris instead of `TdbModel::Esa` for TDB and TCB.
+    /// Returns an error if TDB (or TCB) is involved and the ephemeris does not cover this epoch, or if UT1 is involved
+    /// and the registered UT1 provider does not cover it.
+    ///
+    /// :type ts: TimeScale
+    /// :type ephemeris: TimeEphemeris
+    /// :rtype: Epoch
+    pub fn try_to_time_scale_with_ephemeris(
+        &self,
+        ts: TimeScale,
+        ephemeris: &TimeEphemeris,
+    ) -> Result<Self, HifitimeError> {
+        self.to_time_scale_inner(
+            ts,
+            &Self::conversion_delta_at,
+            &Self::try_registered_delta_tai_ut1,
+            EtModel::NAIF0012,
+            ephemeris,
+        )
+    }
+}
+
+impl Epoch {
+    /// Initialize an Epoch from the provided Barycentric Dynamical Time (TDB) duration past J2000 using the provided time ephemeris.
+    /// Returns an error if the ephemeris does not cover this epoch.
+    ///
+    /// The returned epoch is in TAI so that its subsequent conversions do not depend on the ephemeris.
+    pub fn from_tdb_duration_with_ephemeris(
+        duration_since_j2000: Duration,
+        ephemeris: &TimeEphemeris,
+    ) -> Result<Self, HifitimeError> {
+        ephemeris
+            .tdb_to_tai(duration_since_j2000)
+            .map(Self::from_tai_duration)
+            .ok_or(HifitimeError::MissingTimeEphemerisData {
+                epoch: Self::from_tdb_duration(duration_since_j2000),
+            })
+    }
+}
+
+/// Returns the address of the first double of the provided record.
+fn record_address(record: usize) -> usize {
+    (record - 1) * DAF_RECORD_LEN / 8 + 1
+}
+
+fn invalid(details: &'static str) -> HifitimeError {
+    HifitimeError::Parse {
+        source: ParsingError::UnknownFormat,
+        details,
+    }
+}
+
+/// The contents of a Double precision Array File (DAF) of NAIF, e.g. an SPK file.
+struct Daf<'a> {
+    bytes: &'a [u8],
+    little_endian: bool,
+    /// Number of doubles of each summary.
+    summary_len: usize,
+    num_doubles: usize,
+    first_summary_record: usize,
+}
+
+impl<'a> Daf<'a> {
+    /// Reads the file record of the provided DAF.
+    fn new(bytes: &'a [u8]) -> Result<Self, HifitimeError> {
+        if bytes.len() < DAF_RECORD_LEN || !bytes.starts_with(b"DAF/") {
+            return Err(invalid("time ephemeris is not a DAF file"));
+        }
+        let little_endian = match &bytes[88..96] {
+            b"LTL-IEEE" => true,
+            b"BIG-IEEE" => false,
+            _ => return Err(invalid("unsupported DAF binary format")),
+        };
+
+        let mut daf = Self {
+            bytes,
+            little_endian,
+            summary_len: 0,
+            num_doubles: 0,
+            first_summary_record: 0,
+        };
+        let num_doubles = daf.int(8);
+        let num_ints = daf.int(12);
+        if num_doubles != 2 || num_ints != 6 {
+            return Err(invalid("time ephemeris is not an SPK file"));
+        }
+        daf.num_doubles = 2;
+        // The integers are packed two per double.
+        daf.summary_len = 2 + 6_usize.div_ceil(2);
+        daf.first_summary_record = daf.int(76) as usize;
+        Ok(daf)
+    }
+
+    fn int(&self, offset: usize) -> i32 {
+        let bytes = self.bytes[offset..offset + 4].try_into().unwrap();
+        if self.little_endian {
+            i32::from_le_bytes(bytes)
+        } else {
+            i32::from_be_bytes(bytes)
+        }
+    }
+
+    /// Returns the `count` doubles from the provided (one-based) address.
+    fn doubles(&self, address: usize, count: usize) -> Result<Vec<f64>, HifitimeError> {
+        let start = (address - 1) * 8;
+        let bytes = self
+            .bytes
+            .get(start..start + count * 8)
+            .ok_or_else(|| invalid("truncated time ephemeris"))?;
+        Ok(bytes
+            .chunks_exact(8)
+            .map(|chunk| {
+                let chunk = chunk.try_into().unwrap();
+                if self.little_endian {
+                    f64::from_le_bytes(chunk)
+                } else {
+                    f64::from_be_bytes(chunk)
+                }
+            })
+            .collect())
+    }
+
+    /// Reads the segment of the summary at the provided address, if it is a TT - TDB segment.
+    fn time_segment(&self, address: usize) -> Result<Option<TimeSegment>, HifitimeError> {
+        let summary = self.doubles(address, self.summary_len)?;
+        let ints_offset = (address - 1 + self.num_doubles) * 8;
+        let [target, center, _frame, data_type, begin, end] =
+            core::array::from_fn(|i| self.int(ints_offset + 4 * i));
+        if target != TT_MINUS_TDB_ID || center != TIME_CENTER_ID {
+            return Ok(None);
+        }
+        // Type 3 records also hold the coefficients of the rate, after those of the three components.
+        let num_components = match data_type {
+            2 => 3,
+            3 => 6,
+            _ => return Err(invalid("unsupported time ephemeris segment type")),
+        };
+
+        let (begin, end) = (begin as usize, end as usize);
+        if begin == 0 || end < begin + 3 {
+            return Err(invalid("invalid time ephemeris segment addresses"));
+        }
+        let directory = self.doubles(end - 3, 4)?;
+        let (init, interval, record_len, num_records) = (
+            directory[0],
+            directory[1],
+            directory[2] as usize,
+            directory[3] as usize,
+        );
+        if num_records == 0
+            || interval <= 0.0
+            || record_len < 2 + num_components
+            || (record_len - 2) % num_components != 0
+            || num_records * record_len != end - 3 - begin
+        {
+            return Err(invalid("invalid time ephemeris segment directory"));
+        }
+
+        Ok(Some(TimeSegment {
+            start: summary[0],
+            end: summary[1],
+            init,
+            interval,
+            degree_plus_one: (record_len - 2) / num_components,
+            record_len,
+            records: self.doubles(begin, num_records * record_len)?,
+        }))
+    }
+}
@@ -10,27 +10,211 @@
 
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
+#[cfg(feature = "python")]
+use pyo3::types::PyType;
 
+#[cfg(feature = "ut1-download")]
 use reqwest::{blocking::get, StatusCode};
 
 use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
-use std::{fs::File, io::Read};
+use std::fs::{metadata, read, write, File};
+use std::io::Read;
+use std::sync::{Arc, PoisonError, RwLock};
 
+use core::f64::consts::PI;
 use core::fmt;
-use core::ops::Index;
+use core::ops::{Index, Range};
+use core::str::FromStr;
+
+use super::sidereal::normalize_rad;
+use crate::{
+    DeltaTModel, Duration, Epoch, EtModel, HifitimeError, ParsingError, TdbModel, TimeScale,
+    TimeUnits, Unit,
+};
+
+/// The URL of the EOP2 data of JPL, to which the version of the data is appended.
+const JPL_EOP2_URL: &str = "https://eop2-external.jpl.nasa.gov/eop2";
+
+/// A source of TAI - UT1 data, e.g. a `Ut1Provider` or a user-defined store of Earth Orientation Parameters.
+///
+/// Sources expose their samples as a borrowed slice sorted by increasing epoch, so that the lookups are binary searches
+/// without any iteration state: a single source can be shared between threads, e.g. in an `Arc`, and used by reference.
+pub trait Ut1Source {
+    /// Returns the samples of this source, sorted by strictly increasing epoch, where each epoch is in TAI.
+    /// The interpolation is not defined between two samples at the same epoch.
+    fn samples(&self) -> &[DeltaTaiUt1];
+
+    /// Returns the interpolation mode used between the samples of this source, `Ut1Interpolation::Lagrange` by default.
+    fn interpolation(&self) -> Ut1Interpolation {
+        Ut1Interpolation::default()
+    }
 
-use crate::{Duration, Epoch, HifitimeError, ParsingError, TimeScale, Unit};
+    /// Returns the model predicting UT1 after the last sample of this source, if any. None by default.
+    fn prediction(&self) -> Option<Ut1Prediction> {
+        None
+    }
+
+    /// Returns the ΔT model used before the first sample of this source, and after its last one if it has no prediction model.
+    /// None by default.
+    fn delta_t_model(&self) -> Option<DeltaTModel> {
+        None
+    }
+
+    /// Returns TAI - UT1 at the provided epoch, or None if this epoch is outside of the data of this source
+    /// and can neither be predicted nor modeled, cf. `delta_tai_ut1_estimated`.
+    /// The epoch is converted to TAI first, so it should not be in UT1 itself.
+    fn delta_tai_ut1(&self, epoch: &Epoch) -> Option<Duration> {
+        self.delta_tai_ut1_estimated(epoch)
+            .map(|(delta_tai_ut1, _)| delta_tai_ut1)
+    }
+
+    /// Returns TAI - UT1 at the provided epoch and how it was estimated: from a sample at that epoch, interpolated between
+    /// the samples of this source, predicted with the model of this source after its last sample, or modeled with its ΔT model
+    /// outside of its samples otherwise.
+    /// Returns None outside of the samples if this source has neither a prediction nor a ΔT model.
+    fn delta_tai_ut1_estimated(&self, epoch: &Epoch) -> Option<(Duration, Ut1Estimate)> {
+        let tai = epoch.to_tai_duration();
+        match Window::new(self.samples(), tai, self.interpolation()) {
+            Some(window) => Some(
+                match window
+                    .samples
+                    .iter()
+                    .find(|sample| sample.epoch.duration == tai)
+                {
+                    Some(sample) => (sample.delta_tai_minus_ut1, Ut1Estimate::Sampled),
+                    None => (
+                        window.delta_tai_ut1(),
+                        Ut1Estimate::Interpolated(window.used),
+                    ),
+                },
+            ),
+            None => {
+                let predicted = match self.samples().last() {
+                    Some(last) if tai > last.epoch.duration => {
+                        self.prediction().map(|prediction| {
+                            (prediction.delta_tai_ut1(epoch), Ut1Estimate::Predicted)
+                        })
+                    }
+                    _ => None,
+                };
+                predicted.or_else(|| {
+                    self.delta_t_model()
+                        .map(|model| (model.delta_tai_ut1(epoch), Ut1Estimate::Modeled))
+                })
+            }
+        }
+    }
+
+    /// Returns TAI - UT1 at the provided epoch and the interpolation mode actually used to compute it,
+    /// or None if this epoch is outside of the data of this source.
+    ///
+    /// The mode used is the one of this source, unless there are too few samples for it: Lagrange interpolation
+    /// falls back to linear interpolation with fewer than four samples, and a single sample is always used as a step.
+    fn delta_tai_ut1_interpolated(&self, epoch: &Epoch) -> Option<(Duration, Ut1Interpolation)> {
+        let window = Window::new(
+            self.samples(),
+            epoch.to_tai_duration(),
+            self.interpolation(),
+        )?;
+        Some((window.delta_tai_ut1(), window.used))
+    }
+
+    /// Returns the Earth orientation parameters other than UT1 at the provided epoch, interpolated like TAI - UT1,
+    /// or None if this epoch is outside of the data of this source.
+    ///
+    /// Each parameter is None if any of the samples used to interpolate it lacks it.
+    fn earth_orientation_at(&self, epoch: &Epoch) -> Option<EarthOrientation> {
+        let window = Window::new(
+            self.samples(),
+            epoch.to_tai_duration(),
+            self.interpolation(),
+        )?;
+        Some(EarthOrientation {
+            x_pole_arcsec: window.interpolate(|eop| eop.x_pole_arcsec),
+            y_pole_arcsec: window.interpolate(|eop| eop.y_pole_arcsec),
+            lod: window
+                .interpolate(|eop| eop.lod.map(|lod| lod.to_seconds()))
+                .map(|lod| lod.seconds()),
+            dx_arcsec: window.interpolate(|eop| eop.dx_arcsec),
+            dy_arcsec: window.interpolate(|eop| eop.dy_arcsec),
+        })
+    }
+
+    /// Returns the quality of TAI - UT1 at the provided epoch, i.e. the least reliable quality of the samples surrounding it,
+    /// or None if this epoch is outside of the data of this source.
+    fn quality_at(&self, epoch: &Epoch) -> Option<Ut1Quality> {
+        let samples = self.samples();
+        let tai = epoch.to_tai_duration();
+        if tai < samples.first()?.epoch.duration || tai > samples.last()?.epoch.duration {
+            return None;
+        }
+        let idx = samples.partition_point(|sample| sample.epoch.duration <= tai);
+        // At a sample, TAI - UT1 is that of this sample only.
+        let end = if samples[idx - 1].epoch.duration == tai {
+            idx
+        } else {
+            idx + 1
+        };
+        samples[idx - 1..end]
+            .iter()
+            .map(|sample| sample.quality)
+            .max()
+    }
+}
+
+/// The UT1 provider used by `Epoch::to_time_scale` and `Epoch::try_to_time_scale`, if any: without it, converting to or from UT1 panics or fails.
+static REGISTERED_PROVIDER: RwLock<Option<Arc<Ut1Provider>>> = RwLock::new(None);
 
 impl Epoch {
+    /// Converts self to another time scale using the UT1 data of the provided source, e.g. a `Ut1Provider`.
+    /// Returns an error if UT1 is involved and the source does not cover this epoch.
+    /// The leap seconds are those of `to_time_scale`.
+    ///
+    /// This is the preferred way to convert to and from UT1: unlike `to_time_scale`, which uses the provider registered
+    /// for the whole process with `Ut1Provider::register`, the UT1 data is explicit and can differ between conversions.
+    ///
+    /// ```
+    /// use hifitime::ut1::Ut1Provider;
+    /// use hifitime::{Epoch, TimeScale};
+    ///
+    /// let provider = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    /// let epoch = Epoch::from_gregorian_utc_at_noon(2022, 6, 1);
+    /// let ut1 = epoch.to_time_scale_with_ut1(TimeScale::UT1, &provider).unwrap();
+    /// assert_eq!(ut1.time_scale, TimeScale::UT1);
+    /// assert_eq!(ut1.to_time_scale_with_ut1(TimeScale::UTC, &provider), Ok(epoch));
+    /// // Outside of the UT1 data, this is an error.
+    /// let before = Epoch::from_gregorian_utc_at_midnight(1990, 1, 1);
+    /// assert!(before.to_time_scale_with_ut1(TimeScale::UT1, &provider).is_err());
+    /// ```
+    pub fn to_time_scale_with_ut1<S: Ut1Source + ?Sized>(
+        &self,
+        ts: TimeScale,
+        provider: &S,
+    ) -> Result<Self, HifitimeError> {
+        self.to_time_scale_inner(
+            ts,
+            &Self::conversion_delta_at,
+            &|tai| {
+                let epoch = Self::from_tai_duration(tai);
+                provider
+                    .delta_tai_ut1(&epoch)
+                    .ok_or(HifitimeError::MissingUt1Data { epoch })
+            },
+            EtModel::NAIF0012,
+            &TdbModel::Esa,
+        )
+    }
+
     #[must_use]
     /// Initialize an Epoch from the provided UT1 duration since 1900 January 01 at midnight
     ///
     /// # Warning
     /// The time scale of this Epoch will be set to TAI! This is to ensure that no additional computations will change the duration since it's stored in TAI.
     /// However, this also means that calling `to_duration()` on this Epoch will return the TAI duration and not the UT1 duration!
-    pub fn from_ut1_duration(duration: Duration, provider: Ut1Provider) -> Self {
+    /// To keep the UT1 representation, use `Epoch::from_duration(duration, TimeScale::UT1)` instead, and convert it with `to_time_scale_with_ut1`.
+    pub fn from_ut1_duration<S: Ut1Source + ?Sized>(duration: Duration, provider: &S) -> Self {
         let mut e = Self::from_tai_duration(duration);
         // Compute the TAI to UT1 offset at this time.
         // We have the time in TAI. But we were given UT1.
@@ -40,101 +224,330 @@ impl Epoch {
         e
     }
 
-    /// Get the accumulated offset between this epoch and UT1, assuming that the provider includes all data.
-    pub fn ut1_offset(&self, provider: Ut1Provider) -> Option<Duration> {
-        for delta_tai_ut1 in provider.rev() {
-            if self > &delta_tai_ut1.epoch {
-                return Some(delta_tai_ut1.delta_tai_minus_ut1);
-            }
-        }
-        None
+    /// Get the accumulated offset between this epoch and UT1, interpolated with the interpolation mode of the provider.
+    /// Outside of the samples of the provider, its prediction model (after the last sample) or its ΔT model is used if any.
+    /// Otherwise, this is the last value of the provider after its last sample, and None before its first sample. Use `Ut1Source::delta_tai_ut1_estimated` to know how the offset was estimated.
+    pub fn ut1_offset<S: Ut1Source + ?Sized>(&self, provider: &S) -> Option<Duration> {
+        provider.delta_tai_ut1(self).or_else(|| {
+            provider
+                .samples()
+                .last()
+                .filter(|last| self > &last.epoch)
+                .map(|last| last.delta_tai_minus_ut1)
+        })
     }
 
     #[must_use]
     /// Returns this time in a Duration past J1900 counted in UT1
-    pub fn to_ut1_duration(&self, provider: Ut1Provider) -> Duration {
+    pub fn to_ut1_duration<S: Ut1Source + ?Sized>(&self, provider: &S) -> Duration {
         // TAI = UT1 + offset <=> UTC = TAI - offset
         self.to_tai_duration() - self.ut1_offset(provider).unwrap_or(Duration::ZERO)
     }
 
     #[must_use]
-    /// Returns this time in a Duration past J1900 counted in UT1
-    pub fn to_ut1(&self, provider: Ut1Provider) -> Self {
+    /// Returns this time in a Duration past J1900 counted in UT1, but stored in a TAI Epoch.
+    /// Use `to_time_scale_with_ut1(TimeScale::UT1, &provider)` to get an Epoch in the UT1 time scale.
+    pub fn to_ut1<S: Ut1Source + ?Sized>(&self, provider: &S) -> Self {
         Self::from_tai_duration(self.to_ut1_duration(provider))
     }
+
+    /// Returns the Earth Rotation Angle (IAU 2000) of this epoch, in radians in [0, 2π), using the UT1 data of the provided source.
+    /// Returns an error if the source does not cover this epoch.
+    pub fn earth_rotation_angle_rad_with_ut1<S: Ut1Source + ?Sized>(
+        &self,
+        provider: &S,
+    ) -> Result<f64, HifitimeError> {
+        let ut1 = self.to_time_scale_with_ut1(TimeScale::UT1, provider)?;
+        Ok(Self::earth_rotation_angle_at(ut1.duration))
+    }
+
+    /// Returns the Greenwich Mean Sidereal Time (IAU 2006) of this epoch, in radians in [0, 2π), using the UT1 data of the provided source.
+    /// Returns an error if the source does not cover this epoch.
+    pub fn gmst_rad_with_ut1<S: Ut1Source + ?Sized>(
+        &self,
+        provider: &S,
+    ) -> Result<f64, HifitimeError> {
+        let ut1 = self.to_time_scale_with_ut1(TimeScale::UT1, provider)?;
+        let tt = self.to_time_scale_with_ut1(TimeScale::TT, provider)?;
+        Ok(Self::gmst_at(ut1.duration, tt.to_tt_centuries_j2k()))
+    }
+
+    /// Returns the Greenwich Apparent Sidereal Time (IAU 2000B) of this epoch, in radians in [0, 2π), using the UT1 data of the provided source,
+    /// cf. `gast_2000b_rad`. Returns an error if the source does not cover this epoch.
+    pub fn gast_2000b_rad_with_ut1<S: Ut1Source + ?Sized>(
+        &self,
+        provider: &S,
+    ) -> Result<f64, HifitimeError> {
+        let ut1 = self.to_time_scale_with_ut1(TimeScale::UT1, provider)?;
+        let tt = self.to_time_scale_with_ut1(TimeScale::TT, provider)?;
+        Ok(Self::gast_at(ut1.duration, tt.to_tt_centuries_j2k()))
+    }
+
+    /// Returns the local apparent sidereal time of this epoch at the provided longitude (positive east), both in radians,
+    /// using the UT1 data of the provided source, cf. `local_sidereal_time_rad`. Returns an error if the source does not cover this epoch.
+    pub fn local_sidereal_time_rad_with_ut1<S: Ut1Source + ?Sized>(
+        &self,
+        longitude_rad: f64,
+        provider: &S,
+    ) -> Result<f64, HifitimeError> {
+        Ok(normalize_rad(
+            self.gast_2000b_rad_with_ut1(provider)? + longitude_rad,
+        ))
+    }
+
+    /// Returns the local mean solar time of this epoch at the provided longitude (positive east, in radians), using the UT1 data
+    /// of the provided source, cf. `local_mean_solar_time`. Returns an error if the source does not cover this epoch.
+    pub fn local_mean_solar_time_with_ut1<S: Ut1Source + ?Sized>(
+        &self,
+        longitude_rad: f64,
+        provider: &S,
+    ) -> Result<Duration, HifitimeError> {
+        let ut1 = self.to_time_scale_with_ut1(TimeScale::UT1, provider)?;
+        Ok(Self::time_of_day(
+            ut1.duration + Self::mean_solar_time_offset(longitude_rad),
+        ))
+    }
+
+    /// Returns the local apparent solar time of this epoch at the provided longitude (positive east, in radians), using the UT1 data
+    /// of the provided source, cf. `local_apparent_solar_time`. Returns an error if the source does not cover this epoch.
+    pub fn local_apparent_solar_time_with_ut1<S: Ut1Source + ?Sized>(
+        &self,
+        longitude_rad: f64,
+        provider: &S,
+    ) -> Result<Duration, HifitimeError> {
+        let ut1 = self.to_time_scale_with_ut1(TimeScale::UT1, provider)?;
+        let tt = self.to_time_scale_with_ut1(TimeScale::TT, provider)?;
+        Ok(Self::time_of_day(
+            ut1.duration + Self::mean_solar_time_offset(longitude_rad) + tt.equation_of_time(),
+        ))
+    }
 }
 
 #[cfg_attr(kani, derive(kani::Arbitrary))]
 #[derive(Copy, Clone, Debug, Default, Tabled)]
+/// A sample of TAI - UT1 at an epoch, with the other Earth orientation parameters published with it.
 pub struct DeltaTaiUt1 {
     pub epoch: Epoch,
     pub delta_tai_minus_ut1: Duration,
+    pub quality: Ut1Quality,
+    #[tabled(skip)]
+    pub orientation: EarthOrientation,
+}
+
+/// The Earth orientation parameters other than UT1, each of which is None if the EOP data does not include it.
+#[cfg_attr(kani, derive(kani::Arbitrary))]
+#[cfg_attr(feature = "python", pyclass(get_all))]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct EarthOrientation {
+    /// Polar motion: x coordinate of the Celestial Intermediate Pole in the ITRS, in arcseconds.
+    pub x_pole_arcsec: Option<f64>,
+    /// Polar motion: y coordinate of the Celestial Intermediate Pole in the ITRS, in arcseconds.
+    pub y_pole_arcsec: Option<f64>,
+    /// Excess of the length of day over 86400 SI seconds.
+    pub lod: Option<Duration>,
+    /// Celestial pole offset dX with respect to the IAU 2006/2000A precession-nutation model, in arcseconds.
+    pub dx_arcsec: Option<f64>,
+    /// Celestial pole offset dY with respect to the IAU 2006/2000A precession-nutation model, in arcseconds.
+    pub dy_arcsec: Option<f64>,
+}
+
+/// Whether a sample of UT1 was measured or predicted, as flagged in the EOP products.
+///
+/// The qualities are ordered from the most to the least reliable.
+#[cfg_attr(kani, derive(kani::Arbitrary))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+pub enum Ut1Quality {
+    /// Final measured values, e.g. those of IERS Bulletin B and of the C04 series.
+    #[default]
+    Final,
+    /// Measured values which are not final yet, e.g. the rapid service values of IERS Bulletin A (flagged `I`).
+    Rapid,
+    /// Predicted values, e.g. those of IERS Bulletin A flagged `P`.
+    Predicted,
+}
+
+impl fmt::Display for Ut1Quality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Final => write!(f, "final"),
+            Self::Rapid => write!(f, "rapid"),
+            Self::Predicted => write!(f, "predicted"),
+        }
+    }
+}
+
+/// How a `Ut1Provider` computes TAI - UT1 between two of its samples.
+#[cfg_attr(kani, derive(kani::Arbitrary))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
+pub enum Ut1Interpolation {
+    /// Uses the value of the last sample at or before the epoch.
+    Step,
+    /// Linear interpolation between the two samples surrounding the epoch.
+    Linear,
+    /// Cubic Lagrange interpolation over the four samples surrounding the epoch, which is what the IERS recommends for daily EOP data.
+    /// Falls back to `Linear` if the provider has fewer than four samples.
+    #[default]
+    Lagrange,
+}
+
+/// How TAI - UT1 was estimated at an epoch, cf. `Ut1Source::delta_tai_ut1_estimated`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Ut1Estimate {
+    /// The value of the sample at that epoch.
+    Sampled,
+    /// Interpolated between the samples surrounding that epoch, with the provided mode.
+    Interpolated(Ut1Interpolation),
+    /// Predicted after the last sample, with the prediction model of the source.
+    Predicted,
+    /// Modeled outside of the samples, with the ΔT model of the source.
+    Modeled,
+}
+
+/// The model of IERS Bulletin A to predict UT1 beyond the tabulated EOP data:
+///
+/// UT1 - UTC = a + b (MJD - MJD0) - (UT2 - UT1), where MJD is the Modified Julian Date in UTC,
+/// and UT2 - UT1 is the conventional seasonal variation, cf. `Ut1Prediction::ut2_minus_ut1`.
+///
+/// The IERS estimates the accuracy of this prediction to 0.00025 (MJD - MJD0)^0.75 seconds.
+///
+/// (Python documentation hints)
+/// :type a: float
+/// :type b: float
+/// :type mjd0: float
+/// :rtype: Ut1Prediction
+#[cfg_attr(kani, derive(kani::Arbitrary))]
+#[cfg_attr(feature = "python", pyclass)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ut1Prediction {
+    /// UT1 - UTC at MJD0 without the seasonal variation, in seconds.
+    pub a: f64,
+    /// Drift of UT1 - UTC, in seconds per day.
+    pub b: f64,
+    /// The reference Modified Julian Date (UTC) of the model.
+    pub mjd0: f64,
+}
+
+impl Ut1Prediction {
+    /// Builds the prediction model from the provided path to an IERS Bulletin A, e.g. `ser7.dat`.
+    pub fn from_bulletin_a_file(path: &str) -> Result<Self, HifitimeError> {
+        Self::from_bulletin_a_data(read_eop_file(path)?)
+    }
+
+    /// Builds the prediction model from the `UT1-UTC = a + b (MJD - MJD0) - (UT2-UT1)` formula of the provided IERS Bulletin A,
+    /// e.g. `UT1-UTC = -0.0316 - 0.00059 (MJD - 60604) - (UT2-UT1)`.
+    pub fn from_bulletin_a_data(contents: String) -> Result<Self, HifitimeError> {
+        let invalid = || HifitimeError::Parse {
+            source: ParsingError::UnknownFormat,
+            details: "expected Bulletin A to contain UT1-UTC = a + b (MJD - MJD0) - (UT2-UT1)",
+        };
+
+        let formula: String = contents
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("UT1-UTC ="))
+            .find(|formula| formula.contains("(UT2-UT1)"))
+            .ok_or_else(invalid)?
+            .split_whitespace()
+            .collect();
+
+        // The formula is now of the form `a±b(MJD-MJD0)-(UT2-UT1)`.
+        let (constant, rest) = formula.split_once("(MJD-").ok_or_else(invalid)?;
+        let (mjd0, _) = rest.split_once(')').ok_or_else(invalid)?;
+        // The drift is signed, after the first character which may be the sign of the constant.
+        let split = constant[1..]
+            .find(['+', '-'])
+            .map(|idx| idx + 1)
+            .ok_or_else(invalid)?;
+        let (a, b) = constant.split_at(split);
+
+        Ok(Self {
+            a: parse_eop_number(a, "when parsing a of the Bulletin A UT1-UTC formula")?,
+            b: parse_eop_number(
+                b.trim_start_matches('+'),
+                "when parsing b of the Bulletin A UT1-UTC formula",
+            )?,
+            mjd0: parse_eop_number(mjd0, "when parsing MJD0 of the Bulletin A UT1-UTC formula")?,
+        })
+    }
+
+    /// Returns the conventional seasonal variation UT2 - UT1 in seconds at the provided epoch:
+    /// 0.022 sin(2πT) - 0.012 cos(2πT) - 0.006 sin(4πT) + 0.007 cos(4πT), where T is the Besselian year.
+    pub fn ut2_minus_ut1(epoch: &Epoch) -> f64 {
+        let two_pi_t = 2.0 * PI * (2000.0 + (epoch.to_mjd_utc_days() - 51_544.03) / 365.2422);
+        0.022 * two_pi_t.sin() - 0.012 * two_pi_t.cos() - 0.006 * (2.0 * two_pi_t).sin()
+            + 0.007 * (2.0 * two_pi_t).cos()
+    }
+
+    /// Returns the predicted UT1 - UTC at the provided epoch.
+    pub fn delta_ut1_utc(&self, epoch: &Epoch) -> Duration {
+        (self.a + self.b * (epoch.to_mjd_utc_days() - self.mjd0) - Self::ut2_minus_ut1(epoch))
+            .seconds()
+    }
+
+    /// Returns the predicted TAI - UT1 at the provided epoch, using the TAI - UTC of the default conversions.
+    pub fn delta_tai_ut1(&self, epoch: &Epoch) -> Duration {
+        delta_tai_utc(epoch) - self.delta_ut1_utc(epoch)
+    }
 }
 
 #[repr(C)]
 #[cfg_attr(feature = "python", pyclass)]
 #[derive(Clone, Debug, Default)]
 /// A structure storing all of the TAI-UT1 data
+///
+/// The samples are stored as TAI - UT1, which, unlike UT1 - UTC, is continuous across leap seconds and can therefore be interpolated.
 pub struct Ut1Provider {
     data: Vec<DeltaTaiUt1>,
-    iter_pos: usize,
+    interpolation: Ut1Interpolation,
+    prediction: Option<Ut1Prediction>,
+    delta_t_model: Option<DeltaTModel>,
 }
 
 impl Ut1Provider {
     /// Builds a UT1 provided by downloading the data from <https://eop2-external.jpl.nasa.gov/eop2/latest_eop2.short> (short time scale UT1 data) and parsing it.
+    #[cfg(feature = "ut1-download")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ut1-download")))]
     pub fn download_short_from_jpl() -> Result<Self, HifitimeError> {
         Self::download_from_jpl("latest_eop2.short")
     }
 
     /// Build a UT1 provider by downloading the data from <https://eop2-external.jpl.nasa.gov/eop2/latest_eop2.long> (long time scale UT1 data) and parsing it.
+    #[cfg(feature = "ut1-download")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ut1-download")))]
     pub fn download_from_jpl(version: &str) -> Result<Self, HifitimeError> {
-        match get(format!(
-            "https://eop2-external.jpl.nasa.gov/eop2/{}",
-            version
-        )) {
-            Ok(resp) => {
-                let eop_data = String::from_utf8(resp.bytes().unwrap().to_vec()).unwrap();
-                Self::from_eop_data(eop_data)
-            }
-            Err(e) => Err(HifitimeError::Parse {
-                source: ParsingError::DownloadError {
-                    code: e.status().unwrap_or(StatusCode::SEE_OTHER),
-                },
-                details: "when downloading EOP2 file from JPL",
-            }),
-        }
+        Self::fetch_from_jpl(version, download)
     }
 
-    /// Builds a UT1 provider from the provided path to an EOP file.
-    pub fn from_eop_file(path: &str) -> Result<Self, HifitimeError> {
-        let mut f = match File::open(path) {
-            Ok(f) => f,
-            Err(e) => {
-                return Err(HifitimeError::Parse {
-                    source: ParsingError::InOut { err: e.kind() },
-                    details: "when opening EOP file",
-                })
-            }
-        };
-
-        let mut contents = String::new();
-        if let Err(e) = f.read_to_string(&mut contents) {
-            return Err(HifitimeError::Parse {
-                source: ParsingError::InOut { err: e.kind() },
-                details: "when reading EOP file",
-            });
-        }
+    /// Builds a UT1 provider from the provided version of the JPL EOP2 data, e.g. `latest_eop2.short`, where the provided callback
+    /// fetches the contents at the URL of that version, e.g. with another HTTP client, through a proxy, or from a local mirror.
+    ///
+    /// Unlike `download_from_jpl`, this does not require the `ut1-download` feature.
+    pub fn fetch_from_jpl<F>(version: &str, fetch: F) -> Result<Self, HifitimeError>
+    where
+        F: FnOnce(&str) -> Result<String, HifitimeError>,
+    {
+        Self::from_eop_data(fetch(&format!("{JPL_EOP2_URL}/{version}"))?)
+    }
 
-        Self::from_eop_data(contents)
+    /// Builds a UT1 provider from the provided path to a JPL EOP2 file.
+    pub fn from_eop_file(path: &str) -> Result<Self, HifitimeError> {
+        Self::from_eop_data(read_eop_file(path)?)
     }
 
-    /// Builds a UT1 provider from the provided EOP data
+    /// Builds a UT1 provider from the provided JPL EOP2 data.
+    ///
+    /// The samples after the `Last UTPM Data Point` of the header, if any, are flagged as predicted, and all others as rapid.
     pub fn from_eop_data(contents: String) -> Result<Self, HifitimeError> {
         let mut me = Self::default();
 
+        let mut last_measured = None;
         let mut ignore = true;
         for line in contents.lines() {
-            if line == " EOP2=" {
+            if let Some(date) = line.trim().strip_prefix("$  Last UTPM Data Point") {
+                last_measured = Some(Epoch::from_str(date.trim())?);
+                continue;
+            } else if line == " EOP2=" {
                 // Data will start after this line
                 ignore = false;
                 continue;
@@ -176,14 +589,522 @@ impl Ut1Provider {
                 }
             }
 
+            // Polar motion and the celestial pole offsets are in milliarcseconds, and missing from older files.
+            let milliarcseconds = |idx: usize, details| {
+                parse_optional_eop_number(data.get(idx).copied(), details)
+                    .map(|mas| mas.map(|mas| mas / 1000.0))
+            };
+
+            let epoch = Epoch::from_mjd_tai(mjd_tai_days);
             me.data.push(DeltaTaiUt1 {
-                epoch: Epoch::from_mjd_tai(mjd_tai_days),
+                epoch,
                 delta_tai_minus_ut1: delta_ut1_ms * Unit::Millisecond,
+                quality: match last_measured {
+                    Some(last_measured) if epoch > last_measured => Ut1Quality::Predicted,
+                    _ => Ut1Quality::Rapid,
+                },
+                orientation: EarthOrientation {
+                    x_pole_arcsec: milliarcseconds(1, "when parsing PMx in mas (first column)")?,
+                    y_pole_arcsec: milliarcseconds(2, "when parsing PMy in mas (second column)")?,
+                    lod: None,
+                    dx_arcsec: milliarcseconds(10, "when parsing DX in mas (tenth column)")?,
+                    dy_arcsec: milliarcseconds(11, "when parsing DY in mas (eleventh column)")?,
+                },
+            });
+        }
+
+        me.sort_and_dedup();
+
+        Ok(me)
+    }
+
+    /// Builds a UT1 provider from the provided path to an IERS `finals2000A.all`, `finals2000A.daily`, `finals.all` or `finals.daily` file.
+    pub fn from_finals_file(path: &str) -> Result<Self, HifitimeError> {
+        Self::from_finals_data(read_eop_file(path)?)
+    }
+
+    /// Builds a UT1 provider from the provided IERS finals data (fixed-width columns, cf. `readme.finals2000A` of the IERS).
+    ///
+    /// UT1 - UTC is that of Bulletin B where available, flagged as final, and that of Bulletin A otherwise, flagged as rapid
+    /// or predicted depending on its `I` or `P` flag. The dates without any UT1 - UTC value, at the end of the files, are skipped.
+    /// Likewise, the polar motion and celestial pole offsets are those of Bulletin B where available, and the length of day that of Bulletin A.
+    ///
+    /// Note that the IAU 1980 `finals` files store the nutation corrections dψ and dε where the `finals2000A` files store dX and dY:
+    /// only use the celestial pole offsets of `finals2000A` files.
+    pub fn from_finals_data(contents: String) -> Result<Self, HifitimeError> {
+        let mut samples = Vec::new();
+
+        for line in contents.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mjd_utc_days =
+                parse_column(line, 7..15, "when parsing MJD UTC in finals (columns 8-15)")?;
+
+            let quality = match line.get(57..58) {
+                Some("I") => Ut1Quality::Rapid,
+                Some("P") => Ut1Quality::Predicted,
+                Some(" ") | None => continue,
+                Some(_) => {
+                    return Err(HifitimeError::Parse {
+                        source: ParsingError::UnknownFormat,
+                        details: "expected the UT1 - UTC flag of finals (column 58) to be I or P",
+                    })
+                }
+            };
+
+            let optional = |columns: Range<usize>, details| {
+                parse_optional_eop_number(line.get(columns), details)
+            };
+
+            let lod = optional(
+                79..86,
+                "when parsing Bulletin A LOD in finals (columns 80-86)",
+            )?
+            .map(|lod_ms| lod_ms * Unit::Millisecond);
+
+            let bulletin_b = line
+                .get(154..165)
+                .filter(|column| !column.trim().is_empty());
+            let (delta_ut1_utc_s, quality, orientation) = match bulletin_b {
+                Some(_) => (
+                    parse_column(
+                        line,
+                        154..165,
+                        "when parsing Bulletin B UT1 - UTC in finals (columns 155-165)",
+                    )?,
+                    Ut1Quality::Final,
+                    EarthOrientation {
+                        x_pole_arcsec: optional(
+                            134..144,
+                            "when parsing Bulletin B PM-x in finals (columns 135-144)",
+                        )?,
+                        y_pole_arcsec: optional(
+                            144..154,
+                            "when parsing Bulletin B PM-y in finals (columns 145-154)",
+                        )?,
+                        lod,
+                        dx_arcsec: optional(
+                            165..175,
+                            "when parsing Bulletin B dX in finals (columns 166-175)",
+                        )?
+                        .map(|mas| mas / 1000.0),
+                        dy_arcsec: optional(
+                            175..185,
+                            "when parsing Bulletin B dY in finals (columns 176-185)",
+                        )?
+                        .map(|mas| mas / 1000.0),
+                    },
+                ),
+                None => (
+                    parse_column(
+                        line,
+                        58..68,
+                        "when parsing Bulletin A UT1 - UTC in finals (columns 59-68)",
+                    )?,
+                    quality,
+                    EarthOrientation {
+                        x_pole_arcsec: optional(
+                            18..27,
+                            "when parsing Bulletin A PM-x in finals (columns 19-27)",
+                        )?,
+                        y_pole_arcsec: optional(
+                            37..46,
+                            "when parsing Bulletin A PM-y in finals (columns 38-46)",
+                        )?,
+                        lod,
+                        dx_arcsec: optional(
+                            97..106,
+                            "when parsing Bulletin A dX in finals (columns 98-106)",
+                        )?
+                        .map(|mas| mas / 1000.0),
+                        dy_arcsec: optional(
+                            116..125,
+                            "when parsing Bulletin A dY in finals (columns 117-125)",
+                        )?
+                        .map(|mas| mas / 1000.0),
+                    },
+                ),
+            };
+
+            samples.push((
+                Epoch::from_mjd_utc(mjd_utc_days),
+                delta_ut1_utc_s.seconds(),
+                quality,
+                orientation,
+            ));
+        }
+
+        Ok(Self::from_samples(samples))
+    }
+
+    /// Builds a UT1 provider from the provided path to an IERS EOP 14 C04 or EOP 20 C04 file, e.g. `eopc04.1962-now`.
+    pub fn from_c04_file(path: &str) -> Result<Self, HifitimeError> {
+        Self::from_c04_data(read_eop_file(path)?)
+    }
+
+    /// Builds a UT1 provider from the provided IERS EOP 14 C04 or EOP 20 C04 data, where all values are flagged as final.
+    ///
+    /// Both series are whitespace separated, starting with the date: EOP 14 C04 lines are `YYYY MM DD MJD x y UT1-UTC LOD dX dY ...`
+    /// and EOP 20 C04 lines are `YYYY MM DD HH MJD x y UT1-UTC dX dY xrt yrt LOD ...`. All other lines, i.e. the headers, are skipped.
+    pub fn from_c04_data(contents: String) -> Result<Self, HifitimeError> {
+        let mut samples = Vec::new();
+
+        for line in contents.lines() {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            // Data lines start with the year, month and day, which excludes all header lines.
+            if columns.len() < 8
+                || !columns[..3]
+                    .iter()
+                    .all(|column| column.parse::<u16>().is_ok())
+            {
+                continue;
+            }
+
+            // The fourth column is the MJD in EOP 14 C04, but the hour in EOP 20 C04.
+            let fourth = parse_eop_number(
+                columns[3],
+                "when parsing the MJD or hour of C04 (fourth column)",
+            )?;
+            let optional =
+                |idx: usize, details| parse_optional_eop_number(columns.get(idx).copied(), details);
+
+            let (mjd_utc_days, delta_ut1_utc_s, orientation) = if fourth < 24.0 {
+                (
+                    parse_eop_number(
+                        columns[4],
+                        "when parsing MJD UTC of EOP 20 C04 (fifth column)",
+                    )?,
+                    parse_eop_number(
+                        columns[7],
+                        "when parsing UT1 - UTC of EOP 20 C04 (eighth column)",
+                    )?,
+                    EarthOrientation {
+                        x_pole_arcsec: optional(5, "when parsing x of EOP 20 C04 (sixth column)")?,
+                        y_pole_arcsec: optional(
+                            6,
+                            "when parsing y of EOP 20 C04 (seventh column)",
+                        )?,
+                        lod: optional(12, "when parsing LOD of EOP 20 C04 (13th column)")?
+                            .map(|lod_s| lod_s.seconds()),
+                        dx_arcsec: optional(8, "when parsing dX of EOP 20 C04 (ninth column)")?,
+                        dy_arcsec: optional(9, "when parsing dY of EOP 20 C04 (tenth column)")?,
+                    },
+                )
+            } else {
+                (
+                    fourth,
+                    parse_eop_number(
+                        columns[6],
+                        "when parsing UT1 - UTC of EOP 14 C04 (seventh column)",
+                    )?,
+                    EarthOrientation {
+                        x_pole_arcsec: optional(4, "when parsing x of EOP 14 C04 (fifth column)")?,
+                        y_pole_arcsec: optional(5, "when parsing y of EOP 14 C04 (sixth column)")?,
+                        lod: optional(7, "when parsing LOD of EOP 14 C04 (eighth column)")?
+                            .map(|lod_s| lod_s.seconds()),
+                        dx_arcsec: optional(8, "when parsing dX of EOP 14 C04 (ninth column)")?,
+                        dy_arcsec: optional(9, "when parsing dY of EOP 14 C04 (tenth column)")?,
+                    },
+                )
+            };
+
+            samples.push((
+                Epoch::from_mjd_utc(mjd_utc_days),
+                delta_ut1_utc_s.seconds(),
+                Ut1Quality::Final,
+                orientation,
+            ));
+        }
+
+        Ok(Self::from_samples(samples))
+    }
+
+    /// Builds a UT1 provider from samples of UT1 - UTC, e.g. from the IERS bulletins, where each epoch is that of the sample in UTC.
+    ///
+    /// UT1 - UTC jumps by one second at each leap second, so interpolating it directly would be off by up to a second around leap seconds.
+    /// Instead, each sample is stored as TAI - UT1 = (TAI - UTC) - (UT1 - UTC), using the TAI - UTC of the default conversions
+    /// at the epoch of that sample, i.e. including the drift of the UTC offsets before 1972, such that converting that epoch
+    /// from UTC to UT1 gives back its UT1 - UTC.
+    ///
+    /// These samples are flagged as final, without any other Earth orientation parameter.
+    pub fn from_delta_ut1_utc<I: IntoIterator<Item = (Epoch, Duration)>>(samples: I) -> Self {
+        Self::from_samples(samples.into_iter().map(|(epoch, delta_ut1_utc)| {
+            (
+                epoch,
+                delta_ut1_utc,
+                Ut1Quality::Final,
+                EarthOrientation::default(),
+            )
+        }))
+    }
+
+    /// Builds a UT1 provider from samples of UT1 - UTC, their quality and the other Earth orientation parameters, cf. `from_delta_ut1_utc`.
+    fn from_samples<I: IntoIterator<Item = (Epoch, Duration, Ut1Quality, EarthOrientation)>>(
+        samples: I,
+    ) -> Self {
+        let mut me = Self {
+            data: samples
+                .into_iter()
+                .map(|(epoch, delta_ut1_utc, quality, orientation)| DeltaTaiUt1 {
+                    // The lookups compare TAI durations.
+                    epoch: epoch.to_time_scale(TimeScale::TAI),
+                    delta_tai_minus_ut1: delta_tai_utc(&epoch) - delta_ut1_utc,
+                    quality,
+                    orientation,
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        me.sort_and_dedup();
+
+        me
+    }
+
+    /// Sets the interpolation mode used between the samples of this provider, `Ut1Interpolation::Lagrange` by default.
+    #[must_use]
+    pub fn with_interpolation(mut self, interpolation: Ut1Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Sets the model predicting UT1 after the last sample of this provider, e.g. that of the latest IERS Bulletin A.
+    /// By default, this provider has no data after its last sample.
+    #[must_use]
+    pub fn with_prediction(mut self, prediction: Ut1Prediction) -> Self {
+        self.prediction = Some(prediction);
+        self
+    }
+
+    /// Sets the ΔT model used outside of the samples of this provider, e.g. to convert historical epochs between UT1 and TT.
+    /// After the last sample, the prediction model of this provider, if any, has precedence.
+    #[must_use]
+    pub fn with_delta_t_model(mut self, model: DeltaTModel) -> Self {
+        self.delta_t_model = Some(model);
+        self
+    }
+
+    /// Serializes this provider, i.e. its samples, interpolation mode, prediction model and ΔT model, into a compact and versioned
+    /// binary representation, to be loaded with `from_bytes` much faster than parsing the original EOP data.
+    ///
+    /// All numbers are little endian, and the epochs and durations are stored exactly, as their centuries and nanoseconds.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(CACHE_HEADER_LEN + self.data.len() * 62);
+        bytes.extend_from_slice(CACHE_MAGIC);
+        bytes.push(CACHE_VERSION);
+        bytes.push(self.interpolation as u8);
+        match self.prediction {
+            Some(prediction) => {
+                bytes.push(1);
+                for value in [prediction.a, prediction.b, prediction.mjd0] {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            None => bytes.extend_from_slice(&[0; 25]),
+        }
+        // Zero without a ΔT model, and one more than the model otherwise.
+        bytes.push(self.delta_t_model.map_or(0, |model| model as u8 + 1));
+        bytes.extend_from_slice(&(self.data.len() as u64).to_le_bytes());
+
+        let push_duration = |bytes: &mut Vec<u8>, duration: Duration| {
+            let (centuries, nanoseconds) = duration.to_parts();
+            bytes.extend_from_slice(&centuries.to_le_bytes());
+            bytes.extend_from_slice(&nanoseconds.to_le_bytes());
+        };
+
+        for sample in &self.data {
+            push_duration(&mut bytes, sample.epoch.to_tai_duration());
+            push_duration(&mut bytes, sample.delta_tai_minus_ut1);
+            bytes.push(sample.quality as u8);
+
+            // Only the available parameters are stored, flagged by the bits of this mask.
+            let eop = sample.orientation;
+            let values = [
+                eop.x_pole_arcsec,
+                eop.y_pole_arcsec,
+                eop.lod.map(|lod| lod.to_seconds()),
+                eop.dx_arcsec,
+                eop.dy_arcsec,
+            ];
+            let mask = values
+                .iter()
+                .enumerate()
+                .filter(|(_, value)| value.is_some())
+                .fold(0_u8, |mask, (bit, _)| mask | (1 << bit));
+            bytes.push(mask);
+            for value in values.into_iter().flatten() {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        bytes
+    }
+
+    /// Loads a provider serialized with `to_bytes`.
+    /// Returns an error if these bytes are not such a serialization, or one of another version of this format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, HifitimeError> {
+        let mut reader = CacheReader { bytes };
+
+        if reader.take(CACHE_MAGIC.len())? != CACHE_MAGIC {
+            return Err(CacheReader::invalid());
+        }
+        if reader.byte()? != CACHE_VERSION {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::UnknownFormat,
+                details: "unsupported version of the EOP cache",
             });
         }
 
+        let interpolation = match reader.byte()? {
+            0 => Ut1Interpolation::Step,
+            1 => Ut1Interpolation::Linear,
+            2 => Ut1Interpolation::Lagrange,
+            _ => return Err(CacheReader::invalid()),
+        };
+        let has_prediction = reader.byte()? == 1;
+        let prediction = Ut1Prediction {
+            a: reader.f64()?,
+            b: reader.f64()?,
+            mjd0: reader.f64()?,
+        };
+        let delta_t_model = match reader.byte()? {
+            0 => None,
+            1 => Some(DeltaTModel::EspenakMeeus),
+            2 => Some(DeltaTModel::LongTermParabola),
+            _ => return Err(CacheReader::invalid()),
+        };
+
+        let len = usize::try_from(reader.u64()?).map_err(|_| CacheReader::invalid())?;
+        // Each sample is at least `CACHE_MIN_SAMPLE_LEN` bytes long, so a larger number of samples is corrupted.
+        if len > reader.bytes.len() / CACHE_MIN_SAMPLE_LEN {
+            return Err(CacheReader::invalid());
+        }
+        let mut data = Vec::with_capacity(len);
+        for _ in 0..len {
+            let epoch = Epoch::from_tai_duration(reader.duration()?);
+            let delta_tai_minus_ut1 = reader.duration()?;
+            let quality = match reader.byte()? {
+                0 => Ut1Quality::Final,
+                1 => Ut1Quality::Rapid,
+                2 => Ut1Quality::Predicted,
+                _ => return Err(CacheReader::invalid()),
+            };
+
+            let mask = reader.byte()?;
+            let mut values = [None; 5];
+            for (bit, value) in values.iter_mut().enumerate() {
+                if mask & (1 << bit) != 0 {
+                    *value = Some(reader.f64()?);
+                }
+            }
+
+            data.push(DeltaTaiUt1 {
+                epoch,
+                delta_tai_minus_ut1,
+                quality,
+                orientation: EarthOrientation {
+                    x_pole_arcsec: values[0],
+                    y_pole_arcsec: values[1],
+                    lod: values[2].map(|lod| lod.seconds()),
+                    dx_arcsec: values[3],
+                    dy_arcsec: values[4],
+                },
+            });
+        }
+
+        if !reader.bytes.is_empty() {
+            return Err(CacheReader::invalid());
+        }
+
+        let mut me = Self {
+            data,
+            interpolation,
+            prediction: has_prediction.then_some(prediction),
+            delta_t_model,
+        };
+        me.sort_and_dedup();
+        Ok(me)
+    }
+
+    /// Loads the provider cached at `cache_path` if this cache is at least as recent as the EOP file at `source_path`,
+    /// and otherwise parses that file with `parse`, e.g. `Ut1Provider::from_finals_file`, and caches the result at `cache_path`.
+    ///
+    /// A cache which cannot be loaded, e.g. one written by another version of hifitime, is replaced. Caching is best effort:
+    /// if the cache cannot be written, e.g. in a read-only directory, the parsed provider is still returned.
+    pub fn from_cached_file<F>(
+        source_path: &str,
+        cache_path: &str,
+        parse: F,
+    ) -> Result<Self, HifitimeError>
+    where
+        F: FnOnce(&str) -> Result<Self, HifitimeError>,
+    {
+        let modified = |path: &str| metadata(path).and_then(|meta| meta.modified());
+
+        let source_modified = modified(source_path).map_err(|e| HifitimeError::Parse {
+            source: ParsingError::InOut { err: e.kind() },
+            details: "when reading the modification time of the EOP file",
+        })?;
+
+        if matches!(modified(cache_path), Ok(cache_modified) if cache_modified >= source_modified) {
+            if let Ok(me) = read(cache_path)
+                .map_err(|_| CacheReader::invalid())
+                .and_then(|bytes| Self::from_bytes(&bytes))
+            {
+                return Ok(me);
+            }
+        }
+
+        let me = parse(source_path)?;
+        // Failing to write the cache only means that the next call parses the source file again.
+        let _ = write(cache_path, me.to_bytes());
+
         Ok(me)
     }
+
+    /// The interpolation requires the samples to be sorted by epoch, and divides by the differences between their epochs:
+    /// of the samples at the same epoch, e.g. in overlapping files, only the last one is kept.
+    fn sort_and_dedup(&mut self) {
+        // The sort is stable, so the samples at the same epoch remain in their original order.
+        self.data.sort_by_key(|sample| sample.epoch.duration);
+        self.data.dedup_by(|next, kept| {
+            let duplicate = next.epoch.duration == kept.epoch.duration;
+            if duplicate {
+                *kept = *next;
+            }
+            duplicate
+        });
+    }
+
+    /// Registers this provider as the UT1 data used by `Epoch::to_time_scale` and `Epoch::try_to_time_scale` in this process,
+    /// and returns the previously registered provider, if any.
+    ///
+    /// This is a convenience for the conversions which do not take any UT1 data: since it changes them in the whole process,
+    /// prefer providing the UT1 data per conversion, e.g. with `Epoch::to_time_scale_with_ut1` or `Epoch::gmst_rad_with_ut1`.
+    pub fn register(self) -> Option<Arc<Self>> {
+        REGISTERED_PROVIDER
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace(Arc::new(self))
+    }
+
+    /// Unregisters the UT1 provider of this process, if any, and returns it.
+    pub fn unregister() -> Option<Arc<Self>> {
+        REGISTERED_PROVIDER
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+    }
+
+    /// Returns the UT1 provider registered in this process, if any.
+    pub fn registered() -> Option<Arc<Self>> {
+        REGISTERED_PROVIDER
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
 }
 
 #[cfg(feature = "python")]
@@ -197,33 +1118,375 @@ impl Ut1Provider {
     fn __repr__(&self) -> String {
         format!("{self:?} @ {self:p}")
     }
+
+    #[classmethod]
+    /// Builds a UT1 provider from the provided path to an IERS finals file, e.g. `finals2000A.all`.
+    ///
+    /// :type path: str
+    /// :rtype: Ut1Provider
+    #[pyo3(name = "from_finals_file")]
+    fn py_from_finals_file(_cls: &Bound<'_, PyType>, path: &str) -> Result<Self, HifitimeError> {
+        Self::from_finals_file(path)
+    }
+
+    #[classmethod]
+    /// Builds a UT1 provider from the provided path to an IERS EOP 14 C04 or EOP 20 C04 file.
+    ///
+    /// :type path: str
+    /// :rtype: Ut1Provider
+    #[pyo3(name = "from_c04_file")]
+    fn py_from_c04_file(_cls: &Bound<'_, PyType>, path: &str) -> Result<Self, HifitimeError> {
+        Self::from_c04_file(path)
+    }
+
+    /// Returns the quality of TAI - UT1 at the provided epoch, or None if this epoch is outside of the data of this provider.
+    ///
+    /// :type epoch: Epoch
+    /// :rtype: Ut1Quality
+    #[pyo3(name = "quality_at")]
+    fn py_quality_at(&self, epoch: Epoch) -> Option<Ut1Quality> {
+        self.quality_at(&epoch)
+    }
+
+    /// Returns the Earth orientation parameters other than UT1 at the provided epoch, or None if this epoch is outside of the data of this provider.
+    ///
+    /// :type epoch: Epoch
+    /// :rtype: EarthOrientation
+    #[pyo3(name = "earth_orientation_at")]
+    fn py_earth_orientation_at(&self, epoch: Epoch) -> Option<EarthOrientation> {
+        self.earth_orientation_at(&epoch)
+    }
+
+    /// Returns a copy of this provider using the provided prediction model after its last sample.
+    ///
+    /// :type prediction: Ut1Prediction
+    /// :rtype: Ut1Provider
+    #[pyo3(name = "with_prediction")]
+    fn py_with_prediction(&self, prediction: Ut1Prediction) -> Self {
+        self.clone().with_prediction(prediction)
+    }
+
+    /// Returns a copy of this provider using the provided ΔT model outside of its samples.
+    ///
+    /// :type model: DeltaTModel
+    /// :rtype: Ut1Provider
+    #[pyo3(name = "with_delta_t_model")]
+    fn py_with_delta_t_model(&self, model: DeltaTModel) -> Self {
+        self.clone().with_delta_t_model(model)
+    }
+
+    /// Returns a copy of this provider using the provided interpolation mode.
+    ///
+    /// :type interpolation: Ut1Interpolation
+    /// :rtype: Ut1Provider
+    #[pyo3(name = "with_interpolation")]
+    fn py_with_interpolation(&self, interpolation: Ut1Interpolation) -> Self {
+        self.clone().with_interpolation(interpolation)
+    }
+
+    /// Returns the interpolation mode requested for this provider.
+    ///
+    /// :rtype: Ut1Interpolation
+    #[pyo3(name = "interpolation")]
+    fn py_interpolation(&self) -> Ut1Interpolation {
+        self.interpolation
+    }
+
+    /// Registers a copy of this provider as the UT1 data used by the time scale conversions which do not take any UT1 data,
+    /// in the whole process: prefer providing it per conversion with `Epoch.to_time_scale_with_ut1`.
+    #[pyo3(name = "register")]
+    fn py_register(&self) {
+        self.clone().register();
+    }
 }
 
-impl fmt::Display for Ut1Provider {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut table = Table::new(&self.data);
-        table.with(Style::rounded());
-        write!(f, "{}", table)
+/// Returns TAI - UTC at the provided epoch, as used by the default conversions between UTC and TAI (and so UT1).
+fn delta_tai_utc(epoch: &Epoch) -> Duration {
+    epoch.to_tai_duration() - epoch.to_utc_duration()
+}
+
+/// Downloads the contents at the provided URL.
+#[cfg(feature = "ut1-download")]
+fn download(url: &str) -> Result<String, HifitimeError> {
+    get(url)
+        .and_then(|resp| resp.error_for_status())
+        .and_then(|resp| resp.text())
+        .map_err(|e| HifitimeError::Parse {
+            source: ParsingError::DownloadError {
+                code: e.status().unwrap_or(StatusCode::SEE_OTHER).as_u16(),
+            },
+            details: "when downloading EOP2 file from JPL",
+        })
+}
+
+/// The first bytes of a serialized `Ut1Provider`, followed by the version of the format.
+const CACHE_MAGIC: &[u8] = b"HIFITIME-EOP";
+/// The version of the serialization format of `Ut1Provider`, to be incremented on any change of that format.
+const CACHE_VERSION: u8 = 2;
+/// The length of the magic, version, interpolation mode, prediction model, ΔT model and number of samples.
+const CACHE_HEADER_LEN: usize = 12 + 1 + 1 + 25 + 1 + 8;
+/// The length of a sample without any Earth orientation parameter: its epoch, TAI - UT1, quality and mask of parameters.
+const CACHE_MIN_SAMPLE_LEN: usize = 10 + 10 + 1 + 1;
+
+/// Reads the fields of a serialized `Ut1Provider` in order.
+struct CacheReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> CacheReader<'a> {
+    fn invalid() -> HifitimeError {
+        HifitimeError::Parse {
+            source: ParsingError::UnknownFormat,
+            details: "invalid or truncated EOP cache",
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], HifitimeError> {
+        if self.bytes.len() < len {
+            return Err(Self::invalid());
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], HifitimeError> {
+        self.take(N)?.try_into().map_err(|_| Self::invalid())
+    }
+
+    fn byte(&mut self) -> Result<u8, HifitimeError> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn u64(&mut self) -> Result<u64, HifitimeError> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    fn f64(&mut self) -> Result<f64, HifitimeError> {
+        self.array().map(f64::from_le_bytes)
+    }
+
+    fn duration(&mut self) -> Result<Duration, HifitimeError> {
+        let centuries = i16::from_le_bytes(self.array()?);
+        Ok(Duration::from_parts(centuries, self.u64()?))
     }
 }
 
-impl Iterator for Ut1Provider {
-    type Item = DeltaTaiUt1;
+/// Reads the whole EOP file at the provided path.
+fn read_eop_file(path: &str) -> Result<String, HifitimeError> {
+    let mut f = match File::open(path) {
+        Ok(f) => f,
+        Err(e) => {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::InOut { err: e.kind() },
+                details: "when opening EOP file",
+            })
+        }
+    };
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.iter_pos += 1;
-        self.data.get(self.iter_pos - 1).copied()
+    let mut contents = String::new();
+    if let Err(e) = f.read_to_string(&mut contents) {
+        return Err(HifitimeError::Parse {
+            source: ParsingError::InOut { err: e.kind() },
+            details: "when reading EOP file",
+        });
     }
+
+    Ok(contents)
 }
 
-impl DoubleEndedIterator for Ut1Provider {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.iter_pos == self.data.len() {
-            None
-        } else {
-            self.iter_pos += 1;
-            self.data.get(self.data.len() - self.iter_pos).copied()
+/// Parses the number in the provided (zero-indexed, byte) columns of a fixed-width line.
+fn parse_column(
+    line: &str,
+    columns: Range<usize>,
+    details: &'static str,
+) -> Result<f64, HifitimeError> {
+    let column = line.get(columns).ok_or(HifitimeError::Parse {
+        source: ParsingError::UnknownFormat,
+        details,
+    })?;
+    parse_eop_number(column, details)
+}
+
+/// Parses an optional number of an EOP file, which is None if the column is missing or blank.
+fn parse_optional_eop_number(
+    column: Option<&str>,
+    details: &'static str,
+) -> Result<Option<f64>, HifitimeError> {
+    match column {
+        Some(column) if !column.trim().is_empty() => parse_eop_number(column, details).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Parses a number of an EOP file.
+fn parse_eop_number(column: &str, details: &'static str) -> Result<f64, HifitimeError> {
+    lexical_core::parse(column.trim().as_bytes()).map_err(|err| HifitimeError::Parse {
+        source: ParsingError::Lexical { err },
+        details,
+    })
+}
+
+/// The samples surrounding an epoch, and their weights to interpolate any of their quantities at that epoch.
+struct Window<'a> {
+    samples: &'a [DeltaTaiUt1],
+    weights: [f64; 4],
+    used: Ut1Interpolation,
+}
+
+impl<'a> Window<'a> {
+    /// Returns the window of the provided samples, sorted by increasing epoch, at the provided TAI duration past J1900,
+    /// or None if it is outside of these samples.
+    fn new(
+        samples: &'a [DeltaTaiUt1],
+        tai: Duration,
+        interpolation: Ut1Interpolation,
+    ) -> Option<Self> {
+        let first = samples.first()?;
+        let last = samples.last()?;
+        if tai < first.epoch.duration || tai > last.epoch.duration {
+            return None;
+        }
+
+        // Index of the last sample at or before this epoch: the data is sorted, so this is a binary search.
+        let idx = samples.partition_point(|sample| sample.epoch.duration <= tai) - 1;
+
+        let (start, len, used) = match interpolation {
+            Ut1Interpolation::Lagrange if samples.len() >= 4 => (
+                idx.saturating_sub(1).min(samples.len() - 4),
+                4,
+                Ut1Interpolation::Lagrange,
+            ),
+            Ut1Interpolation::Lagrange | Ut1Interpolation::Linear if samples.len() >= 2 => {
+                (idx.min(samples.len() - 2), 2, Ut1Interpolation::Linear)
+            }
+            _ => (idx, 1, Ut1Interpolation::Step),
+        };
+
+        let window = &samples[start..start + len];
+        // Work relative to the first sample of the window to preserve the precision of the f64 computations.
+        let origin = window[0].epoch.duration;
+        let x = (tai - origin).to_seconds();
+
+        let mut weights = [0.0; 4];
+        for (i, sample_i) in window.iter().enumerate() {
+            let x_i = (sample_i.epoch.duration - origin).to_seconds();
+            weights[i] = 1.0;
+            for (j, sample_j) in window.iter().enumerate() {
+                if i != j {
+                    let x_j = (sample_j.epoch.duration - origin).to_seconds();
+                    weights[i] *= (x - x_j) / (x_i - x_j);
+                }
+            }
         }
+
+        Some(Self {
+            samples: window,
+            weights,
+            used,
+        })
+    }
+
+    /// Interpolates TAI - UT1.
+    fn delta_tai_ut1(&self) -> Duration {
+        let origin = self.samples[0].delta_tai_minus_ut1;
+        let delta: f64 = self
+            .samples
+            .iter()
+            .zip(self.weights)
+            .map(|(sample, weight)| weight * (sample.delta_tai_minus_ut1 - origin).to_seconds())
+            .sum();
+        origin + delta.seconds()
+    }
+
+    /// Interpolates the provided quantity of the samples, or returns None if any of these samples lacks it.
+    fn interpolate<F: Fn(&EarthOrientation) -> Option<f64>>(&self, quantity: F) -> Option<f64> {
+        let origin = quantity(&self.samples[0].orientation)?;
+        let mut delta = 0.0;
+        for (sample, weight) in self.samples.iter().zip(self.weights) {
+            delta += weight * (quantity(&sample.orientation)? - origin);
+        }
+        Some(origin + delta)
+    }
+}
+
+impl Ut1Source for Ut1Provider {
+    fn samples(&self) -> &[DeltaTaiUt1] {
+        &self.data
+    }
+
+    fn interpolation(&self) -> Ut1Interpolation {
+        self.interpolation
+    }
+
+    fn prediction(&self) -> Option<Ut1Prediction> {
+        self.prediction
+    }
+
+    fn delta_t_model(&self) -> Option<DeltaTModel> {
+        self.delta_t_model
+    }
+}
+
+/// A ΔT model is a source of UT1 without any sample, which covers every epoch.
+impl Ut1Source for DeltaTModel {
+    fn samples(&self) -> &[DeltaTaiUt1] {
+        &[]
+    }
+
+    fn delta_t_model(&self) -> Option<DeltaTModel> {
+        Some(*self)
+    }
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(feature = "python", pymethods)]
+impl Ut1Prediction {
+    #[new]
+    pub fn __new__(a: f64, b: f64, mjd0: f64) -> Self {
+        Self { a, b, mjd0 }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?} @ {self:p}")
+    }
+
+    #[classmethod]
+    /// Builds the prediction model from the provided path to an IERS Bulletin A.
+    ///
+    /// :type path: str
+    /// :rtype: Ut1Prediction
+    #[pyo3(name = "from_bulletin_a_file")]
+    fn py_from_bulletin_a_file(
+        _cls: &Bound<'_, PyType>,
+        path: &str,
+    ) -> Result<Self, HifitimeError> {
+        Self::from_bulletin_a_file(path)
+    }
+
+    /// Returns the predicted UT1 - UTC at the provided epoch.
+    ///
+    /// :type epoch: Epoch
+    /// :rtype: Duration
+    #[pyo3(name = "delta_ut1_utc")]
+    fn py_delta_ut1_utc(&self, epoch: Epoch) -> Duration {
+        self.delta_ut1_utc(&epoch)
+    }
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(feature = "python", pymethods)]
+impl EarthOrientation {
+    fn __repr__(&self) -> String {
+        format!("{self:?} @ {self:p}")
+    }
+}
+
+impl fmt::Display for Ut1Provider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut table = Table::new(&self.data);
+        table.with(Style::rounded());
+        write!(f, "{}", table)
     }
 }
 
@@ -238,6 +1501,7 @@ impl Index<usize> for Ut1Provider {
 #[cfg(kani)]
 mod kani_harnesses {
     use super::*;
+    #[cfg(feature = "ut1-download")]
     #[kani::proof]
     fn kani_harness_Ut1Provider_download_short_from_jpl() {
         Ut1Provider::download_short_from_jpl();
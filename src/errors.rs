@@ -16,10 +16,7 @@ use std::io::ErrorKind as IOError;
 
 use lexical_core::Error as LexicalError;
 
-#[cfg(feature = "ut1")]
-use reqwest::StatusCode;
-
-use crate::Weekday;
+use crate::{Epoch, Weekday};
 
 /// Errors handles all oddities which may occur in this library.
 #[non_exhaustive]
@@ -38,6 +35,36 @@ pub enum HifitimeError {
     Duration {
         source: DurationError,
     },
+    #[snafu(display("no UT1 data available for {epoch:?}"))]
+    MissingUt1Data {
+        epoch: Epoch,
+    },
+    #[snafu(display("no TT - TDB time ephemeris data available for {epoch:?}"))]
+    MissingTimeEphemerisData {
+        epoch: Epoch,
+    },
+    #[snafu(display("the trajectory of the proper time does not cover {epoch:?}"))]
+    MissingProperTimeData {
+        epoch: Epoch,
+    },
+    #[snafu(display("invalid trajectory: {details}"))]
+    InvalidTrajectory {
+        details: &'static str,
+    },
+    #[snafu(display("invalid custom time scale: {details}"))]
+    InvalidCustomTimeScale {
+        details: &'static str,
+    },
+    #[cfg(feature = "std")]
+    #[snafu(display("a different custom time scale named {name} is already registered"))]
+    DuplicateCustomTimeScale {
+        name: String,
+    },
+    #[snafu(display("leap seconds expired on {expires:?}, before {epoch:?}"))]
+    ExpiredLeapSeconds {
+        expires: Epoch,
+        epoch: Epoch,
+    },
     #[cfg(feature = "python")]
     #[snafu(display("python interop error: {reason}"))]
     PythonError {
@@ -84,13 +111,15 @@ pub enum ParsingError {
         expected: Weekday,
     },
     InvalidTimezone,
+    HashMismatch,
     #[cfg(feature = "std")]
     InOut {
         err: IOError,
     },
+    /// Downloading EOP data failed with the provided HTTP status code.
     #[cfg(feature = "ut1")]
     DownloadError {
-        code: StatusCode,
+        code: u16,
     },
 }
 
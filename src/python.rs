@@ -14,22 +14,39 @@ use pyo3::{
     types::{PyDict, PyTuple},
 };
 
-use crate::leap_seconds::{LatestLeapSeconds, LeapSecondsFile};
+use crate::leap_seconds::{LatestLeapSeconds, LeapSecondsFile, LeapSecondsKernel};
 use crate::prelude::*;
-use crate::ut1::Ut1Provider;
-use crate::MonthName;
+use crate::ut1::{EarthOrientation, Ut1Interpolation, Ut1Prediction, Ut1Provider, Ut1Quality};
+use crate::{
+    CustomTimeScale, DeltaTModel, EtModel, MonthName, Observer, ObserverFrame, ProperTime,
+    PyTimeScale, TdbModel, TimeEphemeris, TrajectorySample,
+};
 
 // Keep the module at the top
 #[pymodule]
 fn hifitime(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Epoch>()?;
-    m.add_class::<TimeScale>()?;
+    m.add_class::<PyTimeScale>()?;
+    m.add_class::<CustomTimeScale>()?;
     m.add_class::<TimeSeries>()?;
     m.add_class::<Duration>()?;
     m.add_class::<Unit>()?;
     m.add_class::<LatestLeapSeconds>()?;
     m.add_class::<LeapSecondsFile>()?;
+    m.add_class::<LeapSecondsKernel>()?;
+    m.add_class::<EtModel>()?;
+    m.add_class::<DeltaTModel>()?;
+    m.add_class::<TdbModel>()?;
+    m.add_class::<TimeEphemeris>()?;
+    m.add_class::<Observer>()?;
+    m.add_class::<ObserverFrame>()?;
+    m.add_class::<ProperTime>()?;
+    m.add_class::<TrajectorySample>()?;
     m.add_class::<Ut1Provider>()?;
+    m.add_class::<Ut1Interpolation>()?;
+    m.add_class::<Ut1Quality>()?;
+    m.add_class::<Ut1Prediction>()?;
+    m.add_class::<EarthOrientation>()?;
     m.add_class::<MonthName>()?;
     m.add_class::<PyHifitimeError>()?;
     m.add_class::<PyDurationError>()?;
@@ -0,0 +1,514 @@
+/*
+* Hifitime
+* Copyright (C) 2017-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+* This Source Code Form is subject to the terms of the Mozilla Public
+* License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at https://mozilla.org/MPL/2.0/.
+*
+* Documentation: https://nyxspace.com/
+*/
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::{
+    CustomTimeScaleId, Duration, Epoch, HifitimeError, ParsingError, TimeScale, TimeUnits,
+};
+
+/// A custom time scale registered in this process, whose definition never changes once registered.
+struct Registration {
+    time_scale: Arc<CustomTimeScale>,
+    /// Whether the name of this time scale is parsed, i.e. it was not unregistered.
+    active: bool,
+}
+
+/// The custom time scales registered in this process, indexed by their `CustomTimeScaleId`.
+/// Unregistering keeps their definitions, such that the epochs in an unregistered time scale remain valid,
+/// until `CustomTimeScale::purge_unregistered` frees their slots (`None`) for the next registrations.
+static REGISTERED_TIME_SCALES: RwLock<Vec<Option<Registration>>> = RwLock::new(Vec::new());
+/// Whether any custom time scale is registered, such that parsing time scales does not lock the registry otherwise.
+static HAS_REGISTERED_TIME_SCALES: AtomicBool = AtomicBool::new(false);
+
+/// A user-defined time scale, e.g. the clock of a spacecraft or a mission elapsed time, defined from a base time scale.
+///
+/// The custom clock reads `offset` at its reference epoch, and runs faster than the base time scale by `rate_ppm`
+/// parts per million: `custom = offset + (1 + rate_ppm × 10^-6) × (base - reference)`.
+/// Its durations are counted past its reference epoch, and its calendar is that of the base time scale, such that
+/// a custom time scale without any offset nor rate shows the same Gregorian dates as its base time scale.
+///
+/// Register it to get its `TimeScale::Custom` handle: epochs can then be converted into this time scale with
+/// `Epoch::to_time_scale`, are formatted in it (including by `Formatter`), and are parsed by `Epoch::from_str`
+/// when suffixed by its name, e.g. `2024-01-01T00:00:00 MET`. The duration of an epoch in this time scale is its reading,
+/// i.e. `to_duration`.
+///
+/// The methods of this structure convert, format and parse epochs in this time scale without registering it,
+/// e.g. `to_duration`, `to_gregorian_str` and `parse`.
+///
+/// (Python documentation hints)
+/// :type name: str
+/// :type reference_epoch: Epoch
+/// :type base: TimeScale
+/// :type offset: Duration, optional
+/// :type rate_ppm: float, optional
+/// :rtype: CustomTimeScale
+#[cfg_attr(feature = "python", pyclass)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomTimeScale {
+    name: String,
+    /// Reference epoch, in the base time scale.
+    reference_epoch: Epoch,
+    offset: Duration,
+    rate_ppm: f64,
+}
+
+impl CustomTimeScale {
+    /// Defines a custom time scale named `name`, counted from the provided reference epoch in the base time scale,
+    /// without any offset nor rate.
+    ///
+    /// Returns an error if the name is not made of ASCII letters, digits and underscores starting with a letter,
+    /// if it is that of a built-in time scale, or if the base time scale is itself a custom time scale.
+    pub fn new(name: &str, reference_epoch: Epoch, base: TimeScale) -> Result<Self, HifitimeError> {
+        if matches!(base, TimeScale::Custom(_)) {
+            return Err(HifitimeError::InvalidCustomTimeScale {
+                details: "the base time scale must be a built-in time scale",
+            });
+        }
+        if !name.starts_with(|c: char| c.is_ascii_alphabetic())
+            || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            return Err(HifitimeError::InvalidCustomTimeScale {
+                details:
+                    "the name must be ASCII letters, digits and underscores, starting with a letter",
+            });
+        }
+        if TimeScale::from_builtin_str(name).is_some() {
+            return Err(HifitimeError::InvalidCustomTimeScale {
+                details: "the name is that of a built-in time scale",
+            });
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            reference_epoch: reference_epoch.to_time_scale(base),
+            offset: Duration::ZERO,
+            rate_ppm: 0.0,
+        })
+    }
+
+    /// Returns a copy of this time scale which reads the provided offset at its reference epoch.
+    pub fn with_offset(mut self, offset: Duration) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns a copy of this time scale which runs faster than its base time scale by `rate_ppm` parts per million.
+    ///
+    /// Returns an error if the rate is not finite, or if the custom clock would not move forward, i.e. `rate_ppm <= -10^6`.
+    pub fn with_rate_ppm(mut self, rate_ppm: f64) -> Result<Self, HifitimeError> {
+        if !rate_ppm.is_finite() || rate_ppm <= -1e6 {
+            return Err(HifitimeError::InvalidCustomTimeScale {
+                details: "the rate must be finite and greater than -10^6 ppm",
+            });
+        }
+        self.rate_ppm = rate_ppm;
+        Ok(self)
+    }
+
+    /// Returns the name of this time scale.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the reference epoch of this time scale, in its base time scale.
+    pub fn reference_epoch(&self) -> Epoch {
+        self.reference_epoch
+    }
+
+    /// Returns the base time scale of this time scale.
+    pub fn base(&self) -> TimeScale {
+        self.reference_epoch.time_scale
+    }
+
+    /// Returns the reading of this time scale at its reference epoch.
+    pub fn offset(&self) -> Duration {
+        self.offset
+    }
+
+    /// Returns the rate of this time scale relative to its base time scale, in parts per million.
+    pub fn rate_ppm(&self) -> f64 {
+        self.rate_ppm
+    }
+
+    /// Returns the duration past the reference epoch read by this time scale at the provided epoch, in any time scale.
+    pub fn to_duration(&self, epoch: &Epoch) -> Duration {
+        self.reading(epoch.to_time_scale(self.base()).duration)
+    }
+
+    /// Returns the epoch, in the base time scale, at which this time scale reads the provided duration past its reference epoch.
+    pub fn from_duration(&self, duration: Duration) -> Epoch {
+        Epoch::from_duration(self.base_duration(duration), self.base())
+    }
+
+    /// Returns the reading of this time scale at the provided duration in its base time scale.
+    pub(crate) fn reading(&self, base_duration: Duration) -> Duration {
+        let elapsed = base_duration - self.reference_epoch.duration;
+        // The rate is applied to the elapsed seconds separately to preserve the nanosecond precision of long durations.
+        elapsed + (elapsed.to_seconds() * self.rate_ppm * 1e-6).seconds() + self.offset
+    }
+
+    /// Returns the duration in the base time scale at which this time scale reads the provided duration.
+    pub(crate) fn base_duration(&self, reading: Duration) -> Duration {
+        let reading = reading - self.offset;
+        let rate = self.rate_ppm * 1e-6;
+        self.reference_epoch.duration + reading
+            - (reading.to_seconds() * rate / (1.0 + rate)).seconds()
+    }
+
+    /// Returns the Gregorian date and time of the provided epoch in this time scale,
+    /// as (year, month, day, hour, minute, second, nanoseconds).
+    pub fn to_gregorian(&self, epoch: &Epoch) -> (i32, u8, u8, u8, u8, u8, u32) {
+        Epoch::compute_gregorian(
+            self.reference_epoch.duration + self.to_duration(epoch),
+            self.base(),
+        )
+    }
+
+    /// Returns the provided epoch formatted as a Gregorian date and time in this time scale, e.g. `2024-01-01T00:00:00 MET`.
+    pub fn to_gregorian_str(&self, epoch: &Epoch) -> String {
+        let (y, mm, dd, hh, min, s, nanos) = self.to_gregorian(epoch);
+        if nanos == 0 {
+            format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02} {}",
+                y, mm, dd, hh, min, s, self.name
+            )
+        } else {
+            format!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09} {}",
+                y, mm, dd, hh, min, s, nanos, self.name
+            )
+        }
+    }
+
+    /// Parses an epoch in this time scale, either as a Gregorian date and time (e.g. `2024-01-01T12:34:56.789 MET`) or as seconds
+    /// past its reference epoch (e.g. `SEC 17.2 MET`), and returns it in the base time scale.
+    /// The name of this time scale is optional at the end of the string, but any other time scale (including `Z` for UTC)
+    /// and timezone offsets are rejected.
+    pub fn parse(&self, s_in: &str) -> Result<Epoch, HifitimeError> {
+        let mut s = s_in.trim();
+        if let Some(stripped) = s.strip_suffix(self.name.as_str()) {
+            if stripped.ends_with(char::is_whitespace) {
+                s = stripped.trim_end();
+            }
+        }
+
+        if let Some(value) = s.strip_prefix("SEC") {
+            let value: f64 =
+                lexical_core::parse(value.trim().as_bytes()).map_err(|_| HifitimeError::Parse {
+                    source: ParsingError::ValueError,
+                    details: "parsing as SEC in a custom time scale",
+                })?;
+            return Ok(self.from_duration(value.seconds()));
+        }
+
+        let (parsed, tz, explicit_ts) = Epoch::parse_gregorian_str(s)?;
+        if explicit_ts {
+            // The Gregorian date must be expressed in this time scale only.
+            return Err(HifitimeError::Parse {
+                source: ParsingError::TimeSystem,
+                details: "parsing as Gregorian date in a custom time scale",
+            });
+        }
+        if tz != Duration::ZERO {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::ISO8601,
+                details: "timezone offsets are not supported in a custom time scale",
+            });
+        }
+        // Express the parsed Gregorian date in the calendar of the base time scale.
+        let calendar = parsed.duration + parsed.time_scale.gregorian_epoch_offset()
+            - self.base().gregorian_epoch_offset();
+        Ok(self.from_duration(calendar - self.reference_epoch.duration))
+    }
+
+    /// Registers this time scale in this process and returns its `TimeScale::Custom` handle, cf. the documentation of this structure.
+    ///
+    /// A registered definition never changes, such that the epochs in its time scale keep their meaning:
+    /// registering the same definition again returns the same handle, even after it was unregistered, and registering a
+    /// different definition after the time scale of that name was unregistered returns a new handle.
+    /// Returns a `DuplicateCustomTimeScale` error if a different time scale of the same name is registered,
+    /// or an error if 65536 custom time scales are already registered or unregistered but not purged.
+    pub fn register(self) -> Result<TimeScale, HifitimeError> {
+        let mut registered = REGISTERED_TIME_SCALES
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(active) = registered
+            .iter()
+            .flatten()
+            .find(|r| r.active && r.time_scale.name == self.name)
+        {
+            if *active.time_scale != self {
+                return Err(HifitimeError::DuplicateCustomTimeScale { name: self.name });
+            }
+        }
+        let known = registered
+            .iter()
+            .position(|r| r.as_ref().is_some_and(|r| *r.time_scale == self));
+        let idx = match known {
+            Some(idx) => {
+                if let Some(r) = registered[idx].as_mut() {
+                    r.active = true;
+                }
+                idx
+            }
+            None => {
+                let registration = Registration {
+                    time_scale: Arc::new(self),
+                    active: true,
+                };
+                match registered.iter().position(Option::is_none) {
+                    Some(idx) => {
+                        registered[idx] = Some(registration);
+                        idx
+                    }
+                    None => {
+                        if registered.len() > usize::from(u16::MAX) {
+                            return Err(HifitimeError::InvalidCustomTimeScale {
+                                details: "too many custom time scales are registered",
+                            });
+                        }
+                        registered.push(Some(registration));
+                        registered.len() - 1
+                    }
+                }
+            }
+        };
+        HAS_REGISTERED_TIME_SCALES.store(true, Ordering::Release);
+        Ok(TimeScale::Custom(CustomTimeScaleId(idx as u16)))
+    }
+
+    /// Unregisters the time scale of the provided name, if any, and returns it.
+    ///
+    /// Its name is no longer parsed, but the epochs already in this time scale remain valid,
+    /// and registering the same definition again returns the same handle.
+    pub fn unregister(name: &str) -> Option<Arc<Self>> {
+        let mut registered = REGISTERED_TIME_SCALES
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        let registration = registered
+            .iter_mut()
+            .flatten()
+            .find(|r| r.active && r.time_scale.name == name)?;
+        registration.active = false;
+        let custom = registration.time_scale.clone();
+        HAS_REGISTERED_TIME_SCALES.store(
+            registered.iter().flatten().any(|r| r.active),
+            Ordering::Release,
+        );
+        Some(custom)
+    }
+
+    /// Forgets the definitions of all the unregistered time scales, and returns how many were forgotten.
+    ///
+    /// The registry of this process otherwise keeps every definition ever registered, such that it never shrinks.
+    /// The handles of the forgotten time scales are reused by the next registrations, so the epochs in these time scales
+    /// must no longer be used: `from_time_scale` returns None for them until their handles are reused.
+    pub fn purge_unregistered() -> usize {
+        let mut registered = REGISTERED_TIME_SCALES
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        let mut purged = 0;
+        for slot in registered.iter_mut() {
+            if slot.as_ref().is_some_and(|r| !r.active) {
+                *slot = None;
+                purged += 1;
+            }
+        }
+        while registered.last().is_some_and(Option::is_none) {
+            registered.pop();
+        }
+        registered.shrink_to_fit();
+        purged
+    }
+
+    /// Returns the time scale of the provided name registered in this process, if any.
+    pub fn registered(name: &str) -> Option<Arc<Self>> {
+        let ts = Self::registered_time_scale(name)?;
+        Self::from_time_scale(ts)
+    }
+
+    /// Returns the custom time scale of the provided `TimeScale::Custom` handle, even if it was unregistered,
+    /// or None for a built-in time scale.
+    pub fn from_time_scale(ts: TimeScale) -> Option<Arc<Self>> {
+        let TimeScale::Custom(CustomTimeScaleId(idx)) = ts else {
+            return None;
+        };
+        REGISTERED_TIME_SCALES
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(usize::from(idx))?
+            .as_ref()
+            .map(|r| r.time_scale.clone())
+    }
+
+    /// Returns the `TimeScale::Custom` handle of the time scale of the provided name registered in this process, if any.
+    pub(crate) fn registered_time_scale(name: &str) -> Option<TimeScale> {
+        if !HAS_REGISTERED_TIME_SCALES.load(Ordering::Acquire) {
+            return None;
+        }
+        REGISTERED_TIME_SCALES
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .position(|r| {
+                r.as_ref()
+                    .is_some_and(|r| r.active && r.time_scale.name == name)
+            })
+            .map(|idx| TimeScale::Custom(CustomTimeScaleId(idx as u16)))
+    }
+}
+
+#[cfg(feature = "python")]
+#[cfg_attr(feature = "python", pymethods)]
+impl CustomTimeScale {
+    #[new]
+    #[pyo3(signature=(name, reference_epoch, base, offset=None, rate_ppm=None))]
+    fn __new__(
+        name: String,
+        reference_epoch: Epoch,
+        base: TimeScale,
+        offset: Option<Duration>,
+        rate_ppm: Option<f64>,
+    ) -> Result<Self, HifitimeError> {
+        Self::new(&name, reference_epoch, base)?
+            .with_offset(offset.unwrap_or(Duration::ZERO))
+            .with_rate_ppm(rate_ppm.unwrap_or(0.0))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?} @ {self:p}")
+    }
+
+    fn __str__(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Returns the name of this time scale.
+    ///
+    /// :rtype: str
+    #[pyo3(name = "name")]
+    fn py_name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Returns the reference epoch of this time scale, in its base time scale.
+    ///
+    /// :rtype: Epoch
+    #[pyo3(name = "reference_epoch")]
+    fn py_reference_epoch(&self) -> Epoch {
+        self.reference_epoch()
+    }
+
+    /// Returns the base time scale of this time scale.
+    ///
+    /// :rtype: TimeScale
+    #[pyo3(name = "base")]
+    fn py_base(&self) -> TimeScale {
+        self.base()
+    }
+
+    /// Returns the reading of this time scale at its reference epoch.
+    ///
+    /// :rtype: Duration
+    #[pyo3(name = "offset")]
+    fn py_offset(&self) -> Duration {
+        self.offset()
+    }
+
+    /// Returns the rate of this time scale relative to its base time scale, in parts per million.
+    ///
+    /// :rtype: float
+    #[pyo3(name = "rate_ppm")]
+    fn py_rate_ppm(&self) -> f64 {
+        self.rate_ppm()
+    }
+
+    /// Returns the duration past the reference epoch read by this time scale at the provided epoch, in any time scale.
+    ///
+    /// :type epoch: Epoch
+    /// :rtype: Duration
+    #[pyo3(name = "to_duration")]
+    fn py_to_duration(&self, epoch: Epoch) -> Duration {
+        self.to_duration(&epoch)
+    }
+
+    /// Returns the epoch, in the base time scale, at which this time scale reads the provided duration past its reference epoch.
+    ///
+    /// :type duration: Duration
+    /// :rtype: Epoch
+    #[pyo3(name = "from_duration")]
+    fn py_from_duration(&self, duration: Duration) -> Epoch {
+        self.from_duration(duration)
+    }
+
+    /// Returns the provided epoch formatted as a Gregorian date and time in this time scale, e.g. `2024-01-01T00:00:00 MET`.
+    ///
+    /// :type epoch: Epoch
+    /// :rtype: str
+    #[pyo3(name = "to_gregorian_str")]
+    fn py_to_gregorian_str(&self, epoch: Epoch) -> String {
+        self.to_gregorian_str(&epoch)
+    }
+
+    /// Parses an epoch in this time scale, either as a Gregorian date and time (e.g. `2024-01-01T12:34:56.789 MET`) or as seconds
+    /// past its reference epoch (e.g. `SEC 17.2 MET`), and returns it in the base time scale.
+    ///
+    /// :type s_in: str
+    /// :rtype: Epoch
+    #[pyo3(name = "parse")]
+    fn py_parse(&self, s_in: String) -> Result<Epoch, HifitimeError> {
+        self.parse(&s_in)
+    }
+
+    /// Registers a copy of this time scale and returns its `TimeScale` handle, which epochs can be converted into,
+    /// and such that `Epoch` parses the epochs suffixed by its name.
+    /// Raises an exception if a different time scale of the same name is registered.
+    ///
+    /// :rtype: TimeScale
+    #[pyo3(name = "register")]
+    fn py_register(&self) -> Result<TimeScale, HifitimeError> {
+        self.clone().register()
+    }
+
+    /// Returns the custom time scale of the provided `TimeScale` handle, even if it was unregistered,
+    /// or None for a built-in time scale.
+    ///
+    /// :type ts: TimeScale
+    /// :rtype: CustomTimeScale
+    #[staticmethod]
+    #[pyo3(name = "from_time_scale")]
+    fn py_from_time_scale(ts: TimeScale) -> Option<Self> {
+        Self::from_time_scale(ts).map(|custom| (*custom).clone())
+    }
+
+    /// Unregisters the time scale of the provided name, if any.
+    ///
+    /// :type name: str
+    #[staticmethod]
+    #[pyo3(name = "unregister")]
+    fn py_unregister(name: String) {
+        Self::unregister(&name);
+    }
+
+    /// Forgets the definitions of all the unregistered time scales, and returns how many were forgotten.
+    /// Their handles are reused by the next registrations, so the epochs in these time scales must no longer be used.
+    ///
+    /// :rtype: int
+    #[staticmethod]
+    #[pyo3(name = "purge_unregistered")]
+    fn py_purge_unregistered() -> usize {
+        Self::purge_unregistered()
+    }
+}
@@ -28,6 +28,18 @@ impl fmt::Display for TimeScale {
             Self::GST => write!(f, "GST"),
             Self::BDT => write!(f, "BDT"),
             Self::QZSST => write!(f, "QZSST"),
+            Self::GLONASST => write!(f, "GLONASST"),
+            Self::IRNSST => write!(f, "IRNSST"),
+            Self::TCG => write!(f, "TCG"),
+            Self::TCB => write!(f, "TCB"),
+            Self::UT1 => write!(f, "UT1"),
+            Self::Custom(_) => {
+                #[cfg(feature = "std")]
+                if let Some(custom) = super::CustomTimeScale::from_time_scale(*self) {
+                    return write!(f, "{}", custom.name());
+                }
+                write!(f, "CUSTOM")
+            }
         }
     }
 }
@@ -41,6 +53,8 @@ impl fmt::LowerHex for TimeScale {
             Self::GST => write!(f, "GAL"),
             Self::BDT => write!(f, "BDS"),
             Self::QZSST => write!(f, "QZSS"),
+            Self::GLONASST => write!(f, "GLO"),
+            Self::IRNSST => write!(f, "IRN"),
             _ => write!(f, "{self}"),
         }
     }
@@ -51,26 +65,50 @@ impl FromStr for TimeScale {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let val = s.trim();
+        if let Some(ts) = Self::from_builtin_str(val) {
+            return Ok(ts);
+        }
+        #[cfg(feature = "std")]
+        if let Some(ts) = super::CustomTimeScale::registered_time_scale(val) {
+            return Ok(ts);
+        }
+        Err(ParsingError::TimeSystem)
+    }
+}
+
+impl TimeScale {
+    /// Returns the built-in time scale of the provided name or abbreviation, if any, ignoring the custom time scales.
+    pub(crate) fn from_builtin_str(val: &str) -> Option<Self> {
         if val == "UTC" {
-            Ok(Self::UTC)
+            Some(Self::UTC)
         } else if val == "TT" {
-            Ok(Self::TT)
+            Some(Self::TT)
         } else if val == "TAI" {
-            Ok(Self::TAI)
+            Some(Self::TAI)
         } else if val == "TDB" {
-            Ok(Self::TDB)
+            Some(Self::TDB)
         } else if val == "ET" {
-            Ok(Self::ET)
+            Some(Self::ET)
         } else if val == "GPST" || val == "GPS" {
-            Ok(Self::GPST)
+            Some(Self::GPST)
         } else if val == "GST" || val == "GAL" {
-            Ok(Self::GST)
+            Some(Self::GST)
         } else if val == "BDT" || val == "BDS" {
-            Ok(Self::BDT)
+            Some(Self::BDT)
         } else if val == "QZSST" || val == "QZSS" {
-            Ok(Self::QZSST)
+            Some(Self::QZSST)
+        } else if val == "GLONASST" || val == "GLO" {
+            Some(Self::GLONASST)
+        } else if val == "IRNSST" || val == "IRN" {
+            Some(Self::IRNSST)
+        } else if val == "TCG" {
+            Some(Self::TCG)
+        } else if val == "TCB" {
+            Some(Self::TCB)
+        } else if val == "UT1" {
+            Some(Self::UT1)
         } else {
-            Err(ParsingError::TimeSystem)
+            None
         }
     }
 }
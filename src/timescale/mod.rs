@@ -8,9 +8,6 @@
 * Documentation: https://nyxspace.com/
 */
 
-#[cfg(feature = "python")]
-use pyo3::prelude::*;
-
 #[cfg(kani)]
 mod kani;
 
@@ -19,7 +16,17 @@ use serde_derive::{Deserialize, Serialize};
 
 mod fmt;
 
-use crate::{Duration, Epoch, Unit, SECONDS_PER_DAY};
+#[cfg(feature = "std")]
+mod custom;
+#[cfg(feature = "std")]
+pub use custom::CustomTimeScale;
+
+#[cfg(feature = "python")]
+mod python;
+#[cfg(feature = "python")]
+pub use python::PyTimeScale;
+
+use crate::{Duration, Epoch, HifitimeError, Unit, SECONDS_PER_DAY};
 
 /// The J1900 reference epoch (1900-01-01 at noon) TAI.
 pub const J1900_REF_EPOCH: Epoch = Epoch {
@@ -70,19 +77,57 @@ pub const BDT_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
 pub const SECONDS_BDT_TAI_OFFSET: f64 = 3_345_062_433.0;
 pub const SECONDS_BDT_TAI_OFFSET_I64: i64 = 3_345_062_433;
 
-/// The UNIX reference epoch of 1970-01-01 in TAI duration, accounting only for IERS leap seconds.
-pub const UNIX_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
+/// GLONASST (GLONASS) reference epoch is 1996 January 01 at midnight in GLONASST, i.e. 1995 December 31st at 21:00:00 UTC.
+/// **This time scale is UTC(SU) + 3 hours, so it follows the leap seconds.**
+/// |UTC - TAI| = 29 Leap Seconds on that day.
+pub const GLONASST_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
+    centuries: 0,
+    nanoseconds: 3_029_432_429_000_000_000,
+});
+/// Number of hours GLONASST is ahead of UTC(SU).
+pub const GLONASST_UTC_OFFSET_HOURS: i64 = 3;
+
+/// IRNSST (NavIC/IRNSS) reference epoch is 1999 August 22 at midnight GPST, i.e. 13 seconds before 1999 August 22 UTC at midnight, like Galileo.
+/// |UTC - TAI| = 32 Leap Seconds on that day.
+pub const IRNSST_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
+    centuries: 0,
+    nanoseconds: 3_144_268_819_000_000_000,
+});
+pub const SECONDS_IRNSST_TAI_OFFSET: f64 = 3_144_268_819.0;
+pub const SECONDS_IRNSST_TAI_OFFSET_I64: i64 = 3_144_268_819;
+
+/// Reference epoch of the IAU coordinate time scales (TCG and TCB): 1977 January 01 at midnight TAI.
+/// At this instant, TT, TCG and TCB all read 1977-01-01T00:00:32.184 (and TDB reads TCB + TDB0).
+pub const IAU_TIME_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
     centuries: 0,
-    nanoseconds: 2_208_988_800_000_000_000,
+    nanoseconds: 2_429_913_600_000_000_000,
 });
+/// Defining constant L_G = 1 - d(TT)/d(TCG) of the IAU 2000 Resolution B1.9
+pub const IAU_L_G: f64 = 6.969290134e-10;
+/// Defining constant L_B = 1 - d(TDB)/d(TCB) of the IAU 2006 Resolution B3
+pub const IAU_L_B: f64 = 1.550519768e-8;
+/// Defining constant TDB0 of the IAU 2006 Resolution B3, in seconds
+pub const IAU_TDB0_S: f64 = -6.55e-5;
+
+/// The UNIX reference epoch of 1970-01-01 at midnight UTC, i.e. 1970-01-01T00:00:08.000082 TAI as per the UTC offsets before 1972.
+pub const UNIX_REF_EPOCH: Epoch = Epoch {
+    duration: Duration {
+        centuries: 0,
+        nanoseconds: 2_208_988_800_000_000_000,
+    },
+    time_scale: TimeScale::UTC,
+};
 
 /// Reference year of the Hifitime prime epoch.
 pub(crate) const HIFITIME_REF_YEAR: i32 = 1900;
 
+/// Identifies a `CustomTimeScale` registered in this process, cf. `TimeScale::Custom` and `CustomTimeScale::register`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CustomTimeScaleId(pub(crate) u16);
+
 /// Enum of the different time systems available
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(feature = "python", pyclass(eq, eq_int))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TimeScale {
     /// TAI is the representation of an Epoch internally
@@ -103,6 +148,21 @@ pub enum TimeScale {
     BDT,
     /// QZSS Time scale has the same properties as GPST but with dedicated clocks
     QZSST,
+    /// GLONASS Time scale, defined as UTC(SU) + 3 hours: unlike other GNSS time scales, it includes leap seconds
+    GLONASST,
+    /// NavIC (IRNSS) Time scale, whose reference epoch is 1999 August 22 at midnight GPST (13 seconds before midnight UTC), like Galileo
+    IRNSST,
+    /// Geocentric Coordinate Time (TCG), related to TT by the IAU 2000 Resolution B1.9: TT = TCG - L_G × (TCG - T0)
+    TCG,
+    /// Barycentric Coordinate Time (TCB), related to TDB by the IAU 2006 Resolution B3: TDB = TCB - L_B × (TCB - T0) + TDB0
+    TCB,
+    /// Universal Time (UT1), the time scale of the rotation of the Earth: converting to or from UT1 requires Earth Orientation Parameters,
+    /// cf. `Ut1Provider` (requires the `ut1` feature). Without such data, `Epoch::to_time_scale` panics and `Epoch::try_to_time_scale` returns an error.
+    UT1,
+    /// A user-defined time scale, e.g. the clock of a spacecraft, as returned by `CustomTimeScale::register` (requires the `std` feature).
+    /// Its epochs are formatted, parsed and converted like those of the built-in time scales, but cannot be serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(CustomTimeScaleId),
 }
 
 impl Default for TimeScale {
@@ -113,18 +173,12 @@ impl Default for TimeScale {
 }
 
 impl TimeScale {
-    pub(crate) const fn formatted_len(&self) -> usize {
-        match &self {
-            Self::QZSST => 5,
-            Self::GPST => 4,
-            Self::TAI | Self::TDB | Self::UTC | Self::GST | Self::BDT => 3,
-            Self::ET | Self::TT => 2,
-        }
-    }
-
     /// Returns true if Self is based off a GNSS constellation
     pub const fn is_gnss(&self) -> bool {
-        matches!(self, Self::GPST | Self::GST | Self::BDT | Self::QZSST)
+        matches!(
+            self,
+            Self::GPST | Self::GST | Self::BDT | Self::QZSST | Self::GLONASST | Self::IRNSST
+        )
     }
 
     /// Returns this time scale's reference epoch: Time Scale initialization date,
@@ -140,8 +194,8 @@ impl TimeScale {
     /// This is used to compute the Gregorian date representations in any time scale.
     pub(crate) const fn prime_epoch_offset(self) -> Duration {
         match self {
-            TimeScale::ET | TimeScale::TDB => {
-                // Both ET and TDB are defined at J2000, which is 2000-01-01 12:00:00 and there were only 36524 days in the 20th century.
+            TimeScale::ET | TimeScale::TDB | TimeScale::TCB => {
+                // ET, TDB and TCB are all counted from J2000, which is 2000-01-01 12:00:00 and there were only 36524 days in the 20th century.
                 // Hence, this math is the output of (Unit.Century*1 + Unit.Hour*12 - Unit.Day*1).to_parts() via Hifitime in Python.
                 Duration {
                     centuries: 0,
@@ -152,7 +206,7 @@ impl TimeScale {
                 centuries: 0,
                 nanoseconds: 2_524_953_619_000_000_000,
             },
-            TimeScale::GST => Duration {
+            TimeScale::GST | TimeScale::IRNSST => Duration {
                 centuries: 0,
                 nanoseconds: 3_144_268_819_000_000_000,
             },
@@ -160,31 +214,46 @@ impl TimeScale {
                 centuries: 1,
                 nanoseconds: 189_302_433_000_000_000,
             },
+            TimeScale::GLONASST => {
+                // GLONASST follows the leap seconds, so this is the number of days between 1900-01-01 and 1996-01-01 (GLONASST),
+                // and _not_ the TAI duration of its reference epoch.
+                Duration {
+                    centuries: 0,
+                    nanoseconds: 3_029_443_200_000_000_000,
+                }
+            }
             _ => Duration::ZERO,
         }
     }
 
     pub(crate) fn gregorian_epoch_offset(self) -> Duration {
+        #[cfg(feature = "std")]
+        if let Some(custom) = CustomTimeScale::from_time_scale(self) {
+            // The calendar of a custom time scale is that of its base time scale, and its durations are counted past its reference epoch.
+            return custom.reference_epoch().duration + custom.base().gregorian_epoch_offset();
+        }
+
         let prime_offset = self.prime_epoch_offset();
 
         prime_offset - prime_offset.subdivision(Unit::Second).unwrap()
     }
 }
 
-#[cfg_attr(feature = "python", pymethods)]
 impl TimeScale {
     /// Returns true if self takes leap seconds into account
-    /// :rtype: bool
     pub const fn uses_leap_seconds(&self) -> bool {
-        matches!(self, Self::UTC)
+        matches!(self, Self::UTC | Self::GLONASST)
     }
 }
 
-/// Allows conversion of a TimeSystem into a u8
-/// Mapping: TAI: 0; TT: 1; ET: 2; TDB: 3; UTC: 4; GPST: 5; GST: 6; BDT: 7; QZSST: 8;
-impl From<TimeScale> for u8 {
-    fn from(ts: TimeScale) -> Self {
-        match ts {
+/// Allows conversion of a built-in TimeSystem into a u8
+/// Mapping: TAI: 0; TT: 1; ET: 2; TDB: 3; UTC: 4; GPST: 5; GST: 6; BDT: 7; QZSST: 8; GLONASST: 9; IRNSST: 10; TCG: 11; TCB: 12; UT1: 13;
+/// a custom time scale cannot be converted (an `InvalidCustomTimeScale` error), since a u8 cannot hold its handle.
+impl TryFrom<TimeScale> for u8 {
+    type Error = HifitimeError;
+
+    fn try_from(ts: TimeScale) -> Result<Self, Self::Error> {
+        Ok(match ts {
             TimeScale::TAI => 0,
             TimeScale::TT => 1,
             TimeScale::ET => 2,
@@ -194,12 +263,22 @@ impl From<TimeScale> for u8 {
             TimeScale::GST => 6,
             TimeScale::BDT => 7,
             TimeScale::QZSST => 8,
-        }
+            TimeScale::GLONASST => 9,
+            TimeScale::IRNSST => 10,
+            TimeScale::TCG => 11,
+            TimeScale::TCB => 12,
+            TimeScale::UT1 => 13,
+            TimeScale::Custom(_) => {
+                return Err(HifitimeError::InvalidCustomTimeScale {
+                    details: "a custom time scale has no u8 representation",
+                })
+            }
+        })
     }
 }
 
-/// Allows conversion of a u8 into a TimeSystem.
-/// Mapping: 1: TT; 2: ET; 3: TDB; 4: UTC; 5: GPST; 6: GST; 7: BDT; 8: QZSST; anything else: TAI
+/// Allows conversion of a u8 into a built-in TimeSystem.
+/// Mapping: 1: TT; 2: ET; 3: TDB; 4: UTC; 5: GPST; 6: GST; 7: BDT; 8: QZSST; 9: GLONASST; 10: IRNSST; 11: TCG; 12: TCB; 13: UT1; anything else: TAI
 impl From<u8> for TimeScale {
     fn from(val: u8) -> Self {
         match val {
@@ -211,6 +290,11 @@ impl From<u8> for TimeScale {
             6 => Self::GST,
             7 => Self::BDT,
             8 => Self::QZSST,
+            9 => Self::GLONASST,
+            10 => Self::IRNSST,
+            11 => Self::TCG,
+            12 => Self::TCB,
+            13 => Self::UT1,
             _ => Self::TAI,
         }
     }
@@ -234,14 +318,16 @@ mod unit_test_timescale {
     fn test_ts() {
         for ts_u8 in 0..u8::MAX {
             let ts = TimeScale::from(ts_u8);
-            let ts_u8_back: u8 = ts.into();
+            let ts_u8_back = u8::try_from(ts).unwrap();
             // If the u8 is greater than 5, it isn't valid and necessarily encoded as TAI.
-            if ts_u8 < 9 {
+            if ts_u8 < 14 {
                 assert_eq!(ts_u8_back, ts_u8, "got {ts_u8_back} want {ts_u8}");
             } else {
                 assert_eq!(ts, TimeScale::TAI);
             }
         }
+        // A custom time scale is not silently encoded as a built-in one.
+        assert!(u8::try_from(TimeScale::Custom(super::CustomTimeScaleId(0))).is_err());
     }
 
     #[test]
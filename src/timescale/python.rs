@@ -0,0 +1,107 @@
+/*
+* Hifitime
+* Copyright (C) 2017-onward Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+* This Source Code Form is subject to the terms of the Mozilla Public
+* License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at https://mozilla.org/MPL/2.0/.
+*
+* Documentation: https://nyxspace.com/
+*/
+
+// A Python enumeration cannot hold the custom time scales, so the `TimeScale` class wraps a `TimeScale`,
+// and exposes the built-in time scales as class attributes: `TimeScale.TAI` works as with an enumeration,
+// and the handles returned by `CustomTimeScale.register` round trip through Python.
+
+use core::str::FromStr;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use super::TimeScale;
+use crate::HifitimeError;
+
+/// Enum of the different time systems available, including the custom time scales registered with `CustomTimeScale.register`
+///
+/// :type name: str
+/// :rtype: TimeScale
+#[pyclass(name = "TimeScale", frozen, eq, ord, hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PyTimeScale(TimeScale);
+
+#[pymethods]
+impl PyTimeScale {
+    #[classattr]
+    const TAI: Self = Self(TimeScale::TAI);
+    #[classattr]
+    const TT: Self = Self(TimeScale::TT);
+    #[classattr]
+    const ET: Self = Self(TimeScale::ET);
+    #[classattr]
+    const TDB: Self = Self(TimeScale::TDB);
+    #[classattr]
+    const UTC: Self = Self(TimeScale::UTC);
+    #[classattr]
+    const GPST: Self = Self(TimeScale::GPST);
+    #[classattr]
+    const GST: Self = Self(TimeScale::GST);
+    #[classattr]
+    const BDT: Self = Self(TimeScale::BDT);
+    #[classattr]
+    const QZSST: Self = Self(TimeScale::QZSST);
+    #[classattr]
+    const GLONASST: Self = Self(TimeScale::GLONASST);
+    #[classattr]
+    const IRNSST: Self = Self(TimeScale::IRNSST);
+    #[classattr]
+    const TCG: Self = Self(TimeScale::TCG);
+    #[classattr]
+    const TCB: Self = Self(TimeScale::TCB);
+    #[classattr]
+    const UT1: Self = Self(TimeScale::UT1);
+
+    /// Returns the time scale of the provided name, either built-in (e.g. `GPST` or `GPS`) or registered with `CustomTimeScale.register`.
+    #[new]
+    fn new(name: &str) -> PyResult<Self> {
+        TimeScale::from_str(name)
+            .map(Self)
+            .map_err(|_| PyValueError::new_err(format!("unknown time scale {name}")))
+    }
+
+    /// Returns true if self takes leap seconds into account
+    /// :rtype: bool
+    fn uses_leap_seconds(&self) -> bool {
+        self.0.uses_leap_seconds()
+    }
+
+    fn __repr__(&self) -> String {
+        match self.0 {
+            TimeScale::Custom(_) => format!("TimeScale(\"{}\")", self.0),
+            ts => format!("TimeScale.{ts}"),
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the integer of a built-in time scale, as with the former enumeration (e.g. 0 for TAI), or raises an exception for a custom time scale.
+    fn __int__(&self) -> Result<u8, HifitimeError> {
+        u8::try_from(self.0)
+    }
+}
+
+impl<'py> FromPyObject<'py> for TimeScale {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        Ok(ob.extract::<PyTimeScale>()?.0)
+    }
+}
+
+impl<'py> IntoPyObject<'py> for TimeScale {
+    type Target = PyTimeScale;
+    type Output = Bound<'py, PyTimeScale>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        Bound::new(py, PyTimeScale(self))
+    }
+}
@@ -8,7 +8,7 @@
 * Documentation: https://nyxspace.com/
 */
 
-use super::{Duration, Epoch};
+use super::{leap_seconds, Duration, Epoch};
 
 use core::fmt;
 
@@ -27,6 +27,9 @@ NOTE: This is taken from itertools: https://docs.rs/itertools-num/0.1.3/src/iter
 
 /// An iterator of a sequence of evenly spaced Epochs.
 ///
+/// The epochs are spaced in the time scale of the start epoch. In UTC, the dates which never existed because of a negative leap second
+/// are skipped, and are not counted in its length.
+///
 /// (Python documentation hints)
 /// :type start: Epoch
 /// :type end: Epoch
@@ -264,14 +267,19 @@ impl Iterator for TimeSeries {
 
     #[inline]
     fn next(&mut self) -> Option<Epoch> {
-        let next_offset = self.cur * self.step;
-        if (!self.incl && next_offset >= self.duration)
-            || (self.incl && next_offset > self.duration)
-        {
-            None
-        } else {
+        loop {
+            let next_offset = self.cur * self.step;
+            if (!self.incl && next_offset >= self.duration)
+                || (self.incl && next_offset > self.duration)
+            {
+                return None;
+            }
             self.cur += 1;
-            Some(self.start + next_offset)
+            let epoch = self.start + next_offset;
+            // Skip the UTC dates which never existed because of a negative leap second.
+            if !epoch.is_skipped_by_leap_second() {
+                return Some(epoch);
+            }
         }
     }
 
@@ -283,27 +291,71 @@ impl Iterator for TimeSeries {
 impl DoubleEndedIterator for TimeSeries {
     #[inline]
     fn next_back(&mut self) -> Option<Epoch> {
-        // Offset from the end of the iterator
-        self.cur += 1;
-        let offset = self.cur * self.step;
-        // if offset < -self.duration - self.step {
-        if (!self.incl && offset > self.duration)
-            || (self.incl && offset > self.duration + self.step)
-        {
-            None
-        } else {
-            Some(self.start + self.duration - offset)
+        loop {
+            // Offset from the end of the iterator
+            self.cur += 1;
+            let offset = self.cur * self.step;
+            // if offset < -self.duration - self.step {
+            if (!self.incl && offset > self.duration)
+                || (self.incl && offset > self.duration + self.step)
+            {
+                return None;
+            }
+            let epoch = self.start + self.duration - offset;
+            // Skip the UTC dates which never existed because of a negative leap second.
+            if !epoch.is_skipped_by_leap_second() {
+                return Some(epoch);
+            }
+        }
+    }
+}
+
+impl TimeSeries {
+    /// Returns the number of epochs of this time series which are skipped because they never existed in UTC,
+    /// cf. `Epoch::is_skipped_by_leap_second`.
+    fn skipped_len(&self) -> usize {
+        let (Some(utc_start), step) = (
+            self.start.skippable_utc_duration(),
+            self.step.total_nanoseconds(),
+        ) else {
+            return 0;
+        };
+        if step <= 0 {
+            return 0;
         }
+        // Index of the last epoch of the series.
+        let span = self.duration.total_nanoseconds();
+        let last = if self.incl {
+            span.div_euclid(step)
+        } else {
+            ceil_div(span, step) - 1
+        };
+
+        let mut skipped = 0;
+        leap_seconds::for_each_default_skipped_interval(|skipped_start, skipped_end| {
+            // The indexes of the epochs within that interval, i.e. ceil((start - utc_start) / step) up to the end excluded.
+            let first = ceil_div((skipped_start - utc_start).total_nanoseconds(), step).max(0);
+            let end = ceil_div((skipped_end - utc_start).total_nanoseconds(), step).min(last + 1);
+            if end > first {
+                skipped += (end - first) as usize;
+            }
+        });
+        skipped
     }
 }
 
+/// Returns the ceiling of `num / den` for a positive `den`.
+fn ceil_div(num: i128, den: i128) -> i128 {
+    -(-num).div_euclid(den)
+}
+
 impl ExactSizeIterator for TimeSeries
 where
     TimeSeries: Iterator,
 {
     fn len(&self) -> usize {
         let approx = (self.duration.to_seconds() / self.step.to_seconds()).abs();
-        if self.incl {
+        let len = if self.incl {
             if approx.ceil() >= usize::MAX as f64 {
                 usize::MAX
             } else {
@@ -313,7 +365,9 @@ where
             usize::MAX
         } else {
             approx.floor() as usize
-        }
+        };
+        // The epochs which never existed in UTC are not yielded.
+        len.saturating_sub(self.skipped_len())
     }
 }
 
@@ -3,12 +3,14 @@ extern crate core;
 
 use hifitime::{
     is_gregorian_valid, Duration, Epoch, HifitimeError, ParsingError, TimeScale, TimeUnits, Unit,
-    Weekday, BDT_REF_EPOCH, DAYS_GPS_TAI_OFFSET, DAYS_PER_YEAR, GPST_REF_EPOCH, GST_REF_EPOCH,
-    J1900_REF_EPOCH, J2000_REF_EPOCH, JD_J2000, MJD_J1900, MJD_J2000, MJD_OFFSET,
-    SECONDS_BDT_TAI_OFFSET, SECONDS_GPS_TAI_OFFSET, SECONDS_GST_TAI_OFFSET, SECONDS_PER_DAY,
+    Weekday, BDT_REF_EPOCH, DAYS_GPS_TAI_OFFSET, DAYS_PER_YEAR, GLONASST_REF_EPOCH, GPST_REF_EPOCH,
+    GST_REF_EPOCH, IRNSST_REF_EPOCH, J1900_REF_EPOCH, J2000_REF_EPOCH, JD_J2000, MJD_J1900,
+    MJD_J2000, MJD_OFFSET, NANOSECONDS_PER_DAY, SECONDS_BDT_TAI_OFFSET, SECONDS_GPS_TAI_OFFSET,
+    SECONDS_GST_TAI_OFFSET, SECONDS_IRNSST_TAI_OFFSET, SECONDS_PER_DAY,
 };
 
 use hifitime::efmt::{Format, Formatter};
+use hifitime::leap_seconds::{IersOnly, LatestLeapSeconds};
 
 #[test]
 fn test_basic_ops() {
@@ -563,6 +565,289 @@ fn beidou_time_scale() {
     );
 }
 
+#[test]
+fn glonass_time_scale() {
+    use core::str::FromStr;
+
+    // GLONASST is UTC(SU) + 3 hours, hence it includes leap seconds.
+    let glo_epoch = Epoch::from_glonasst_seconds(0.0);
+    assert_eq!(glo_epoch, GLONASST_REF_EPOCH);
+    assert_eq!(glo_epoch, Epoch::from_glonasst_days(0.0));
+    assert_eq!(glo_epoch, Epoch::from_glonasst_nanoseconds(0));
+    assert_eq!(glo_epoch, Epoch::from_glonasst_n4_nt(1, 1, 0).unwrap());
+    assert_eq!(format!("{glo_epoch}"), "1996-01-01T00:00:00 GLONASST");
+    assert_eq!(format!("{glo_epoch:?}"), "1995-12-31T21:00:00 UTC");
+    assert_eq!(format!("{glo_epoch:x}"), "1995-12-31T21:00:29 TAI");
+    assert_eq!(
+        glo_epoch,
+        Epoch::from_gregorian_utc_hms(1995, 12, 31, 21, 0, 0)
+    );
+    assert!(glo_epoch.to_glonasst_seconds().abs() < f64::EPSILON);
+
+    // The offset with UTC is constant across leap seconds.
+    for (y, m, d) in [(1996, 1, 1), (2016, 12, 31), (2017, 1, 1), (2024, 6, 30)] {
+        let utc = Epoch::from_gregorian_utc_at_noon(y, m, d);
+        let glo = Epoch::from_gregorian_at_noon(y, m, d, TimeScale::GLONASST);
+        assert_eq!(utc - glo, 3 * Unit::Hour, "{y}-{m}-{d}");
+        assert_eq!(
+            format!("{}", utc.to_time_scale(TimeScale::GLONASST)),
+            format!("{y:04}-{m:02}-{d:02}T15:00:00 GLONASST")
+        );
+    }
+
+    // Round trip through the N4 / NT representation
+    let now = Epoch::from_gregorian_utc_hms(2024, 3, 1, 12, 34, 56);
+    let (n4, nt, nanos) = now.to_glonasst_n4_nt().unwrap();
+    assert_eq!(n4, 8);
+    // 2024-03-01 is day 31 + 29 + 1 of the leap year starting the interval
+    assert_eq!(nt, 61);
+    assert_eq!(nanos, (15 * 3600 + 34 * 60 + 56) * 1_000_000_000);
+    assert_eq!(Epoch::from_glonasst_n4_nt(n4, nt, nanos).unwrap(), now);
+    // N4 and NT start at 1, NT is at most 1461 and the nanoseconds are within a day
+    for (n4, nt, nanos) in [
+        (0, 1, 0),
+        (1, 0, 0),
+        (1, 1462, 0),
+        (1, 1, NANOSECONDS_PER_DAY),
+    ] {
+        assert_eq!(
+            Epoch::from_glonasst_n4_nt(n4, nt, nanos),
+            Err(HifitimeError::InvalidGregorianDate)
+        );
+    }
+    assert_eq!(
+        Epoch::from_glonasst_n4_nt(1, 1461, 0).unwrap(),
+        Epoch::from_glonasst_n4_nt(2, 1, 0).unwrap() - Unit::Day
+    );
+    // N4 must fit within 1 to 255
+    assert!(Epoch::from_gregorian_utc_at_midnight(1995, 6, 1)
+        .to_glonasst_n4_nt()
+        .is_err());
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(3015, 6, 1)
+            .to_glonasst_n4_nt()
+            .unwrap()
+            .0,
+        255
+    );
+    assert!(Epoch::from_gregorian_utc_at_midnight(3016, 6, 1)
+        .to_glonasst_n4_nt()
+        .is_err());
+
+    let nanos = now.to_glonasst_nanoseconds().unwrap();
+    assert_eq!(Epoch::from_glonasst_nanoseconds(nanos), now);
+
+    // Parsing
+    assert_eq!(
+        Epoch::from_str("2024-03-01T15:34:56 GLONASST").unwrap(),
+        now
+    );
+    assert_eq!(
+        Epoch::from_str(&format!("SEC {} GLO", now.to_glonasst_seconds())).unwrap(),
+        now
+    );
+    assert_eq!(
+        Epoch::from_str(&format!("SEC {} GLONASST", now.to_glonasst_seconds())).unwrap(),
+        now
+    );
+    // Round trip of the full name written by Display
+    let sec = Epoch::from_str("SEC 1 GLONASST").unwrap();
+    assert_eq!(sec, Epoch::from_glonasst_seconds(1.0));
+    assert_eq!(Epoch::from_str(&format!("{sec}")).unwrap(), sec);
+}
+
+#[test]
+fn navic_time_scale() {
+    use core::str::FromStr;
+
+    let now = Epoch::from_gregorian_tai_hms(2019, 8, 24, 3, 49, 9);
+    let nanos = now.to_irnsst_nanoseconds().unwrap();
+    assert_eq!(Epoch::from_irnsst_nanoseconds(nanos), now);
+    assert!(
+        (now.to_tai_seconds() - SECONDS_IRNSST_TAI_OFFSET - now.to_irnsst_seconds()).abs()
+            < f64::EPSILON
+    );
+
+    let irn_epoch = Epoch::from_tai_seconds(SECONDS_IRNSST_TAI_OFFSET);
+    assert_eq!(irn_epoch, Epoch::from_irnsst_days(0.0));
+    assert_eq!(irn_epoch, Epoch::from_irnsst_seconds(0.0));
+    assert_eq!(irn_epoch, Epoch::from_irnsst_nanoseconds(0));
+    assert_eq!(irn_epoch, IRNSST_REF_EPOCH);
+    assert_eq!(format!("{IRNSST_REF_EPOCH:?}"), "1999-08-21T23:59:47 UTC");
+    assert_eq!(format!("{:x}", IRNSST_REF_EPOCH), "1999-08-22T00:00:19 TAI");
+
+    // NavIC time is offset from GPS time by the same amount as Galileo
+    assert_eq!(
+        now.to_irnsst_seconds() - now.to_gpst_seconds(),
+        now.to_gst_seconds() - now.to_gpst_seconds()
+    );
+
+    // Parsing
+    assert_eq!(
+        Epoch::from_str(&format!("SEC {} IRN", now.to_irnsst_seconds())).unwrap(),
+        now
+    );
+    assert_eq!(Epoch::from_str("2019-08-24T03:48:50 IRNSST").unwrap(), now);
+    assert_eq!(
+        Epoch::from_str(&format!("SEC {} IRNSST", now.to_irnsst_seconds())).unwrap(),
+        now
+    );
+    // Round trip of the full name written by Display
+    let sec = Epoch::from_str("SEC 1 IRNSST").unwrap();
+    assert_eq!(sec, Epoch::from_irnsst_seconds(1.0));
+    assert_eq!(Epoch::from_str(&format!("{sec}")).unwrap(), sec);
+    let mjd = Epoch::from_str("MJD 60000 IRNSST").unwrap();
+    assert_eq!(
+        mjd,
+        Epoch::from_mjd_in_time_scale(60_000.0, TimeScale::IRNSST)
+    );
+    assert_eq!(Epoch::from_str("MJD 60000 IRN").unwrap(), mjd);
+}
+
+#[test]
+fn coordinate_time_scales_sofa() {
+    use core::str::FromStr;
+
+    // Regression tests against the SOFA test suite (t_sofa_c.c), with the same tolerance of 1e-12 days.
+    let tol = 1e-12 * Unit::Day;
+    let jd_j1900 = Unit::Day * (MJD_J1900 + MJD_OFFSET);
+    let jd_j2000 = Unit::Day * JD_J2000;
+    let jd = |days: i64, frac: f64| Unit::Day * days + frac * Unit::Day;
+
+    // iauTttcg
+    let tt = Epoch::from_tt_duration(Unit::Day * 0.5 + jd(2453750, 0.892482639) - jd_j1900);
+    let tcg = tt.to_time_scale(TimeScale::TCG);
+    assert_eq!(tcg.time_scale, TimeScale::TCG);
+    let err = (tcg.to_jde_tcg_duration() - Unit::Day * 0.5 - jd(2453750, 0.8924900312508587)).abs();
+    assert!(err < tol, "iauTttcg error: {err}");
+
+    // iauTcgtt
+    let tcg = Epoch::from_tcg_duration(Unit::Day * 0.5 + jd(2453750, 0.892862531) - jd_j1900);
+    let err = (tcg.to_jde_tt_duration() - Unit::Day * 0.5 - jd(2453750, 0.8928551387488817)).abs();
+    assert!(err < tol, "iauTcgtt error: {err}");
+
+    // iauTdbtcb
+    let tdb = Epoch::from_tdb_duration(Unit::Day * 0.5 + jd(2453750, 0.892855137) - jd_j2000);
+    let err = (tdb.to_jde_tcb_duration() - Unit::Day * 0.5 - jd(2453750, 0.8930195997253657)).abs();
+    assert!(err < tol, "iauTdbtcb error: {err}");
+
+    // iauTcbtdb
+    let tcb = Epoch::from_tcb_duration(Unit::Day * 0.5 + jd(2453750, 0.893019599) - jd_j2000);
+    let err = (tcb.to_jde_tdb_duration() - Unit::Day * 0.5 - jd(2453750, 0.8928551362746343)).abs();
+    assert!(err < tol, "iauTcbtdb error: {err}");
+
+    // At the IAU reference epoch, TT = TCG = TCB = TDB - TDB0.
+    let t0 = Epoch::from_gregorian_tai_at_midnight(1977, 1, 1);
+    assert_eq!(
+        format!("{}", t0.to_time_scale(TimeScale::TCG)),
+        "1977-01-01T00:00:32.184000000 TCG"
+    );
+    assert_eq!(t0.to_tcg_duration(), t0.to_tt_duration());
+    assert!(
+        (t0.to_tcb_duration() - t0.to_tdb_duration() - 65.5 * Unit::Microsecond).abs()
+            < 10 * Unit::Nanosecond
+    );
+
+    // Round trips
+    let now = Epoch::from_gregorian_utc_hms(2024, 6, 1, 12, 0, 0);
+    assert_eq!(
+        now.to_time_scale(TimeScale::TCG)
+            .to_time_scale(TimeScale::UTC),
+        now
+    );
+    assert!((now.to_time_scale(TimeScale::TCB) - now).abs() < 10 * Unit::Nanosecond);
+    // TCB has drifted from TDB by about 23 seconds by 2024.
+    let tcb_tdb = now.to_tcb_duration() - now.to_tdb_duration();
+    assert!(
+        tcb_tdb > 23 * Unit::Second && tcb_tdb < 24 * Unit::Second,
+        "{tcb_tdb}"
+    );
+
+    // Parsing
+    assert_eq!(
+        Epoch::from_str("2024-06-01T12:01:10.184000000 TCG")
+            .unwrap()
+            .time_scale,
+        TimeScale::TCG
+    );
+}
+
+#[test]
+fn ut1_without_data() {
+    use core::str::FromStr;
+
+    let epoch = Epoch::from_gregorian_utc_hms(2022, 1, 3, 3, 5, 6);
+    // Without any UT1 data, the conversion fails...
+    assert!(matches!(
+        epoch.try_to_time_scale(TimeScale::UT1),
+        Err(HifitimeError::MissingUt1Data { .. })
+    ));
+    // ... and so does the conversion of a parsed UT1 epoch, which cannot be formatted in UTC either.
+    let ut1_epoch = Epoch::from_str("2022-01-03T03:05:06 UT1").unwrap();
+    assert_eq!(format!("{ut1_epoch}"), "2022-01-03T03:05:06 UT1");
+    assert_eq!(format!("{ut1_epoch:?}"), "2022-01-03T03:05:06 UT1");
+    assert!(matches!(
+        ut1_epoch.try_to_time_scale(TimeScale::UTC),
+        Err(HifitimeError::MissingUt1Data { .. })
+    ));
+    assert!(!ut1_epoch.has_ut1_data());
+    // Conversions that do not involve UT1 never fail.
+    assert_eq!(
+        epoch.try_to_time_scale(TimeScale::TT).unwrap().duration,
+        epoch.to_tt_duration()
+    );
+    assert_eq!(
+        Epoch::from_duration(Unit::Day * 1, TimeScale::UT1).to_string(),
+        "1900-01-02T00:00:00 UT1"
+    );
+}
+
+#[test]
+#[should_panic(expected = "no UT1 data available")]
+fn ut1_without_data_panics() {
+    // UT1 is never approximated by UTC.
+    let _ = Epoch::from_gregorian_utc_hms(2022, 1, 3, 3, 5, 6).to_time_scale(TimeScale::UT1);
+}
+
+#[test]
+#[should_panic(expected = "no UT1 data available")]
+fn ut1_round_trip_without_data_panics() {
+    use core::str::FromStr;
+
+    let ut1_epoch = Epoch::from_str("2022-01-03T03:05:06 UT1").unwrap();
+    let _ = ut1_epoch.to_time_scale(TimeScale::UTC);
+}
+
+#[test]
+fn ut1_comparisons_without_data() {
+    use core::str::FromStr;
+
+    // Comparing and subtracting UT1 epochs never panics: without UT1 data, UT1 is compared as if it were UTC.
+    let ut1_epoch = Epoch::from_str("2022-01-03T03:05:06 UT1").unwrap();
+    let utc_epoch = Epoch::from_gregorian_utc_hms(2022, 1, 3, 3, 5, 6);
+    let tai_epoch = utc_epoch.to_time_scale(TimeScale::TAI);
+    assert_ne!(ut1_epoch, utc_epoch + 1 * Unit::Second);
+    assert!(ut1_epoch < utc_epoch + 1 * Unit::Second);
+    assert!(ut1_epoch > tai_epoch - 1 * Unit::Second);
+    assert!(utc_epoch - 1 * Unit::Second < ut1_epoch);
+    assert_eq!(ut1_epoch, utc_epoch);
+    assert_eq!(tai_epoch, ut1_epoch);
+    assert_eq!((utc_epoch + 1 * Unit::Second) - ut1_epoch, 1 * Unit::Second);
+    assert_eq!(ut1_epoch - (tai_epoch - 2 * Unit::Second), 2 * Unit::Second);
+    assert_eq!(
+        ut1_epoch.max(utc_epoch + 1 * Unit::Second),
+        utc_epoch + 1 * Unit::Second
+    );
+    // Between UT1 epochs, nothing is converted.
+    assert_eq!((ut1_epoch + 1 * Unit::Second) - ut1_epoch, 1 * Unit::Second);
+}
+
+#[test]
+#[should_panic(expected = "no UT1 data available")]
+fn sidereal_time_without_data_panics() {
+    let _ = Epoch::from_gregorian_utc_hms(2022, 1, 3, 3, 5, 6).gmst_rad();
+}
+
 #[test]
 fn unix() {
     // Continuous check that the system time as reported by this machine is within millisecond accuracy of what we compute
@@ -606,8 +891,17 @@ fn unix() {
         format!("{}", unix_epoch.to_time_scale(TimeScale::UTC)),
         "1970-01-01T00:00:00 UTC"
     );
+    // UTC follows the drifting offsets of SOFA before 1972: TAI - UTC = 4.2131700 + (40587 - 39126) * 0.002592 = 8.000082 s on 1970-01-01.
     assert_eq!(
         format!("{:x}", unix_epoch.to_time_scale(TimeScale::TAI)),
+        "1970-01-01T00:00:08.000082000 TAI"
+    );
+    // Accounting only for the IERS leap seconds, there are none yet, so UTC and TAI coincide (the previous default).
+    assert_eq!(
+        format!(
+            "{:x}",
+            unix_epoch.to_time_scale_with(TimeScale::TAI, &IersOnly(LatestLeapSeconds))
+        ),
         "1970-01-01T00:00:00 TAI"
     );
     // Print as UNIX seconds
@@ -643,7 +937,7 @@ fn naif_spice_et_tdb_verification() {
     // The maximum error due to small perturbations accounted for in ESA algorithm but not SPICE algorithm.
     let max_tdb_et_err = 32 * Unit::Microsecond;
     // Prior to 01 JAN 1972, IERS claims that there is no leap second at all but SPICE claims that there are nine (9) leap seconds
-    // between TAI and UTC. Hifitime also claims that there are zero leap seconds (to ensure correct computation of UNIX time at its reference time).
+    // between TAI and UTC. Hifitime follows SOFA by default, so the ET and TDB are compared from TAI accounting only for the IERS leap seconds.
     let spice_utc_tai_ls_err = 9.0;
     // SPICE will only output up to 6 digits for the JDE computation. This is likely due to the precision limitation of the `double`s type.
     // This means that a SPICE JDE is precise to 0.008 seconds, whereas a JDE in Hifitime maintains its nanosecond precision.
@@ -672,20 +966,21 @@ fn naif_spice_et_tdb_verification() {
         );
 
         // Test ET computation
+        let tai = epoch.to_time_scale_with(TimeScale::TAI, &IersOnly(LatestLeapSeconds));
         let extra_seconds = if epoch.leap_seconds_iers() == 0 {
             spice_utc_tai_ls_err
         } else {
             0.0
         };
         assert!(
-            (epoch.to_et_seconds() - et_s + extra_seconds).abs() < f64::EPSILON,
+            (tai.to_et_seconds() - et_s + extra_seconds).abs() < f64::EPSILON,
             "{} failed ET test",
             epoch
         );
 
         // Test TDB computation
         assert!(
-            (epoch.to_tdb_duration() - et_s * Unit::Second + extra_seconds * Unit::Second).abs()
+            (tai.to_tdb_duration() - et_s * Unit::Second + extra_seconds * Unit::Second).abs()
                 <= max_tdb_et_err,
             "{} failed TDB test",
             epoch
@@ -985,7 +1280,7 @@ fn test_format() {
     assert_eq!(format!("{epoch:o}"), "1346541887000000000"); // GPS nanoseconds
 
     // Ensure that the appropriate time system is used in the debug print.
-    for ts_u8 in 0..=7 {
+    for ts_u8 in 0..=13 {
         let ts: TimeScale = ts_u8.into();
 
         let recent = Epoch::from_gregorian(2020, 9, 6, 23, 24, 29, 2, ts);
@@ -1126,10 +1421,85 @@ fn test_leap_seconds_iers() {
         epoch_from_utc_greg.duration_in_year(),
         (31 + 29 + 31 + 30 + 31 + 30) * Unit::Day - Unit::Second
     );
-    // Just after it.
-    let epoch_from_utc_greg1 = Epoch::from_gregorian_tai_hms(1972, 7, 1, 0, 0, 0);
     assert_eq!(epoch_from_utc_greg.leap_seconds_iers(), 10);
+    // Midnight TAI is 1972-06-30T23:59:50 UTC, i.e. still before the second leap second, which takes effect at 1972-07-01T00:00:00 UTC.
+    // This used to be 11 because the UTC timestamp of the leap second was compared with the TAI duration (cf. the changelog).
+    let epoch_from_utc_greg1 = Epoch::from_gregorian_tai_hms(1972, 7, 1, 0, 0, 0);
+    assert_eq!(epoch_from_utc_greg1.leap_seconds_iers(), 10);
+    // Just after it, from the inserted second 1972-06-30T23:59:60 UTC.
+    let epoch_from_utc_greg1 = Epoch::from_gregorian_tai_hms(1972, 7, 1, 0, 0, 10);
     assert_eq!(epoch_from_utc_greg1.leap_seconds_iers(), 11);
+    assert_eq!(
+        (epoch_from_utc_greg1 - Unit::Nanosecond).leap_seconds_iers(),
+        10
+    );
+}
+
+#[test]
+fn test_leap_seconds_pre_1972_drift() {
+    // Values of iauDat from SOFA (src/dat.c), i.e. ΔAT = a + (MJD - MJD0) * b, for dates away from the offset changes.
+    let sofa_dat = [
+        (Epoch::from_gregorian_utc_at_midnight(1960, 1, 1), 0.943482),
+        (
+            Epoch::from_gregorian_utc_hms(1961, 9, 20, 6, 0, 0),
+            1.712694,
+        ),
+        (Epoch::from_gregorian_utc_at_noon(1962, 6, 15), 2.0317476),
+        (Epoch::from_gregorian_utc_at_midnight(1964, 2, 29), 2.842258),
+        (Epoch::from_gregorian_utc_at_noon(1965, 6, 15), 3.854618),
+        (Epoch::from_gregorian_utc_at_midnight(1968, 3, 1), 6.26085),
+        (
+            Epoch::from_gregorian_utc_hms(1971, 12, 31, 18, 0, 0),
+            9.891594,
+        ),
+    ];
+
+    for (epoch, expected) in sofa_dat {
+        let delta_at = epoch.leap_seconds(false).unwrap();
+        assert!(
+            (delta_at - expected).abs() < 1e-9,
+            "{epoch}: got {delta_at} expected {expected}"
+        );
+        // Only the IERS leap seconds are accounted for by default.
+        assert_eq!(epoch.leap_seconds_iers(), 0);
+
+        // The SOFA definition of UTC accounts for the drift when converting to TAI and back.
+        let tai = epoch.to_time_scale_sofa(TimeScale::TAI);
+        assert!(((tai.duration - epoch.duration).to_seconds() - expected).abs() < 1e-9);
+        assert!(
+            (tai.leap_seconds(false).unwrap() - expected).abs() < 1e-9,
+            "{epoch}: got {} expected {expected}",
+            tai.leap_seconds(false).unwrap()
+        );
+        let utc = tai.to_time_scale_sofa(TimeScale::UTC);
+        assert!(
+            (utc - epoch).abs() <= 1 * Unit::Nanosecond,
+            "{epoch}: {utc}"
+        );
+        // As does the default conversion, unless only the IERS leap seconds are accounted for.
+        assert_eq!(epoch.to_time_scale(TimeScale::TAI), tai);
+        assert_eq!(
+            epoch
+                .to_time_scale_with(TimeScale::TAI, &IersOnly(LatestLeapSeconds))
+                .duration,
+            epoch.duration
+        );
+    }
+
+    // UTC is not defined prior to 1960.
+    let epoch = Epoch::from_gregorian_utc_at_midnight(1959, 12, 31);
+    assert_eq!(epoch.leap_seconds(false), None);
+    assert_eq!(
+        epoch.to_time_scale_sofa(TimeScale::TAI) - epoch,
+        Duration::ZERO
+    );
+
+    // After 1972, both definitions match.
+    let epoch = Epoch::from_gregorian_utc_at_midnight(1999, 1, 1);
+    assert_eq!(
+        epoch.to_time_scale_sofa(TimeScale::TAI),
+        epoch.to_time_scale(TimeScale::TAI)
+    );
 }
 
 #[cfg(feature = "std")]
@@ -1357,12 +1727,20 @@ fn test_add_durations_over_leap_seconds() {
     let pre_ls_utc = Epoch::from_gregorian_utc_at_noon(1971, 12, 31);
     let pre_ls_tai = pre_ls_utc.to_time_scale(TimeScale::TAI);
 
-    // Before the first leap second, there is no time difference between both epochs (because only IERS announced leap seconds are accounted for by default).
+    // Before the first leap second, there is no time difference between both epochs (they are the same instant).
     assert_eq!(pre_ls_utc - pre_ls_tai, Duration::ZERO);
     // When add 24 hours to either of the them, the UTC initialized epoch will increase the duration by 36 hours in UTC, which will cause a leap second jump.
-    // Therefore the difference between both epochs then becomes 10 seconds.
+    // Therefore the difference between both epochs then becomes that jump, from the drifting ΔAT of 9.890946 s of SOFA to 10 seconds.
     assert_eq!(
         (pre_ls_utc + 1 * Unit::Day) - (pre_ls_tai + 1 * Unit::Day),
+        109_054 * Unit::Microsecond
+    );
+    // Accounting only for the IERS leap seconds, this jump is the first leap second of 10 seconds.
+    let iers_tai = pre_ls_utc.to_time_scale_with(TimeScale::TAI, &IersOnly(LatestLeapSeconds));
+    assert_eq!(
+        (pre_ls_utc + 1 * Unit::Day)
+            .to_time_scale_with(TimeScale::TAI, &IersOnly(LatestLeapSeconds))
+            - (iers_tai + 1 * Unit::Day),
         10 * Unit::Second
     );
     // Of course this works the same way the other way around
@@ -1930,17 +2308,15 @@ fn test_to_tai_time_scale() {
 #[cfg(feature = "std")]
 #[test]
 fn test_leap_seconds_file() {
-    use hifitime::leap_seconds::{LatestLeapSeconds, LeapSecondsFile};
+    use hifitime::leap_seconds::{LatestLeapSeconds, LeapSecondProvider, LeapSecondsFile};
 
     let provider = LeapSecondsFile::from_path("data/leap-seconds.list").unwrap();
 
-    let default = LatestLeapSeconds::default();
-
     // Check that we read the data correctly knowing that the IERS data only contains the announced leap seconds.
     let mut pos = 0;
-    for expected in default {
+    for expected in LatestLeapSeconds.leap_seconds() {
         if expected.announced_by_iers {
-            assert_eq!(expected, provider[pos]);
+            assert_eq!(*expected, provider[pos]);
             pos += 1;
         }
     }
@@ -2185,3 +2561,148 @@ fn regression_test_gh_302() {
         "2024-12-16T02:05:08 UTC"
     );
 }
+
+#[test]
+fn test_delta_t() {
+    use hifitime::DeltaTModel;
+
+    let model = DeltaTModel::default();
+    assert_eq!(model, DeltaTModel::EspenakMeeus);
+
+    // Values of the polynomials of Espenak and Meeus at the start of their intervals, where the decimal year of the
+    // model is off by a few days from the proleptic Gregorian calendar in antiquity.
+    for (year, expected_s, tolerance_s) in [
+        (0, 10583.6, 0.5),
+        (1000, 1574.2, 0.2),
+        (1600, 120.0, 0.01),
+        (1900, -2.79, 0.01),
+        (1950, 29.07, 0.01),
+        (2000, 63.86, 0.01),
+    ] {
+        let epoch = Epoch::from_gregorian_at_midnight(year, 1, 1, TimeScale::TT);
+        let delta_t_s = model.delta_t(&epoch).to_seconds();
+        assert!(
+            (delta_t_s - expected_s).abs() < tolerance_s,
+            "{year}: {delta_t_s} s"
+        );
+    }
+
+    // Far from today, the model is the long-term parabola, which is continuous with the polynomials.
+    for year in [-30_000, -2000, 2200, 30_000] {
+        let epoch = Epoch::from_gregorian_at_midnight(year, 1, 1, TimeScale::TT);
+        assert_eq!(
+            model.delta_t(&epoch),
+            DeltaTModel::LongTermParabola.delta_t(&epoch)
+        );
+    }
+    for year in [-500, 2150] {
+        let epoch = Epoch::from_gregorian_at_midnight(year, 1, 1, TimeScale::TT);
+        let jump =
+            model.delta_t(&(epoch + 1 * Unit::Day)) - model.delta_t(&(epoch - 1 * Unit::Day));
+        assert!(jump.abs() < 1 * Unit::Second, "{year}: {jump}");
+    }
+    // About 37 days of rotation lost 32 millennia ago.
+    let ancient = Epoch::from_gregorian_at_midnight(-30_000, 1, 1, TimeScale::TT);
+    assert!((model.delta_t(&ancient).to_unit(Unit::Day) - 37.5).abs() < 0.1);
+
+    // Historical records are in solar time, e.g. the Babylonian record of the total solar eclipse of 15 April 136 BC (year -135).
+    let ut1 = Epoch::from_gregorian(-135, 4, 15, 6, 0, 0, 0, TimeScale::UT1);
+    let tt = ut1.to_time_scale_with_delta_t(TimeScale::TT, model);
+    assert_eq!(tt.time_scale, TimeScale::TT);
+    // TT - UT1 was about 12000 seconds then.
+    let delta_t = tt.duration - ut1.duration;
+    assert!((delta_t - model.delta_t(&tt)).abs() < 1 * Unit::Microsecond);
+    assert!((delta_t.to_seconds() - 12000.0).abs() < 100.0, "{delta_t}");
+
+    // Converting back and forth is exact to the nanosecond over the whole range of the model.
+    for year in [-30_000, -135, 1820, 1955, 3000, 30_000] {
+        let ut1 = Epoch::from_gregorian_at_noon(year, 6, 1, TimeScale::UT1);
+        let tt = ut1.to_time_scale_with_delta_t(TimeScale::TT, model);
+        assert!(
+            (tt.to_time_scale_with_delta_t(TimeScale::UT1, model)
+                .duration
+                - ut1.duration)
+                .abs()
+                <= 1 * Unit::Nanosecond,
+            "{year}"
+        );
+        assert!(
+            ((tt.duration - ut1.duration) - model.delta_t(&tt)).abs() < 1 * Unit::Microsecond,
+            "{year}"
+        );
+    }
+}
+
+#[test]
+fn test_solar_time() {
+    use core::f64::consts::FRAC_PI_2;
+    use core::str::FromStr;
+
+    // Example 28.b of Meeus (Astronomical Algorithms): the equation of time is 13m42.7s on 1992 October 13.0 TT.
+    let epoch = Epoch::from_gregorian_at_midnight(1992, 10, 13, TimeScale::TT);
+    let equation = epoch.equation_of_time();
+    assert!(
+        (equation - 13 * Unit::Minute - 42.7.seconds()).abs() < 0.2.seconds(),
+        "{equation}"
+    );
+    // The sundial is about 14 minutes slow in mid February, and 16 minutes fast early November.
+    let february = Epoch::from_gregorian_utc_at_midnight(2024, 2, 11).equation_of_time();
+    assert!(
+        (february + 14 * Unit::Minute + 14.seconds()).abs() < 15.seconds(),
+        "{february}"
+    );
+    let november = Epoch::from_gregorian_utc_at_midnight(2024, 11, 3).equation_of_time();
+    assert!(
+        (november - 16 * Unit::Minute - 26.seconds()).abs() < 15.seconds(),
+        "{november}"
+    );
+
+    // The local mean solar time of a UT1 epoch never requires UT1 data.
+    let epoch = Epoch::from_gregorian(2024, 6, 1, 3, 0, 0, 0, TimeScale::UT1);
+    assert_eq!(epoch.local_mean_solar_time(0.0), 3 * Unit::Hour);
+    assert_eq!(epoch.local_mean_solar_time(FRAC_PI_2), 9 * Unit::Hour);
+    assert_eq!(epoch.local_mean_solar_time(-FRAC_PI_2), 21 * Unit::Hour);
+    // Also before J1900.
+    let epoch_1850 = Epoch::from_gregorian(1850, 6, 1, 3, 0, 0, 0, TimeScale::UT1);
+    assert_eq!(
+        epoch_1850.local_mean_solar_time(-FRAC_PI_2),
+        21 * Unit::Hour
+    );
+
+    // The local solar dates and times are formatted like those of any time zone.
+    let format = Format::from_str("%Y-%m-%dT%H:%M:%S%z").unwrap();
+    assert_eq!(
+        format!(
+            "{}",
+            Formatter::with_local_mean_solar_time(epoch, FRAC_PI_2, format).unwrap()
+        ),
+        "2024-06-01T09:00:00+06:00"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            Formatter::with_local_mean_solar_time(epoch, -FRAC_PI_2, format).unwrap()
+        ),
+        "2024-05-31T21:00:00-06:00"
+    );
+
+    // Otherwise, the solar times require UT1 data, which is never approximated by UTC.
+    let utc_epoch = Epoch::from_gregorian_utc_hms(2024, 6, 1, 3, 0, 0);
+    assert!(matches!(
+        utc_epoch.try_local_mean_solar_time(0.0),
+        Err(HifitimeError::MissingUt1Data { .. })
+    ));
+    assert!(matches!(
+        utc_epoch.try_local_apparent_solar_time(0.0),
+        Err(HifitimeError::MissingUt1Data { .. })
+    ));
+    // Formatting them returns the same error instead of panicking.
+    assert!(matches!(
+        Formatter::with_local_mean_solar_time(utc_epoch, 0.0, format),
+        Err(HifitimeError::MissingUt1Data { .. })
+    ));
+    assert!(matches!(
+        Formatter::with_local_apparent_solar_time(utc_epoch, 0.0, format),
+        Err(HifitimeError::MissingUt1Data { .. })
+    ));
+}
@@ -0,0 +1,463 @@
+/// Serializes the tests which change the default leap seconds of the process.
+#[cfg(feature = "std")]
+static DEFAULT_PROVIDER_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[cfg(feature = "std")]
+#[test]
+fn custom_leap_second_provider() {
+    let _guard = DEFAULT_PROVIDER_LOCK.lock().unwrap();
+    use core::str::FromStr;
+    use hifitime::efmt::{Format, Formatter};
+    use hifitime::leap_seconds::{reset_default_provider, set_default_provider, LeapSecondsFile};
+    use hifitime::{Epoch, TimeScale, TimeUnits};
+
+    // Build a leap seconds file which includes a fictitious leap second on 2030-01-01, without its hash since the data changed.
+    let mut contents: String = std::fs::read_to_string("data/leap-seconds.list")
+        .unwrap()
+        .lines()
+        .filter(|line| !line.starts_with("#h"))
+        .map(|line| format!("{line}\n"))
+        .collect();
+    contents.push_str("4102444800\t38\t# 1 Jan 2030\n");
+    let path = std::env::temp_dir().join("hifitime-custom-leap-seconds.list");
+    std::fs::write(&path, contents).unwrap();
+    let provider = LeapSecondsFile::from_path(&path).unwrap();
+
+    // Before the fictitious leap second, the provider matches the built-in leap seconds.
+    let e2020 = Epoch::from_gregorian_utc_at_midnight(2020, 6, 1);
+    assert_eq!(
+        e2020.to_time_scale_with(TimeScale::TAI, &provider),
+        e2020.to_time_scale(TimeScale::TAI)
+    );
+
+    // After it, the conversions account for it.
+    let e2030 = Epoch::from_gregorian_utc_at_midnight(2030, 6, 1);
+    assert_eq!(e2030.leap_seconds_with(true, &provider), Some(38.0));
+    assert_eq!(e2030.leap_seconds(true), Some(37.0));
+    assert_eq!(
+        e2030.to_time_scale_with(TimeScale::TAI, &provider) - e2030.to_time_scale(TimeScale::TAI),
+        1.seconds()
+    );
+    assert_eq!(
+        e2030.to_time_scale_with(TimeScale::GPST, &provider) - e2030.to_time_scale(TimeScale::GPST),
+        1.seconds()
+    );
+
+    // Round trip through UTC with the same provider.
+    let tai = e2030.to_time_scale_with(TimeScale::TAI, &provider);
+    assert_eq!(tai.to_time_scale_with(TimeScale::UTC, &provider), e2030);
+
+    // Initializing from a UTC date with the provider yields the TAI epoch.
+    let from_utc = Epoch::from_gregorian_utc_with(2030, 6, 1, 0, 0, 0, 0, &provider).unwrap();
+    assert_eq!(from_utc.time_scale, TimeScale::TAI);
+    assert_eq!(from_utc, tai);
+    assert_eq!(from_utc - e2030, 1.seconds());
+    assert_eq!(
+        Epoch::from_gregorian_str_with("2030-06-01T00:00:00 UTC", &provider),
+        Ok(tai)
+    );
+    assert_eq!(
+        tai.to_gregorian_utc_with(&provider),
+        (2030, 6, 1, 0, 0, 0, 0)
+    );
+    assert_eq!(tai.to_gregorian_utc(), (2030, 6, 1, 0, 0, 1, 0));
+    assert_eq!(
+        tai.to_gregorian_str_with(TimeScale::UTC, &provider),
+        "2030-06-01T00:00:00 UTC"
+    );
+    assert_eq!(tai.to_utc_duration_with(&provider), e2030.duration);
+    assert_eq!(
+        format!(
+            "{}",
+            Formatter::to_time_scale_with(
+                tai,
+                Format::from_str("%Y-%m-%dT%H:%M:%S").unwrap(),
+                TimeScale::UTC,
+                &provider
+            )
+        ),
+        "2030-06-01T00:00:00"
+    );
+
+    // Setting the provider as the process default changes all conversions.
+    set_default_provider(&provider);
+    assert_eq!(e2030.leap_seconds(true), Some(38.0));
+    assert_eq!(e2030.to_time_scale(TimeScale::TAI), tai);
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(2030, 6, 1).to_time_scale(TimeScale::TAI),
+        tai
+    );
+
+    // And resetting it restores the built-in leap seconds.
+    reset_default_provider();
+    assert_eq!(e2030.leap_seconds(true), Some(37.0));
+    assert_eq!(e2030.to_time_scale(TimeScale::TAI) + 1.seconds(), tai);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn naif_leap_seconds_kernel() {
+    let _guard = DEFAULT_PROVIDER_LOCK.lock().unwrap();
+    use core::str::FromStr;
+    use hifitime::leap_seconds::{LatestLeapSeconds, LeapSecondProvider, LeapSecondsKernel};
+    use hifitime::{Epoch, EtModel, TimeScale};
+
+    let kernel = LeapSecondsKernel::from_path("naif0012.txt").unwrap();
+    assert_eq!(kernel.et_model(), EtModel::NAIF0012);
+
+    // The kernel leap seconds match the built-in ones.
+    let epoch = Epoch::from_gregorian_utc_hms(2020, 6, 1, 12, 34, 56);
+    assert_eq!(
+        epoch.to_time_scale_with(TimeScale::TAI, &kernel),
+        epoch.to_time_scale(TimeScale::TAI)
+    );
+    assert_eq!(
+        epoch.leap_seconds_with(true, &kernel),
+        epoch.leap_seconds_with(true, &LatestLeapSeconds)
+    );
+
+    // A kernel with another ET model, values appended with `+=` and numeric months.
+    let contents = std::fs::read_to_string("naif0012.txt")
+        .unwrap()
+        .replace("1.657D-3", "1.6D-3")
+        .replace(
+            "37,   @2017-JAN-1 )",
+            "37,   @2017-1-1 )\nDELTET/DELTA_AT += ( 38, @2030-JAN-1 )",
+        );
+    let custom = LeapSecondsKernel::from_str(&contents).unwrap();
+    let custom_model = custom.et_model();
+    assert_eq!(custom_model.k, 1.6e-3);
+    assert_eq!(custom.leap_seconds().len(), 29);
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(2030, 6, 1).leap_seconds_with(true, &custom),
+        Some(38.0)
+    );
+
+    let et_default = epoch.to_et_duration();
+    let et_custom = epoch.to_et_duration_with(custom_model);
+    assert_ne!(et_default, et_custom);
+    assert_eq!(epoch.to_et_duration_with(EtModel::NAIF0012), et_default);
+    // Round trip with the custom model.
+    let rtn = Epoch::from_et_duration_with(et_custom, custom_model);
+    assert!((rtn - epoch).abs().to_seconds() < 1e-8);
+
+    // Or per conversion, to and from ET.
+    let et = epoch.to_time_scale_with_et_model(TimeScale::ET, custom_model);
+    assert_eq!(et.duration, et_custom);
+    assert_eq!(
+        epoch.to_time_scale_with_et_model(TimeScale::ET, EtModel::NAIF0012),
+        epoch.to_time_scale(TimeScale::ET)
+    );
+    let rtn = et.to_time_scale_with_et_model(TimeScale::UTC, custom_model);
+    assert!((rtn - epoch).abs().to_seconds() < 1e-8);
+    // Other time scales are unaffected.
+    assert_eq!(
+        epoch.to_time_scale_with_et_model(TimeScale::TDB, custom_model),
+        epoch.to_time_scale(TimeScale::TDB)
+    );
+
+    // Invalid kernels.
+    assert!(LeapSecondsKernel::from_str("\\begindata\nDELTET/K = 1.657D-3\n").is_err());
+    assert!(LeapSecondsKernel::from_str(&contents.replace("@1972-JAN-1", "1972-JAN-1")).is_err());
+    assert!(LeapSecondsKernel::from_str(&contents.replace("DELTET/EB", "DELTET/EB =")).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn leap_seconds_file_expiration_and_hash() {
+    let _guard = DEFAULT_PROVIDER_LOCK.lock().unwrap();
+    use core::str::FromStr;
+    use hifitime::leap_seconds::{
+        default_provider_expires, reset_default_provider, set_default_provider, LatestLeapSeconds,
+        LeapSecondProvider, LeapSecondsFile,
+    };
+    use hifitime::{Epoch, HifitimeError, TimeScale};
+
+    let contents = std::fs::read_to_string("data/leap-seconds.list").unwrap();
+    let provider = LeapSecondsFile::from_str(&contents).unwrap();
+
+    // File expires on:  28 June 2023
+    let expires = Epoch::from_gregorian_utc_at_midnight(2023, 6, 28);
+    assert_eq!(provider.expires(), Some(expires));
+    // Last Update of leap second values:   8 July 2016
+    assert_eq!(
+        provider.last_update(),
+        Some(Epoch::from_gregorian_utc_at_midnight(2016, 7, 8))
+    );
+    assert!(!provider.is_expired_at(Epoch::from_gregorian_utc_at_midnight(2023, 6, 1)));
+    assert!(provider.is_expired_at(Epoch::from_gregorian_utc_at_midnight(2023, 7, 1)));
+    // The built-in leap seconds do not expire.
+    assert_eq!(LatestLeapSeconds.expires(), None);
+
+    // Detect conversions past the validity of the file.
+    let stale = Epoch::from_gregorian_utc_at_midnight(2024, 1, 1);
+    assert_eq!(
+        stale.try_to_time_scale_with(TimeScale::TAI, &provider),
+        Err(HifitimeError::ExpiredLeapSeconds {
+            expires,
+            epoch: stale
+        })
+    );
+    let valid = Epoch::from_gregorian_utc_at_midnight(2023, 1, 1);
+    assert_eq!(
+        valid.try_to_time_scale_with(TimeScale::TAI, &provider),
+        Ok(valid.to_time_scale(TimeScale::TAI))
+    );
+
+    // And whether the leap seconds of the process are stale.
+    assert_eq!(default_provider_expires(), None);
+    set_default_provider(&provider);
+    assert_eq!(default_provider_expires(), Some(expires));
+    reset_default_provider();
+    assert_eq!(default_provider_expires(), None);
+
+    // With the `leap-seconds-hash` feature, corrupted files are rejected, whether the data, the expiration date or the hash
+    // itself changed. Without it, they are not verified.
+    for corrupted in [
+        contents.replace("3692217600\t37", "3692217600\t38"),
+        contents.replace("#@\t3896899200", "#@\t3912710400"),
+        contents.replace("2c413af9", "2c413af8"),
+    ] {
+        #[cfg(feature = "leap-seconds-hash")]
+        assert_eq!(
+            LeapSecondsFile::from_str(&corrupted).unwrap_err(),
+            HifitimeError::Parse {
+                source: hifitime::ParsingError::HashMismatch,
+                details: "leap seconds file is corrupted"
+            }
+        );
+        #[cfg(not(feature = "leap-seconds-hash"))]
+        assert!(LeapSecondsFile::from_str(&corrupted).is_ok());
+    }
+
+    // Files without a hash are not verified.
+    let unverified: String = contents
+        .lines()
+        .filter(|line| !line.starts_with("#h"))
+        .map(|line| format!("{line}\n"))
+        .collect();
+    assert!(LeapSecondsFile::from_str(&unverified).is_ok());
+    // But an invalid hash line is an error.
+    assert!(LeapSecondsFile::from_str(&contents.replace("756ae00b", "")).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn tzdata_and_bulletin_c_leap_seconds() {
+    use hifitime::leap_seconds::{LatestLeapSeconds, LeapSecondProvider, LeapSecondsFile};
+    use hifitime::{Epoch, TimeScale};
+
+    let ietf = LeapSecondsFile::from_path("data/leap-seconds.list").unwrap();
+
+    // The tzdata file has the same leap seconds and dates as the IETF file it is generated from.
+    let tzdata = LeapSecondsFile::from_tzdata_path("data/leapseconds").unwrap();
+    assert_eq!(tzdata.expires(), ietf.expires());
+    assert_eq!(tzdata.last_update(), ietf.last_update());
+    assert_eq!(tzdata.leap_seconds(), ietf.leap_seconds());
+
+    // Bulletin C 65 only specifies the current offset, so the previous leap seconds are the built-in ones.
+    let bulletin = LeapSecondsFile::from_bulletin_c_path("data/bulletinc-065.txt").unwrap();
+    assert_eq!(bulletin.leap_seconds(), LatestLeapSeconds.leap_seconds());
+    // No leap second at the end of June 2023, and the next one could be at the end of December 2023.
+    assert_eq!(
+        bulletin.expires(),
+        Some(Epoch::from_gregorian_utc_at_midnight(2023, 12, 28))
+    );
+
+    // Bulletin C 52 announced the latest leap second.
+    let bulletin_52 = LeapSecondsFile::from_bulletin_c_str(
+        " A positive leap second will be introduced at the end of December 2016.
+ The sequence of dates of the UTC second markers will be:
+                          2016 December 31,     23h 59m 59s
+                          2016 December 31,     23h 59m 60s
+                          2017 January   1,      0h  0m  0s
+
+ The difference between UTC and the International Atomic Time TAI is:
+
+ from 2015 July 1, 0h UTC, to 2017 January 1 0h UTC   : UTC-TAI = - 36s
+ from 2017 January 1, 0h UTC, until further notice    : UTC-TAI = - 37s",
+    )
+    .unwrap();
+    assert_eq!(bulletin_52.leap_seconds(), LatestLeapSeconds.leap_seconds());
+    assert_eq!(
+        bulletin_52.expires(),
+        Some(Epoch::from_gregorian_utc_at_midnight(2017, 6, 28))
+    );
+    assert!(LeapSecondsFile::from_bulletin_c_str("Bulletin C without offsets").is_err());
+
+    // A tzdata file with a (fictitious) negative leap second.
+    let contents = std::fs::read_to_string("data/leapseconds").unwrap()
+        + "Leap\t2030\tDec\t31\t23:59:59\t-\tS\n";
+    let tzdata = LeapSecondsFile::from_tzdata_str(&contents).unwrap();
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2031, 1, 1);
+    assert_eq!(epoch.leap_seconds_with(true, &tzdata), Some(36.0));
+    assert_eq!(
+        (epoch.to_time_scale_with(TimeScale::TAI, &tzdata).duration - epoch.duration).to_seconds(),
+        36.0
+    );
+    assert!(LeapSecondsFile::from_tzdata_str("Leap\t2030\tDec\t31\t23:59:59\t?\tS").is_err());
+
+    // Discovery looks for the leap seconds files of the provided directories, provided that they are up to date.
+    let tzdir = std::env::temp_dir().join("hifitime-tzdir");
+    std::fs::create_dir_all(&tzdir).unwrap();
+    std::fs::write(
+        tzdir.join("leapseconds"),
+        std::fs::read_to_string("data/leapseconds").unwrap()
+            + "Leap\t2030\tDec\t31\t23:59:60\t+\tS\n",
+    )
+    .unwrap();
+    let discovered = LeapSecondsFile::discover_in([&tzdir]);
+    assert_eq!(discovered.leap_seconds().len(), 29);
+    assert_eq!(epoch.leap_seconds_with(true, &discovered), Some(38.0));
+
+    // Whatever is found, the discovered leap seconds include the latest built-in one.
+    std::fs::write(
+        tzdir.join("leapseconds"),
+        "Leap\t1972\tJun\t30\t23:59:60\t+\tS\n",
+    )
+    .unwrap();
+    for discovered in [
+        LeapSecondsFile::discover_in([&tzdir]),
+        LeapSecondsFile::discover(),
+    ] {
+        assert_eq!(
+            discovered.leap_seconds().last(),
+            LatestLeapSeconds.leap_seconds().last()
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn negative_leap_second() {
+    let _guard = DEFAULT_PROVIDER_LOCK.lock().unwrap();
+    use hifitime::leap_seconds::{
+        reset_default_provider, set_default_provider, LatestLeapSeconds, LeapSecond,
+        LeapSecondProvider, LeapSecondsFile,
+    };
+    use hifitime::{Epoch, HifitimeError, TimeScale, TimeSeries, TimeUnits};
+
+    /// A synthetic provider where 2029-12-31T23:59:59 UTC is skipped, such that ΔAT decreases to 36 s on 2030-01-01.
+    struct Synthetic(Vec<LeapSecond>);
+
+    impl LeapSecondProvider for Synthetic {
+        fn leap_seconds(&self) -> &[LeapSecond] {
+            &self.0
+        }
+    }
+
+    const NEGATIVE_LEAP_SECOND_S: i64 = 4_102_444_800;
+    let mut leap_seconds = LatestLeapSeconds.leap_seconds().to_vec();
+    // Without drift, as the IERS leap seconds.
+    let negative_leap_second = LeapSecond {
+        timestamp_utc_s: NEGATIVE_LEAP_SECOND_S,
+        delta_at: 36.0,
+        announced_by_iers: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        negative_leap_second,
+        LeapSecond::new(NEGATIVE_LEAP_SECOND_S, 36.0, true)
+    );
+    leap_seconds.push(negative_leap_second);
+    let provider = Synthetic(leap_seconds);
+
+    // Parsing a leap seconds file with a decreasing ΔAT yields the same leap seconds.
+    let mut contents: String = std::fs::read_to_string("data/leap-seconds.list")
+        .unwrap()
+        .lines()
+        .filter(|line| !line.starts_with("#h"))
+        .map(|line| format!("{line}\n"))
+        .collect();
+    contents.push_str("4102444800\t36\t# 1 Jan 2030\n");
+    let file: LeapSecondsFile = contents.parse().unwrap();
+    assert_eq!(&file.leap_seconds()[28..], &provider.leap_seconds()[42..]);
+
+    // ΔAT lookup in UTC: the offset decreases at midnight UTC.
+    let before = Epoch::from_gregorian_utc(2029, 12, 31, 23, 59, 58, 0);
+    let midnight = Epoch::from_gregorian_utc_at_midnight(2030, 1, 1);
+    assert_eq!(before.leap_seconds_with(true, &provider), Some(37.0));
+    assert_eq!(midnight.leap_seconds_with(true, &provider), Some(36.0));
+
+    // ΔAT lookup in TAI: the TAI second after 23:59:58 UTC is already midnight UTC.
+    let tai_before = before.to_time_scale_with(TimeScale::TAI, &provider);
+    let tai_midnight = midnight.to_time_scale_with(TimeScale::TAI, &provider);
+    assert_eq!(tai_midnight - tai_before, 1.seconds());
+    assert_eq!(tai_before.leap_seconds_with(true, &provider), Some(37.0));
+    assert_eq!(tai_midnight.leap_seconds_with(true, &provider), Some(36.0));
+    assert_eq!(
+        (tai_midnight - 1.nanoseconds()).leap_seconds_with(true, &provider),
+        Some(37.0)
+    );
+
+    // Gregorian decomposition of that day in UTC skips 23:59:59.
+    for (tai, expected) in [
+        (tai_before, (2029, 12, 31, 23, 59, 58, 0)),
+        (
+            tai_before + 500.milliseconds(),
+            (2029, 12, 31, 23, 59, 58, 500_000_000),
+        ),
+        (tai_midnight, (2030, 1, 1, 0, 0, 0, 0)),
+    ] {
+        assert_eq!(
+            tai.to_time_scale_with(TimeScale::UTC, &provider)
+                .to_gregorian_utc(),
+            expected
+        );
+    }
+    assert_eq!(
+        tai_midnight.to_time_scale_with(TimeScale::UTC, &provider),
+        midnight
+    );
+    assert_eq!(
+        Epoch::from_gregorian_utc_with(2029, 12, 31, 23, 59, 59, 0, &provider),
+        Err(HifitimeError::InvalidGregorianDate)
+    );
+    assert_eq!(
+        Epoch::from_gregorian_utc_with(2029, 12, 31, 23, 59, 58, 0, &provider),
+        Ok(tai_before)
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str_with("2029-12-31T23:59:59 UTC", &provider),
+        Err(HifitimeError::InvalidGregorianDate)
+    );
+    assert_eq!(
+        Epoch::from_gregorian_str_with("2029-12-31T23:59:58 UTC", &provider),
+        Ok(tai_before)
+    );
+
+    // The same applies to the default leap seconds, including when stepping through that day in UTC.
+    set_default_provider(&provider);
+    assert_eq!(
+        Epoch::maybe_from_gregorian_utc(2029, 12, 31, 23, 59, 59, 0),
+        Err(HifitimeError::InvalidGregorianDate)
+    );
+    assert_eq!(midnight.to_time_scale(TimeScale::TAI), tai_midnight);
+    assert_eq!(tai_midnight.to_time_scale(TimeScale::UTC), midnight);
+
+    let start = Epoch::from_gregorian_utc(2029, 12, 31, 23, 59, 57, 0);
+    let series = TimeSeries::exclusive(start, midnight + 2.seconds(), 1.seconds());
+    let epochs: Vec<Epoch> = series.clone().collect();
+    let expected = [start, before, midnight, midnight + 1.seconds()];
+    assert_eq!(epochs, expected);
+    // The skipped second is not counted in the length of the series.
+    assert_eq!(series.len(), expected.len());
+    let half_steps = TimeSeries::exclusive(start, midnight + 2.seconds(), 500.milliseconds());
+    assert_eq!(half_steps.len(), 8);
+    assert_eq!(half_steps.count(), 8);
+    // Every step is one second of TAI.
+    for pair in epochs.windows(2) {
+        assert_eq!(
+            pair[1].to_time_scale(TimeScale::TAI) - pair[0].to_time_scale(TimeScale::TAI),
+            1.seconds()
+        );
+    }
+    assert_eq!(
+        series.rev().collect::<Vec<_>>(),
+        expected.iter().rev().copied().collect::<Vec<_>>()
+    );
+
+    reset_default_provider();
+    // Without the negative leap second, 23:59:59 exists.
+    assert!(Epoch::maybe_from_gregorian_utc(2029, 12, 31, 23, 59, 59, 0).is_ok());
+}
@@ -0,0 +1,64 @@
+//! Setting a default provider which only accounts for the IERS leap seconds changes all the conversions of the process,
+//! so this test has its own test binary.
+
+#[cfg(feature = "std")]
+#[test]
+fn pre_1972_utc_default() {
+    use hifitime::leap_seconds::{
+        reset_default_provider, set_default_provider, IersOnly, LatestLeapSeconds,
+    };
+    use hifitime::{DeltaTModel, Epoch, TimeScale, Unit};
+
+    // Value of iauDat from SOFA (src/dat.c), including the drift of the UTC offset.
+    let epoch = Epoch::from_gregorian_utc_at_noon(1965, 6, 15);
+    let expected = 3.854618;
+
+    // By default, all the conversions match the SOFA definition of UTC.
+    let tai = epoch.to_time_scale(TimeScale::TAI);
+    assert_eq!(tai, epoch.to_time_scale_sofa(TimeScale::TAI));
+    assert!(((tai.duration - epoch.duration).to_seconds() - expected).abs() < 1e-9);
+    assert_eq!(
+        epoch.to_time_scale_with(TimeScale::TAI, &LatestLeapSeconds),
+        tai
+    );
+    assert_eq!(epoch.try_to_time_scale(TimeScale::TAI), Ok(tai));
+    // Including those with UT1 from a ΔT model or UT1 data.
+    let model = DeltaTModel::EspenakMeeus;
+    assert_eq!(epoch.to_time_scale_with_delta_t(TimeScale::TAI, model), tai);
+    assert_eq!(
+        epoch
+            .to_time_scale_with_delta_t(TimeScale::UT1, model)
+            .to_time_scale_with_delta_t(TimeScale::TAI, model),
+        tai
+    );
+    #[cfg(feature = "ut1")]
+    assert_eq!(
+        epoch.to_time_scale_with_ut1(TimeScale::TAI, &hifitime::ut1::Ut1Provider::default()),
+        Ok(tai)
+    );
+    assert!((tai.to_time_scale(TimeScale::UTC) - epoch).abs() <= 1 * Unit::Nanosecond);
+
+    // A provider wrapped in `IersOnly` only accounts for the IERS leap seconds, per call ...
+    let iers_tai = epoch.to_time_scale_with(TimeScale::TAI, &IersOnly(LatestLeapSeconds));
+    assert_eq!(iers_tai.duration, epoch.duration);
+
+    // ... or for all the conversions once set as the default provider.
+    set_default_provider(&IersOnly(LatestLeapSeconds));
+    assert_eq!(epoch.to_time_scale(TimeScale::TAI), iers_tai);
+    assert_eq!(epoch.try_to_time_scale(TimeScale::TAI), Ok(iers_tai));
+    assert_eq!(
+        epoch.to_time_scale_with_delta_t(TimeScale::TAI, model),
+        iers_tai
+    );
+    // The SOFA conversion is unaffected.
+    assert_eq!(epoch.to_time_scale_sofa(TimeScale::TAI), tai);
+    // After 1972, nothing changes.
+    let e1999 = Epoch::from_gregorian_utc_at_midnight(1999, 1, 1);
+    assert_eq!(
+        e1999.to_time_scale(TimeScale::TAI),
+        e1999.to_time_scale_sofa(TimeScale::TAI)
+    );
+
+    reset_default_provider();
+    assert_eq!(epoch.to_time_scale(TimeScale::TAI), tai);
+}
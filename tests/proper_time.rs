@@ -0,0 +1,147 @@
+#![cfg(feature = "std")]
+
+use hifitime::{
+    Duration, Epoch, HifitimeError, ProperTime, TimeScale, TimeUnits, TrajectorySample, IAU_L_G,
+};
+
+/// Geocentric gravitational constant, in km^3/s^2.
+const GM_EARTH_KM3_S2: f64 = 398_600.441_8;
+/// Speed of light in vacuum, in km/s.
+const C_KM_S: f64 = 299_792.458;
+
+#[test]
+fn test_proper_time_rates() {
+    let start = Epoch::from_gregorian_utc_at_midnight(2024, 1, 1);
+    let end = start + 1.days();
+
+    // A clock on the geoid, whose potential including the rotation of the Earth is W0 = L_G c^2, ticks at the rate of TT.
+    let speed_km_s = 0.465;
+    let geoid = ProperTime::from_fn(start, end, 10.minutes(), TimeScale::TT, 1.seconds(), |_| {
+        (
+            IAU_L_G * C_KM_S.powi(2) - speed_km_s * speed_km_s / 2.0,
+            speed_km_s,
+        )
+    })
+    .unwrap();
+    assert_eq!(geoid.time_scale(), TimeScale::TT);
+    assert_eq!(geoid.start(), start.to_time_scale(TimeScale::TT));
+    assert_eq!(geoid.end(), end.to_time_scale(TimeScale::TT));
+    for epoch in [start, start + 3.hours() + 7.seconds(), end] {
+        assert!((geoid.offset(&epoch).unwrap() - 1.seconds()).abs() < 1.nanoseconds());
+    }
+    // Relative to TCG, it loses L_G, i.e. about 60 µs per day.
+    let tcg = ProperTime::from_fn(start, end, 1.hours(), TimeScale::TCG, 0.seconds(), |_| {
+        (
+            IAU_L_G * C_KM_S.powi(2) - speed_km_s * speed_km_s / 2.0,
+            speed_km_s,
+        )
+    })
+    .unwrap();
+    let err = (tcg.offset(&end).unwrap() + (IAU_L_G * 86_400.0).seconds()).abs();
+    assert!(err < 1.nanoseconds(), "{err}");
+
+    // The clocks of the GPS satellites, on circular orbits, gain about 38.6 µs per day on TT.
+    let radius_km = 26_561.75;
+    let potential = GM_EARTH_KM3_S2 / radius_km;
+    let gps = ProperTime::from_fn(start, end, 1.hours(), TimeScale::TT, 0.seconds(), |_| {
+        (potential, potential.sqrt())
+    })
+    .unwrap();
+    let gain = gps.offset(&end).unwrap();
+    assert!(
+        gain > 38.4.microseconds() && gain < 38.8.microseconds(),
+        "{gain}"
+    );
+
+    // The samples may be provided in any order.
+    let samples: Vec<TrajectorySample> = (0..=24)
+        .rev()
+        .map(|hour| TrajectorySample::new(start + hour.hours(), potential, potential.sqrt()))
+        .collect();
+    assert_eq!(
+        ProperTime::from_samples(&samples, TimeScale::TT, 0.seconds()).unwrap(),
+        gps
+    );
+}
+
+#[test]
+fn test_proper_time_conversions() {
+    // An eccentric orbit, a = 7000 km and e = 0.1, with the speed from the vis-viva equation.
+    let start = Epoch::from_gregorian_utc_at_midnight(2024, 6, 1);
+    let end = start + 2.days();
+    let (a_km, e) = (7_000.0, 0.1);
+    let period_s = core::f64::consts::TAU * (a_km * a_km * a_km / GM_EARTH_KM3_S2).sqrt();
+    let trajectory = |epoch: Epoch| {
+        // The first order approximation of the radius is plenty for this test.
+        let mean_anomaly = core::f64::consts::TAU * (epoch - start).to_seconds() / period_s;
+        let radius_km = a_km * (1.0 - e * mean_anomaly.cos());
+        let potential = GM_EARTH_KM3_S2 / radius_km;
+        let speed = (GM_EARTH_KM3_S2 * (2.0 / radius_km - 1.0 / a_km)).sqrt();
+        (potential, speed)
+    };
+    let clock = ProperTime::from_fn(
+        start,
+        end,
+        30.seconds(),
+        TimeScale::TCG,
+        -2.milliseconds(),
+        trajectory,
+    )
+    .unwrap();
+
+    // The step converged.
+    let coarse = ProperTime::from_fn(
+        start,
+        end,
+        60.seconds(),
+        TimeScale::TCG,
+        -2.milliseconds(),
+        trajectory,
+    )
+    .unwrap();
+    let epoch = Epoch::from_gregorian_utc_hms(2024, 6, 2, 17, 42, 13);
+    let err = (clock.offset(&epoch).unwrap() - coarse.offset(&epoch).unwrap()).abs();
+    assert!(err < 1.nanoseconds(), "{err}");
+
+    // Round trips, with epochs in any time scale.
+    let proper_time = clock.to_proper_time(&epoch).unwrap();
+    assert_eq!(
+        proper_time,
+        epoch.to_tcg_duration() + clock.offset(&epoch).unwrap()
+    );
+    let rtn = clock.from_proper_time(proper_time).unwrap();
+    assert_eq!(rtn.time_scale, TimeScale::TCG);
+    assert!((rtn - epoch).abs() < 1.nanoseconds(), "{}", rtn - epoch);
+
+    // Out of the trajectory
+    let before = start - 1.seconds();
+    assert_eq!(
+        clock.offset(&before),
+        Err(HifitimeError::MissingProperTimeData { epoch: before })
+    );
+    assert!(clock.from_proper_time(before.to_tcg_duration()).is_err());
+
+    // Invalid trajectories
+    let sample = TrajectorySample::new(start, 60.0, 7.5);
+    assert!(matches!(
+        ProperTime::from_samples(&[sample], TimeScale::TT, 0.seconds()),
+        Err(HifitimeError::InvalidTrajectory { .. })
+    ));
+    assert!(matches!(
+        ProperTime::from_samples(&[sample, sample], TimeScale::TT, 0.seconds()),
+        Err(HifitimeError::InvalidTrajectory { .. })
+    ));
+    let later = TrajectorySample::new(end, 60.0, 7.5);
+    assert!(matches!(
+        ProperTime::from_samples(&[sample, later], TimeScale::UTC, 0.seconds()),
+        Err(HifitimeError::InvalidTrajectory { .. })
+    ));
+    for step in [Duration::ZERO, -1.minutes()] {
+        assert!(matches!(
+            ProperTime::from_fn(start, end, step, TimeScale::TT, 0.seconds(), |_| (
+                60.0, 7.5
+            )),
+            Err(HifitimeError::InvalidTrajectory { .. })
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-from hifitime import Duration, Epoch, HifitimeError, ParsingError, TimeScale, TimeSeries, Unit
+from hifitime import CustomTimeScale, Duration, Epoch, HifitimeError, ParsingError, TimeScale, TimeSeries, Unit
 from datetime import datetime, timezone
 import pickle
 
@@ -125,3 +125,30 @@ def test_interop():
         assert False, "tz aware dt did not fail"
     # Repeat after the strip
     assert Epoch.fromdatetime(tz_datetime.replace(tzinfo=None)) == Epoch("2023-10-08 15:30:00")
+
+def test_custom_time_scale():
+    launch = Epoch("2024-03-14T15:09:26 UTC")
+    met_ts = CustomTimeScale("MET", launch, TimeScale.UTC).register()
+    # The handle of a custom time scale round trips through Python.
+    assert met_ts == TimeScale("MET")
+    assert met_ts != TimeScale.UTC
+    assert str(met_ts) == "MET"
+    assert repr(met_ts) == 'TimeScale("MET")'
+    assert repr(TimeScale.GPST) == "TimeScale.GPST"
+    assert CustomTimeScale.from_time_scale(met_ts).name() == "MET"
+    assert CustomTimeScale.from_time_scale(TimeScale.TAI) is None
+    epoch = (launch + Unit.Minute * 1).to_time_scale(met_ts)
+    assert str(epoch) == "2024-03-14T15:10:26 MET"
+    assert Epoch(str(epoch)) == epoch
+    assert epoch.to_time_scale(TimeScale.UTC) == launch + Unit.Minute * 1
+    # Only the built-in time scales convert to integers, as with the former enumeration.
+    assert int(TimeScale.UTC) == 4
+    try:
+        int(met_ts)
+    except HifitimeError:
+        pass
+    else:
+        assert False, "custom time scale converted to an integer"
+    CustomTimeScale.unregister("MET")
+    assert CustomTimeScale.purge_unregistered() >= 1
+    assert CustomTimeScale.from_time_scale(met_ts) is None
@@ -0,0 +1,129 @@
+// The SOFA test vectors are quoted as published.
+#![allow(clippy::excessive_precision)]
+
+use core::f64::consts::{PI, TAU};
+use hifitime::{Epoch, TimeScale, Unit};
+
+/// Test vector of `iauEra00` in the test suite of SOFA (t_sofa_c.c), where UT1 is MJD 54388.0.
+const SOFA_ERA00: f64 = 0.402_283_724_002_815_810_2;
+/// Test vector of `iauEe00b` in the test suite of SOFA, where TT is MJD 53736.0.
+const SOFA_EE00B: f64 = -0.883_570_006_000_303_283_1e-5;
+
+#[test]
+fn test_sidereal_time() {
+    // The Earth Rotation Angle of a UT1 epoch never requires UT1 data.
+    let ut1_epoch = Epoch::from_mjd_in_time_scale(54388.0, TimeScale::UT1);
+    let era = ut1_epoch.earth_rotation_angle_rad();
+    assert!((era - SOFA_ERA00).abs() < 1e-12, "{era}");
+    let delta = (ut1_epoch + 6 * Unit::Hour).earth_rotation_angle_rad() - era;
+    assert!((delta.rem_euclid(TAU) - 1.002_737_811_911_354_48 * PI / 2.0).abs() < 1e-9);
+
+    // Otherwise, without any UT1 data, the sidereal times fail (or panic), since UT1 is never approximated by UTC.
+    let epoch = Epoch::from_mjd_utc(54388.0);
+    assert!(!epoch.has_ut1_data());
+    assert!(!ut1_epoch.has_ut1_data());
+    assert!(epoch.try_earth_rotation_angle_rad().is_err());
+    assert!(epoch.try_gmst_rad().is_err());
+    assert!(epoch.try_gast_2000b_rad().is_err());
+    assert!(epoch.try_local_sidereal_time_rad(PI).is_err());
+    assert!(epoch.try_local_mean_solar_time(PI).is_err());
+    assert!(epoch.try_local_apparent_solar_time(PI).is_err());
+    // The mean sidereal time of a UT1 epoch also depends on TT, which requires UT1 data.
+    assert!(ut1_epoch.try_gmst_rad().is_err());
+
+    // The equation of the equinoxes does not depend on UT1.
+    let epoch = Epoch::from_mjd_in_time_scale(53736.0, TimeScale::TT);
+    let equation = epoch.equation_of_the_equinoxes_2000b_rad();
+    assert!((equation - SOFA_EE00B).abs() < 1e-12, "{equation}");
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_sidereal_time_with_ut1() {
+    use hifitime::ut1::{DeltaTaiUt1, Ut1Source};
+    use hifitime::{Duration, HifitimeError, TimeUnits};
+
+    /// Test vectors of `iauGmst06`, `iauGmst00` and `iauGst00b` in the test suite of SOFA, where UT1 and TT are both MJD 53736.0.
+    const SOFA_GMST06: f64 = 1.754_174_971_870_091_203;
+    const SOFA_GMST00: f64 = 1.754_174_972_210_740_592;
+    const SOFA_GST00B: f64 = 1.754_166_136_510_680_589;
+
+    /// The SOFA test vectors use the same date in UT1 and TT.
+    struct Ut1IsTt;
+
+    impl Ut1Source for Ut1IsTt {
+        fn samples(&self) -> &[DeltaTaiUt1] {
+            &[]
+        }
+
+        fn delta_tai_ut1(&self, _epoch: &Epoch) -> Option<Duration> {
+            Some(-32.184.seconds())
+        }
+    }
+
+    let epoch = Epoch::from_mjd_in_time_scale(54388.0, TimeScale::TT);
+    let era = epoch.earth_rotation_angle_rad_with_ut1(&Ut1IsTt).unwrap();
+    assert!((era - SOFA_ERA00).abs() < 1e-12, "{era}");
+
+    let epoch = Epoch::from_mjd_in_time_scale(53736.0, TimeScale::TT);
+    let gmst = epoch.gmst_rad_with_ut1(&Ut1IsTt).unwrap();
+    assert!((gmst - SOFA_GMST06).abs() < 1e-12, "{gmst}");
+    let gast = epoch.gast_2000b_rad_with_ut1(&Ut1IsTt).unwrap();
+    assert!((gast - SOFA_GST00B).abs() < 1e-12, "{gast}");
+    // GAST (IAU 2000B) is the GMST of IAU 2000 plus the equation of the equinoxes (IAU 2000B).
+    let equation = gast - epoch.equation_of_the_equinoxes_2000b_rad();
+    assert!((equation - SOFA_GMST00).abs() < 1e-12, "{equation}");
+    assert_eq!(
+        epoch
+            .local_sidereal_time_rad_with_ut1(0.0, &Ut1IsTt)
+            .unwrap(),
+        gast
+    );
+
+    // A ΔT model is a UT1 source too, and sources which do not cover the epoch fail.
+    assert!(epoch
+        .gmst_rad_with_ut1(&hifitime::DeltaTModel::default())
+        .is_ok());
+    assert!(matches!(
+        epoch.gmst_rad_with_ut1(&hifitime::ut1::Ut1Provider::default()),
+        Err(HifitimeError::MissingUt1Data { .. })
+    ));
+
+    for epoch in [
+        Epoch::from_gregorian_utc_at_midnight(1950, 1, 1),
+        Epoch::from_gregorian_utc_hms(2024, 3, 20, 3, 6, 0),
+        Epoch::from_gregorian_utc_at_noon(2100, 12, 31),
+    ] {
+        // A constant TAI - UT1 keeps the length of the sidereal day.
+        let gast = epoch.gast_2000b_rad_with_ut1(&Ut1IsTt).unwrap();
+        let gmst = epoch.gmst_rad_with_ut1(&Ut1IsTt).unwrap();
+        for angle in [
+            epoch.earth_rotation_angle_rad_with_ut1(&Ut1IsTt).unwrap(),
+            gmst,
+            gast,
+            epoch
+                .local_sidereal_time_rad_with_ut1(-PI / 2.0, &Ut1IsTt)
+                .unwrap(),
+        ] {
+            assert!((0.0..TAU).contains(&angle));
+        }
+
+        // The sidereal time is that of Greenwich, i.e. the local one at longitude zero, and half a turn further at the antimeridian.
+        assert_eq!(
+            epoch
+                .local_sidereal_time_rad_with_ut1(0.0, &Ut1IsTt)
+                .unwrap(),
+            gast
+        );
+        let antimeridian = epoch
+            .local_sidereal_time_rad_with_ut1(PI, &Ut1IsTt)
+            .unwrap()
+            - gast;
+        assert!((antimeridian.rem_euclid(TAU) - PI).abs() < 1e-12);
+
+        // The Earth rotates by one turn in a sidereal day.
+        let sidereal_day = 86_164.090_530_832_88.seconds();
+        let delta = (epoch + sidereal_day).gmst_rad_with_ut1(&Ut1IsTt).unwrap() - gmst;
+        assert!(delta.abs() < 1e-8 || (delta.abs() - TAU).abs() < 1e-8);
+    }
+}
@@ -0,0 +1,356 @@
+use core::f64::consts::{FRAC_PI_2, PI};
+
+use hifitime::{Epoch, Observer, ObserverFrame, TdbModel, TimeScale, TimeUnits, Unit, JD_J2000};
+#[cfg(feature = "std")]
+use hifitime::{HifitimeError, TimeEphemeris};
+
+/// Geocentric TDB - TT of the Fairhead & Bretagnon series at TDB Julian dates between 1900 and 2100, i.e. SOFA `iauDtdb`
+/// with a null `u` and `v`. These were computed independently of hifitime with the coefficients of `iauDtdb`, except for the
+/// 31 smallest terms of degree 1 which hifitime omits too: these are below 0.5 ns over that period.
+const DTDB_REFERENCE: [(f64, f64); 7] = [
+    (2415020.5, -1.846_026_998_984_337e-5),
+    (2433282.5, -7.069_833_915_354_172e-5),
+    (2451545.0, -9.930_719_894_379_445e-5),
+    (2455197.5, -9.413_734_423_341_282e-5),
+    (2460310.5, -1.192_361_345_510_359e-4),
+    (2469807.5, -8.018_829_393_445_181e-5),
+    (2488069.5, -8.994_765_567_369_809e-5),
+];
+
+#[test]
+fn test_tdb_models() {
+    // SOFA `iauDtdb` test case on 2448939.623 TDB: -1.280368005936998991e-3 s, of which -383.57 ns of topocentric terms
+    // (computed with the same arguments), hence the geocentric TDB - TT of the full series below. The terms of degree 1
+    // omitted by hifitime account for about 2 ps on that date, close to J2000, so this matches to the nanosecond of `Duration`.
+    let tdb = Epoch::from_tdb_duration((2448939.623 - JD_J2000) * Unit::Day);
+    let expected = -1.279_984_433_218e-3.seconds();
+    let fb = TdbModel::FairheadBretagnon.tdb_minus_tt(&tdb);
+    let err = (fb - expected).abs();
+    assert!(err < 1.nanoseconds(), "Fairhead & Bretagnon error: {err}");
+    for (jd_tdb, expected) in DTDB_REFERENCE {
+        let tdb = Epoch::from_tdb_duration((jd_tdb - JD_J2000) * Unit::Day);
+        let err = (TdbModel::FairheadBretagnon.tdb_minus_tt(&tdb) - expected.seconds()).abs();
+        assert!(err < 1.nanoseconds(), "{tdb}: {err}");
+    }
+    // The ESA model is within a few tens of microseconds.
+    let esa = TdbModel::Esa.tdb_minus_tt(&tdb);
+    let err = (esa - expected).abs();
+    assert!(
+        err > 1.microseconds() && err < 50.microseconds(),
+        "ESA error: {err}"
+    );
+
+    // The conversions use the ESA model unless another one is provided.
+    let epoch = Epoch::from_gregorian_utc_hms(2024, 6, 1, 12, 0, 0);
+    let tdb_esa = epoch.to_tdb_duration();
+    assert_eq!(epoch.to_tdb_duration_with(TdbModel::Esa), tdb_esa);
+
+    let tdb_fb = epoch.to_tdb_duration_with(TdbModel::FairheadBretagnon);
+    assert_ne!(tdb_fb, tdb_esa);
+    assert!((tdb_fb - tdb_esa).abs() < 50.microseconds());
+    // Both are TT past J2000 once TDB - TT is removed.
+    assert_eq!(
+        tdb_fb - TdbModel::FairheadBretagnon.tdb_minus_tt(&epoch),
+        tdb_esa - TdbModel::Esa.tdb_minus_tt(&epoch)
+    );
+
+    // Round trips
+    let rtn = Epoch::from_tdb_duration_with(tdb_fb, TdbModel::FairheadBretagnon);
+    assert_eq!(rtn.time_scale, TimeScale::TAI);
+    assert!((rtn - epoch).abs() < 1.nanoseconds(), "{}", rtn - epoch);
+
+    // Topocentric terms: about 2 µs on the equator at 6 AM local mean solar time, and nothing at local midnight.
+    let equator_km = 6378.137;
+    let morning = TdbModel::topocentric_terms(&epoch, -FRAC_PI_2, equator_km, 0.0);
+    assert!(
+        morning > 1.9.microseconds() && morning < 2.1.microseconds(),
+        "{morning}"
+    );
+    let midnight = TdbModel::topocentric_terms(&epoch, PI, equator_km, 0.0);
+    assert!(midnight.abs() < 0.1.microseconds(), "{midnight}");
+    assert_eq!(
+        epoch.to_topocentric_tdb_duration_with(
+            TdbModel::FairheadBretagnon,
+            -FRAC_PI_2,
+            equator_km,
+            0.0
+        ),
+        tdb_fb + morning
+    );
+
+    // Conversions with a model
+    let model = TdbModel::FairheadBretagnon;
+    let tdb_epoch = epoch.to_time_scale_with_tdb_model(TimeScale::TDB, model);
+    assert_eq!(tdb_epoch.duration, tdb_fb);
+    assert_eq!(
+        tdb_epoch.to_time_scale_with_tdb_model(TimeScale::UTC, model),
+        epoch
+    );
+    // Including TCB, through TDB.
+    let tcb_epoch = epoch.to_time_scale_with_tdb_model(TimeScale::TCB, model);
+    assert_ne!(tcb_epoch, epoch.to_time_scale(TimeScale::TCB));
+    assert_eq!(
+        tcb_epoch.to_time_scale_with_tdb_model(TimeScale::TDB, model),
+        tdb_epoch
+    );
+    // ET is unaffected, to remain compatible with SPICE.
+    assert_eq!(
+        epoch.to_time_scale_with_tdb_model(TimeScale::ET, model),
+        epoch.to_time_scale(TimeScale::ET)
+    );
+}
+
+/// Offset of the synthetic time ephemeris from the Fairhead & Bretagnon series, to tell them apart.
+#[cfg(feature = "std")]
+const SYNTHETIC_BIAS_S: f64 = 5e-4;
+
+/// TT - TDB of the synthetic time ephemeris at the provided TDB seconds past J2000.
+#[cfg(feature = "std")]
+fn synthetic_tt_minus_tdb(seconds: f64) -> f64 {
+    let epoch = Epoch::from_tdb_duration(seconds.seconds());
+    -TdbModel::FairheadBretagnon
+        .tdb_minus_tt(&epoch)
+        .to_seconds()
+        - SYNTHETIC_BIAS_S
+}
+
+/// Builds a synthetic SPK file with a single TT - TDB segment (type 2) from `start` over `num_records` records of 16 days,
+/// fitted on `synthetic_tt_minus_tdb` at the Chebyshev nodes.
+#[cfg(feature = "std")]
+fn synthetic_time_ephemeris(start: f64, num_records: usize, little_endian: bool) -> Vec<u8> {
+    const DEGREE: usize = 12;
+    const RECORD_LEN: usize = 2 + 3 * (DEGREE + 1);
+    let interval = 16.0 * 86_400.0;
+    let n = DEGREE + 1;
+
+    let mut data = Vec::new();
+    for record in 0..num_records {
+        let radius = interval / 2.0;
+        let mid = start + record as f64 * interval + radius;
+        let values: Vec<f64> = (0..n)
+            .map(|k| {
+                let x = (PI * (k as f64 + 0.5) / n as f64).cos();
+                synthetic_tt_minus_tdb(mid + x * radius)
+            })
+            .collect();
+        data.extend([mid, radius]);
+        for j in 0..n {
+            let coefficient = 2.0 / n as f64
+                * values
+                    .iter()
+                    .enumerate()
+                    .map(|(k, value)| value * (PI * j as f64 * (k as f64 + 0.5) / n as f64).cos())
+                    .sum::<f64>();
+            data.push(if j == 0 {
+                coefficient / 2.0
+            } else {
+                coefficient
+            });
+        }
+        // The other two components are unused.
+        data.extend([0.0; 2 * (DEGREE + 1)]);
+    }
+    data.extend([start, interval, RECORD_LEN as f64, num_records as f64]);
+
+    // The file record, a summary record and its name record, then the segment data from the fourth record.
+    let begin = 3 * 128 + 1;
+    let end = begin + data.len() - 1;
+    let int = |value: i32| {
+        if little_endian {
+            value.to_le_bytes()
+        } else {
+            value.to_be_bytes()
+        }
+    };
+    let double = |value: f64| {
+        if little_endian {
+            value.to_le_bytes()
+        } else {
+            value.to_be_bytes()
+        }
+    };
+
+    let mut bytes = vec![b' '; 3 * 1024];
+    bytes[..8].copy_from_slice(b"DAF/SPK ");
+    bytes[8..12].copy_from_slice(&int(2));
+    bytes[12..16].copy_from_slice(&int(6));
+    bytes[76..80].copy_from_slice(&int(2));
+    bytes[80..84].copy_from_slice(&int(2));
+    bytes[84..88].copy_from_slice(&int(end as i32 + 1));
+    bytes[88..96].copy_from_slice(if little_endian {
+        b"LTL-IEEE"
+    } else {
+        b"BIG-IEEE"
+    });
+
+    let mut summary = Vec::new();
+    for value in [0.0, 0.0, 1.0, start, start + num_records as f64 * interval] {
+        summary.extend(double(value));
+    }
+    for value in [1_000_000_001, 1_000_000_000, 1, 2, begin as i32, end as i32] {
+        summary.extend(int(value));
+    }
+    bytes[1024..1024 + summary.len()].copy_from_slice(&summary);
+
+    for value in data {
+        bytes.extend(double(value));
+    }
+    bytes
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_time_ephemeris() {
+    let start = Epoch::from_gregorian_at_midnight(2024, 1, 1, TimeScale::TDB);
+    let bytes = synthetic_time_ephemeris(start.to_tdb_seconds(), 4, true);
+    let path = std::env::temp_dir().join("hifitime-synthetic-tt-tdb.bsp");
+    std::fs::write(&path, &bytes).unwrap();
+    let ephemeris = TimeEphemeris::from_path(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(ephemeris.start(), start);
+    assert_eq!(ephemeris.end(), start + 64.days());
+    // Either endianness is supported.
+    assert_eq!(
+        TimeEphemeris::from_bytes(&synthetic_time_ephemeris(start.to_tdb_seconds(), 4, false))
+            .unwrap(),
+        ephemeris
+    );
+
+    // The Chebyshev polynomials match the synthetic TT - TDB, including between the nodes and at the record boundaries,
+    // up to the rounding of its samples to the nanosecond.
+    for hours in (0..64 * 24).step_by(7) {
+        let tdb = start.to_tdb_duration() + hours.hours();
+        let tt_minus_tdb = ephemeris.tt_minus_tdb(tdb).unwrap();
+        let err = (tt_minus_tdb - synthetic_tt_minus_tdb(tdb.to_seconds()).seconds()).abs();
+        assert!(err <= 3.nanoseconds(), "{hours} h: {err}");
+    }
+    assert!(ephemeris
+        .tt_minus_tdb(start.to_tdb_duration() - 1.seconds())
+        .is_none());
+
+    // Conversions with the ephemeris
+    let epoch = Epoch::from_gregorian_utc_hms(2024, 2, 14, 6, 30, 0);
+    let tdb = epoch.to_tdb_duration_with_ephemeris(&ephemeris).unwrap();
+    let tdb_fb = epoch.to_tdb_duration_with(TdbModel::FairheadBretagnon);
+    assert!((tdb - tdb_fb - SYNTHETIC_BIAS_S.seconds()).abs() < 1.nanoseconds());
+    let rtn = Epoch::from_tdb_duration_with_ephemeris(tdb, &ephemeris).unwrap();
+    assert!((rtn - epoch).abs() < 1.nanoseconds(), "{}", rtn - epoch);
+
+    let outside = Epoch::from_gregorian_utc_at_midnight(2025, 1, 1);
+    assert_eq!(
+        outside.to_tdb_duration_with_ephemeris(&ephemeris),
+        Err(HifitimeError::MissingTimeEphemerisData { epoch: outside })
+    );
+
+    // Time scale conversions with the ephemeris
+    let tdb_epoch = epoch
+        .try_to_time_scale_with_ephemeris(TimeScale::TDB, &ephemeris)
+        .unwrap();
+    assert_eq!(tdb_epoch.duration, tdb);
+    assert_ne!(tdb_epoch, epoch.to_time_scale(TimeScale::TDB));
+    assert_eq!(
+        tdb_epoch.try_to_time_scale_with_ephemeris(TimeScale::UTC, &ephemeris),
+        Ok(epoch)
+    );
+    let tcb_epoch = epoch
+        .try_to_time_scale_with_ephemeris(TimeScale::TCB, &ephemeris)
+        .unwrap();
+    assert_eq!(
+        tcb_epoch.try_to_time_scale_with_ephemeris(TimeScale::TDB, &ephemeris),
+        Ok(tdb_epoch)
+    );
+    assert_eq!(
+        outside.try_to_time_scale_with_ephemeris(TimeScale::TDB, &ephemeris),
+        Err(HifitimeError::MissingTimeEphemerisData { epoch: outside })
+    );
+    // The time scales other than TDB and TCB do not need the ephemeris.
+    assert_eq!(
+        outside.try_to_time_scale_with_ephemeris(TimeScale::GPST, &ephemeris),
+        Ok(outside.to_time_scale(TimeScale::GPST))
+    );
+    // The other conversions are unaffected.
+    assert_eq!(
+        outside.try_to_time_scale(TimeScale::TDB),
+        Ok(outside.to_time_scale(TimeScale::TDB))
+    );
+
+    // Invalid files
+    assert!(TimeEphemeris::from_bytes(b"not a DAF file").is_err());
+    let mut no_time_segment = bytes.clone();
+    no_time_segment[1024 + 40..1024 + 44].copy_from_slice(&399_i32.to_le_bytes());
+    assert!(TimeEphemeris::from_bytes(&no_time_segment).is_err());
+}
+
+#[test]
+fn test_topocentric_tdb() {
+    // The topocentric term of a ground station matches the topocentric terms of Fairhead & Bretagnon.
+    let (u_km, v_km) = (5525.242, 3190.0);
+    let start = Epoch::from_gregorian_utc_at_midnight(2024, 1, 1);
+    for step in 0..100 {
+        let epoch = start + (step as f64 * 3.7).days();
+        let longitude_rad = step as f64 * 0.7;
+        let station =
+            Observer::earth_fixed([u_km * longitude_rad.cos(), u_km * longitude_rad.sin(), v_km]);
+        assert_eq!(station.frame, ObserverFrame::EarthFixed);
+        let err = (station.topocentric_offset(&epoch)
+            - TdbModel::topocentric_terms(&epoch, longitude_rad, u_km, v_km))
+        .abs();
+        assert!(err < 5.nanoseconds(), "{epoch}: {err}");
+    }
+
+    // Conversions
+    let epoch = Epoch::from_gregorian_utc_hms(2024, 6, 1, 12, 0, 0);
+    let station = Observer::earth_fixed([4_000.0, 1_000.0, 4_800.0]);
+    let model = TdbModel::FairheadBretagnon;
+    let tdb = epoch.to_tdb_duration_at(&station, model);
+    assert_eq!(
+        tdb,
+        epoch.to_tdb_duration_with(model) + station.topocentric_offset(&epoch)
+    );
+    let rtn = Epoch::from_tdb_duration_at(tdb, &station, model);
+    assert_eq!(rtn.time_scale, TimeScale::TAI);
+    assert!((rtn - epoch).abs() < 1.nanoseconds(), "{}", rtn - epoch);
+
+    // The term is linear in the position, and up to about 130 µs at the distance of the Moon.
+    let orbiter = Observer::inertial([7_000.0, -300.0, 100.0]);
+    let opposite = Observer::inertial([-7_000.0, 300.0, -100.0]);
+    assert_eq!(
+        orbiter.topocentric_offset(&epoch),
+        -opposite.topocentric_offset(&epoch)
+    );
+    let max_moon = (0..24)
+        .map(|hour| {
+            let angle = hour as f64 * core::f64::consts::PI / 12.0;
+            Observer::inertial([384_400.0 * angle.cos(), 384_400.0 * angle.sin(), 0.0])
+                .topocentric_offset(&epoch)
+        })
+        .max()
+        .unwrap();
+    assert!(
+        max_moon > 110.microseconds() && max_moon < 130.microseconds(),
+        "{max_moon}"
+    );
+
+    // The rate requires the velocity, and is the derivative of the term.
+    assert!(station.topocentric_rate(&epoch).is_none());
+    let station = station.with_velocity([0.0; 3]);
+    let rate = station.topocentric_rate(&epoch).unwrap();
+    let derivative = (station.topocentric_offset(&(epoch + 10.minutes()))
+        - station.topocentric_offset(&(epoch - 10.minutes())))
+    .to_seconds()
+        / 1200.0;
+    assert!((rate - derivative).abs() < 2e-12, "{rate} vs {derivative}");
+    // The orbital velocity of an orbiter dominates the rate: about 2e-9 along the velocity of the Earth.
+    let max_rate = [[7.5, 0.0, 0.0], [0.0, 7.5, 0.0], [0.0, 0.0, 7.5]]
+        .map(|velocity| {
+            orbiter
+                .with_velocity(velocity)
+                .topocentric_rate(&epoch)
+                .unwrap()
+                .abs()
+        })
+        .into_iter()
+        .fold(0.0, f64::max);
+    assert!(max_rate > 1e-9 && max_rate < 2.5e-9, "{max_rate}");
+}
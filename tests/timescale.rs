@@ -13,6 +13,11 @@ fn test_from_str() {
         ("GST", TimeScale::GST),
         ("BDT", TimeScale::BDT),
         ("QZSST", TimeScale::QZSST),
+        ("GLONASST", TimeScale::GLONASST),
+        ("IRNSST", TimeScale::IRNSST),
+        ("TCG", TimeScale::TCG),
+        ("TCB", TimeScale::TCB),
+        ("UT1", TimeScale::UT1),
     ];
     for value in values {
         let (descriptor, expected) = value;
@@ -28,6 +33,8 @@ fn test_from_str() {
             TimeScale::GST => "GAL",
             TimeScale::BDT => "BDS",
             TimeScale::QZSST => "QZSS",
+            TimeScale::GLONASST => "GLO",
+            TimeScale::IRNSST => "IRN",
             _ => descriptor, // untouched
         };
         assert_eq!(format!("{:x}", ts), expected);
@@ -44,6 +51,8 @@ fn test_from_rinex_format() {
     assert_eq!(TimeScale::from_str("GAL"), Ok(TimeScale::GST));
     assert_eq!(TimeScale::from_str("BDS"), Ok(TimeScale::BDT));
     assert_eq!(TimeScale::from_str("QZSS"), Ok(TimeScale::QZSST));
+    assert_eq!(TimeScale::from_str("GLO"), Ok(TimeScale::GLONASST));
+    assert_eq!(TimeScale::from_str("IRN"), Ok(TimeScale::IRNSST));
     // Check error
     assert_eq!(TimeScale::from_str("FAK"), Err(ParsingError::TimeSystem));
 }
@@ -60,9 +69,233 @@ fn test_is_gnss() {
     assert!(!ts.is_gnss());
     let ts = TimeScale::QZSST;
     assert!(ts.is_gnss());
+    let ts = TimeScale::GLONASST;
+    assert!(ts.is_gnss());
+    let ts = TimeScale::IRNSST;
+    assert!(ts.is_gnss());
 }
 
 #[test]
 fn test_default() {
     assert_eq!(TimeScale::default(), TimeScale::TAI);
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_custom_time_scale() {
+    use hifitime::efmt::consts::ISO8601;
+    use hifitime::efmt::Formatter;
+    use hifitime::{CustomTimeScale, Epoch, HifitimeError, TimeUnits};
+
+    // A spacecraft clock counted from its launch, whose oscillator runs 2.5 ppm fast and which was set 1.25 s ahead of GPST.
+    let launch = Epoch::from_gregorian_utc_hms(2024, 3, 14, 15, 9, 26);
+    let sclk = CustomTimeScale::new("SCLK", launch, TimeScale::GPST)
+        .unwrap()
+        .with_offset(1.25.seconds())
+        .with_rate_ppm(2.5)
+        .unwrap();
+    assert_eq!(sclk.name(), "SCLK");
+    assert_eq!(sclk.base(), TimeScale::GPST);
+    assert_eq!(
+        sclk.reference_epoch(),
+        launch.to_time_scale(TimeScale::GPST)
+    );
+    assert_eq!(sclk.to_duration(&launch), 1.25.seconds());
+
+    // After one day of GPST, the clock gained 216 ms.
+    let epoch = launch + 1.days();
+    assert_eq!(
+        sclk.to_duration(&epoch),
+        1.days() + 216.milliseconds() + 1.25.seconds()
+    );
+    // Round trips from any time scale
+    for epoch in [
+        launch - 3.days(),
+        Epoch::from_gregorian_utc_hms(2034, 7, 1, 0, 0, 0),
+        Epoch::from_tdb_seconds(8.12e8 + 0.123_456_789),
+    ] {
+        let rtn = sclk.from_duration(sclk.to_duration(&epoch));
+        assert_eq!(rtn.time_scale, TimeScale::GPST);
+        assert!((rtn - epoch).abs() <= 1.nanoseconds(), "{}", rtn - epoch);
+    }
+
+    // Without an offset nor a rate, the calendar is that of the base time scale.
+    let met = CustomTimeScale::new("MET", launch, TimeScale::UTC).unwrap();
+    let epoch = Epoch::from_gregorian_utc(2024, 12, 31, 23, 59, 58, 123_000_000);
+    assert_eq!(
+        met.to_gregorian_str(&epoch),
+        "2024-12-31T23:59:58.123000000 MET"
+    );
+    assert_eq!(met.to_gregorian(&epoch), epoch.to_gregorian_utc());
+    assert_eq!(met.parse("2024-12-31T23:59:58.123 MET").unwrap(), epoch);
+    assert_eq!(met.parse("SEC 60 MET").unwrap(), launch + 1.minutes());
+
+    // Formatting and parsing round trip: the clock gained 70.316 s in 325.5 days, and was set 1.25 s ahead of GPST (TAI - 19 s).
+    let epoch = Epoch::from_gregorian_tai(2025, 2, 3, 4, 5, 6, 789);
+    let formatted = sclk.to_gregorian_str(&epoch);
+    assert_eq!(formatted, "2025-02-03T04:05:58.566258289 SCLK");
+    assert!((sclk.parse(&formatted).unwrap() - epoch).abs() <= 1.nanoseconds());
+    // The name of the time scale is optional, but no other time scale is allowed.
+    assert_eq!(
+        sclk.parse("2025-02-03T04:05:58.566258289").unwrap(),
+        sclk.parse(&formatted).unwrap()
+    );
+    assert!(sclk.parse("2025-02-03T04:04:52 TAI").is_err());
+    for explicit in [
+        "2025-02-03T04:05:58 UTC",
+        "2025-02-03T04:05:58Z",
+        "2025-02-03T04:05:58 MET",
+    ] {
+        assert!(
+            matches!(
+                sclk.parse(explicit),
+                Err(HifitimeError::Parse {
+                    source: ParsingError::TimeSystem,
+                    ..
+                })
+            ),
+            "{explicit}"
+        );
+    }
+    for offset in [
+        "2025-02-03T04:05:58+01:00",
+        "2025-02-03T04:05:58-00:30 SCLK",
+    ] {
+        assert_eq!(
+            sclk.parse(offset),
+            Err(HifitimeError::Parse {
+                source: ParsingError::ISO8601,
+                details: "timezone offsets are not supported in a custom time scale"
+            }),
+            "{offset}"
+        );
+    }
+
+    // Registered time scales are time scales of their own, which epochs are formatted, parsed and converted into.
+    assert!(Epoch::from_str("2025-02-03T04:05:58.566258289 SCLK").is_err());
+    let sclk_ts = sclk.clone().register().unwrap();
+    assert_eq!(sclk.clone().register(), Ok(sclk_ts));
+    assert_eq!(
+        CustomTimeScale::from_time_scale(sclk_ts).as_deref(),
+        Some(&sclk)
+    );
+    assert_eq!(CustomTimeScale::from_time_scale(TimeScale::GPST), None);
+    assert_eq!(TimeScale::from_str("SCLK"), Ok(sclk_ts));
+    assert_eq!(format!("{sclk_ts}"), "SCLK");
+    assert!(!sclk_ts.is_gnss());
+
+    let epoch_sclk = epoch.to_time_scale(sclk_ts);
+    assert_eq!(epoch_sclk.time_scale, sclk_ts);
+    assert_eq!(epoch_sclk.duration, sclk.to_duration(&epoch));
+    assert_eq!(format!("{epoch_sclk}"), formatted);
+    assert_eq!(
+        format!("{}", Formatter::new(epoch_sclk, ISO8601)),
+        "2025-02-03T04:05:58.566258289 SCLK"
+    );
+    assert_eq!(
+        format!("{}", Formatter::to_time_scale(epoch, ISO8601, sclk_ts)),
+        "2025-02-03T04:05:58.566258289 SCLK"
+    );
+    assert_eq!(epoch.to_gregorian_str(sclk_ts), formatted);
+    assert!((epoch_sclk.to_time_scale(TimeScale::TAI) - epoch).abs() <= 1.nanoseconds());
+    assert_eq!(
+        epoch_sclk.try_to_time_scale(TimeScale::GPST),
+        Ok(epoch_sclk.to_time_scale(TimeScale::GPST))
+    );
+
+    let parsed = Epoch::from_str(&formatted).unwrap();
+    assert_eq!(parsed.time_scale, sclk_ts);
+    assert!((parsed - epoch).abs() <= 1.nanoseconds());
+    let parsed = Epoch::from_str("SEC 1.25 SCLK").unwrap();
+    assert_eq!(parsed, Epoch::from_duration(1.25.seconds(), sclk_ts));
+    assert_eq!(parsed.to_time_scale(TimeScale::UTC), launch);
+    assert_eq!(
+        Epoch::from_str("2025-02-03T04:05:58-00:30 SCLK"),
+        Err(HifitimeError::Parse {
+            source: ParsingError::ISO8601,
+            details: "timezone offsets are not supported in a custom time scale"
+        })
+    );
+
+    // Conversions between custom time scales go through their base time scales.
+    let met_ts = met.clone().register().unwrap();
+    assert_ne!(met_ts, sclk_ts);
+    let epoch_met = epoch_sclk.to_time_scale(met_ts);
+    assert!((epoch_met.duration - met.to_duration(&epoch)).abs() <= 1.nanoseconds());
+    assert!(
+        (epoch_met.to_time_scale(sclk_ts).duration - epoch_sclk.duration).abs() <= 1.nanoseconds()
+    );
+    // A custom time scale cannot be the base of another.
+    assert!(matches!(
+        CustomTimeScale::new("SCLK2", launch, sclk_ts),
+        Err(HifitimeError::InvalidCustomTimeScale { .. })
+    ));
+
+    // Unregistered time scales are no longer parsed, but their epochs remain valid.
+    assert_eq!(CustomTimeScale::registered("SCLK").as_deref(), Some(&sclk));
+    assert_eq!(CustomTimeScale::unregister("SCLK").as_deref(), Some(&sclk));
+    assert_eq!(CustomTimeScale::registered("SCLK"), None);
+    assert_eq!(CustomTimeScale::unregister("SCLK"), None);
+    assert!(TimeScale::from_str("SCLK").is_err());
+    assert!(Epoch::from_str(&formatted).is_err());
+    assert_eq!(format!("{epoch_sclk}"), formatted);
+    assert!((epoch_sclk.to_time_scale(TimeScale::TAI) - epoch).abs() <= 1.nanoseconds());
+    assert_eq!(sclk.clone().register(), Ok(sclk_ts));
+
+    // Registered definitions never change: a different definition of a registered name is rejected...
+    let resynced = sclk.clone().with_offset(2.5.seconds());
+    assert_eq!(
+        resynced.clone().register(),
+        Err(HifitimeError::DuplicateCustomTimeScale {
+            name: "SCLK".to_string()
+        })
+    );
+    assert_eq!(TimeScale::from_str("SCLK"), Ok(sclk_ts));
+    // ... and gets a new handle once the previous one is unregistered, such that the existing epochs keep their meaning.
+    CustomTimeScale::unregister("SCLK");
+    let resynced_ts = resynced.clone().register().unwrap();
+    assert_ne!(resynced_ts, sclk_ts);
+    assert_eq!(TimeScale::from_str("SCLK"), Ok(resynced_ts));
+    assert_eq!(format!("{epoch_sclk}"), formatted);
+    assert_eq!(
+        CustomTimeScale::from_time_scale(sclk_ts).as_deref(),
+        Some(&sclk)
+    );
+    assert_eq!(
+        CustomTimeScale::from_time_scale(resynced_ts).as_deref(),
+        Some(&resynced)
+    );
+    assert_eq!(
+        epoch.to_time_scale(resynced_ts).duration - epoch_sclk.duration,
+        1.25.seconds()
+    );
+    CustomTimeScale::unregister("SCLK");
+    // Only the names of the built-in time scales are reserved by the definitions.
+    assert!(CustomTimeScale::new("MET", launch, TimeScale::TAI).is_ok());
+    CustomTimeScale::unregister("MET");
+
+    // Purging forgets the unregistered definitions, and reuses their handles.
+    assert!(CustomTimeScale::purge_unregistered() >= 3);
+    assert_eq!(CustomTimeScale::from_time_scale(sclk_ts), None);
+    assert_eq!(CustomTimeScale::from_time_scale(met_ts), None);
+    let resynced_ts = resynced.clone().register().unwrap();
+    assert_eq!(
+        CustomTimeScale::from_time_scale(resynced_ts).as_deref(),
+        Some(&resynced)
+    );
+    assert_eq!(CustomTimeScale::purge_unregistered(), 0);
+    CustomTimeScale::unregister("SCLK");
+    // The handle of a custom time scale does not fit in a u8.
+    assert!(u8::try_from(resynced_ts).is_err());
+    assert_eq!(u8::try_from(TimeScale::GPST), Ok(5));
+
+    // Invalid definitions
+    for name in ["", "TAI", "GPS", "2SCLK", "MY SCLK"] {
+        assert!(matches!(
+            CustomTimeScale::new(name, launch, TimeScale::TAI),
+            Err(HifitimeError::InvalidCustomTimeScale { .. })
+        ));
+    }
+    assert!(met.clone().with_rate_ppm(-1e6).is_err());
+    assert!(met.with_rate_ppm(f64::NAN).is_err());
+}
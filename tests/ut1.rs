@@ -1,3 +1,18 @@
+#[cfg(feature = "ut1")]
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Serializes the UT1 tests: the registered UT1 provider is shared by the whole process, and the conversions of UT1 epochs
+/// (including their comparisons with epochs in other time scales) use it.
+#[cfg(feature = "ut1")]
+static REGISTERED_UT1_PROVIDER: Mutex<()> = Mutex::new(());
+
+#[cfg(feature = "ut1")]
+fn lock_ut1_provider() -> MutexGuard<'static, ()> {
+    REGISTERED_UT1_PROVIDER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
 #[cfg(feature = "ut1")]
 #[test]
 fn test_ut1_from_file() {
@@ -5,6 +20,8 @@ fn test_ut1_from_file() {
     use hifitime::ut1::Ut1Provider;
     use hifitime::Epoch;
 
+    let _guard = lock_ut1_provider();
+
     let provider = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
 
     println!("{}", provider);
@@ -18,18 +35,20 @@ fn test_ut1_from_file() {
     //
     let epoch = Epoch::from_str("2022-01-03 03:05:06.7891").unwrap();
     assert_eq!(
-        format!("{:x}", epoch.to_ut1(provider)),
-        "2022-01-03T03:05:06.679020600 TAI"
+        format!("{:x}", epoch.to_ut1(&provider)),
+        "2022-01-03T03:05:06.679051930 TAI"
     );
 }
 
-#[cfg(feature = "ut1")]
+#[cfg(feature = "ut1-download")]
 #[test]
 fn test_ut1_from_jpl() {
     use core::str::FromStr;
     use hifitime::ut1::Ut1Provider;
     use hifitime::Epoch;
 
+    let _guard = lock_ut1_provider();
+
     // Download a specific version of the UT1 file
     let provider = Ut1Provider::download_from_jpl("221222_190002-marge_eop2.short").unwrap();
 
@@ -43,9 +62,937 @@ fn test_ut1_from_jpl() {
     // >>>
     //
     let epoch = Epoch::from_str("2022-01-03 03:05:06.7891 UTC").unwrap();
-    let ut1_epoch = epoch.to_ut1(provider);
+    let ut1_epoch = epoch.to_ut1(&provider);
     assert_eq!(
         format!("{:x}", ut1_epoch),
         "2022-01-03T03:05:43.789100000 TAI",
     );
 }
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_time_scale() {
+    use core::str::FromStr;
+    use hifitime::efmt::{Format, Formatter};
+    use hifitime::ut1::Ut1Provider;
+    use hifitime::{Epoch, HifitimeError, TimeScale, Unit};
+
+    let _guard = lock_ut1_provider();
+
+    let provider = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+
+    // Same reference as above, but now in the UT1 time scale.
+    let epoch = Epoch::from_str("2022-01-03 03:05:06.7891").unwrap();
+    let ut1_epoch = epoch
+        .to_time_scale_with_ut1(TimeScale::UT1, &provider)
+        .unwrap();
+    assert_eq!(ut1_epoch.time_scale, TimeScale::UT1);
+    assert_eq!(format!("{ut1_epoch}"), "2022-01-03T03:05:06.679051930 UT1");
+    assert_eq!(
+        ut1_epoch.duration,
+        epoch.to_ut1(&provider).to_tai_duration()
+    );
+
+    // Round trip back into UTC
+    assert_eq!(
+        ut1_epoch
+            .to_time_scale_with_ut1(TimeScale::UTC, &provider)
+            .unwrap(),
+        epoch
+    );
+
+    // Parsing a UT1 string keeps the time scale.
+    let parsed = Epoch::from_str("2022-01-03T03:05:06.679051930 UT1").unwrap();
+    assert_eq!(parsed.time_scale, TimeScale::UT1);
+    assert_eq!(parsed.duration, ut1_epoch.duration);
+
+    // Outside of the EOP data, the conversion fails.
+    let too_early = Epoch::from_gregorian_utc_at_midnight(2020, 1, 1);
+    assert!(matches!(
+        too_early.to_time_scale_with_ut1(TimeScale::UT1, &provider),
+        Err(HifitimeError::MissingUt1Data { .. })
+    ));
+
+    // Once registered, the provider is used by all conversions.
+    assert!(!epoch.has_ut1_data());
+    let gmst = epoch.gmst_rad_with_ut1(&provider).unwrap();
+    let mean = epoch
+        .local_mean_solar_time_with_ut1(0.0, &provider)
+        .unwrap();
+    let apparent = epoch
+        .local_apparent_solar_time_with_ut1(0.0, &provider)
+        .unwrap();
+    assert_eq!(mean, ut1_epoch.local_mean_solar_time(0.0));
+    assert!((apparent - mean - epoch.equation_of_time()).abs() < 1 * Unit::Nanosecond);
+    assert!(too_early
+        .local_apparent_solar_time_with_ut1(0.0, &provider)
+        .is_err());
+    Ut1Provider::register(provider);
+    assert!(epoch.has_ut1_data());
+    assert!(!too_early.has_ut1_data());
+    assert_eq!(epoch.try_gmst_rad().unwrap(), gmst);
+    assert_eq!(epoch.try_gmst_rad().unwrap(), epoch.gmst_rad());
+    assert_eq!(
+        epoch.try_local_mean_solar_time(0.0).unwrap(),
+        epoch.local_mean_solar_time(0.0)
+    );
+    assert_eq!(epoch.local_mean_solar_time(0.0), mean);
+    assert_eq!(epoch.local_apparent_solar_time(0.0), apparent);
+    assert_eq!(epoch.try_local_apparent_solar_time(0.0).unwrap(), apparent);
+    // The local apparent solar time is that of a sundial, about 16 minutes fast early November.
+    let noon = Epoch::from_gregorian_utc_at_noon(2022, 11, 3);
+    assert_eq!(
+        format!(
+            "{}",
+            Formatter::with_local_apparent_solar_time(
+                noon,
+                0.0,
+                Format::from_str("%H:%M").unwrap()
+            )
+            .unwrap()
+        ),
+        "12:16"
+    );
+    assert!(too_early.try_gmst_rad().is_err());
+    assert_eq!(epoch.try_to_time_scale(TimeScale::UT1).unwrap(), ut1_epoch);
+    assert_eq!(epoch.to_time_scale(TimeScale::UT1), ut1_epoch);
+    assert_eq!(parsed.to_time_scale(TimeScale::UTC), epoch);
+    assert!(too_early.try_to_time_scale(TimeScale::UT1).is_err());
+    assert!(Ut1Provider::unregister().is_some());
+    assert!(epoch.try_to_time_scale(TimeScale::UT1).is_err());
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_interpolation() {
+    use hifitime::ut1::{Ut1Interpolation, Ut1Provider, Ut1Source};
+    use hifitime::{Epoch, TimeUnits};
+    use std::fs::read_to_string;
+
+    let _guard = lock_ut1_provider();
+
+    let contents = read_to_string("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    let full = Ut1Provider::from_eop_data(contents.clone()).unwrap();
+
+    // Drop every other daily sample, and check the interpolation against the dropped samples.
+    let mut in_data = false;
+    let mut idx = 0;
+    let mut decimated = String::new();
+    for line in contents.lines() {
+        if line == " EOP2=" {
+            in_data = true;
+        } else if line == " $END" {
+            in_data = false;
+        } else if in_data {
+            idx += 1;
+            if idx % 2 == 0 {
+                continue;
+            }
+        }
+        decimated.push_str(line);
+        decimated.push('\n');
+    }
+    let decimated = Ut1Provider::from_eop_data(decimated).unwrap();
+    assert_eq!(decimated.interpolation(), Ut1Interpolation::Lagrange);
+
+    let max_error = |interpolation: Ut1Interpolation| {
+        let provider = decimated.clone().with_interpolation(interpolation);
+        full.samples()
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .filter(|sample| decimated.delta_tai_ut1(&sample.epoch).is_some())
+            .map(|sample| {
+                let (delta_tai_ut1, used) =
+                    provider.delta_tai_ut1_interpolated(&sample.epoch).unwrap();
+                assert_eq!(used, interpolation);
+                (delta_tai_ut1 - sample.delta_tai_minus_ut1).abs()
+            })
+            .max()
+            .unwrap()
+    };
+
+    let step = max_error(Ut1Interpolation::Step);
+    let linear = max_error(Ut1Interpolation::Linear);
+    let lagrange = max_error(Ut1Interpolation::Lagrange);
+    println!("max errors: step = {step}\tlinear = {linear}\tlagrange = {lagrange}");
+
+    // UT1 drifts by about a millisecond per day.
+    assert!(step > 1.milliseconds());
+    assert!(linear < 200.microseconds());
+    assert!(lagrange < 50.microseconds());
+    assert!(lagrange < linear);
+
+    // The samples themselves are returned, up to the rounding of the interpolation.
+    let sample = full[100];
+    assert!(
+        (full.delta_tai_ut1(&sample.epoch).unwrap() - sample.delta_tai_minus_ut1).abs()
+            <= 1.nanoseconds()
+    );
+
+    // With too few samples, the mode actually used is reported.
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2022, 1, 1);
+    let samples = [
+        (epoch, -0.1.seconds()),
+        (epoch + 1.days(), -0.101.seconds()),
+        (epoch + 2.days(), -0.102.seconds()),
+    ];
+    let provider = Ut1Provider::from_delta_ut1_utc(samples);
+    assert_eq!(
+        provider.delta_tai_ut1_interpolated(&(epoch + 12.hours())),
+        Some((37.1005.seconds(), Ut1Interpolation::Linear))
+    );
+    let provider = Ut1Provider::from_delta_ut1_utc(samples.into_iter().take(1));
+    assert_eq!(
+        provider.delta_tai_ut1_interpolated(&epoch),
+        Some((37.1.seconds(), Ut1Interpolation::Step))
+    );
+    assert_eq!(provider.delta_tai_ut1(&(epoch + 1.hours())), None);
+
+    // Duplicate epochs, e.g. from overlapping files, keep the last sample instead of dividing by zero in the interpolation.
+    let duplicated = [
+        samples[0],
+        samples[1],
+        (epoch + 1.days(), -0.103.seconds()),
+        samples[2],
+    ];
+    for interpolation in [Ut1Interpolation::Linear, Ut1Interpolation::Lagrange] {
+        let provider =
+            Ut1Provider::from_delta_ut1_utc(duplicated).with_interpolation(interpolation);
+        assert_eq!(provider.samples().len(), 3);
+        assert_eq!(
+            provider.delta_tai_ut1(&(epoch + 1.days())),
+            Some(37.103.seconds())
+        );
+        let (delta_tai_ut1, _) = provider
+            .delta_tai_ut1_interpolated(&(epoch + 12.hours()))
+            .unwrap();
+        assert!((delta_tai_ut1 - 37.1015.seconds()).abs() < 1.nanoseconds());
+    }
+    let contents = std::fs::read_to_string("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    let line = contents
+        .lines()
+        .skip_while(|line| *line != " EOP2=")
+        .nth(10)
+        .unwrap();
+    let overlapping = contents.replacen(line, &format!("{line}\n{line}"), 1);
+    assert_eq!(
+        Ut1Provider::from_eop_data(overlapping).unwrap().to_bytes(),
+        Ut1Provider::from_eop_data(contents).unwrap().to_bytes()
+    );
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_utc_over_leap_second() {
+    use hifitime::ut1::{Ut1Interpolation, Ut1Provider, Ut1Source};
+    use hifitime::{Epoch, TimeScale, TimeUnits};
+
+    let _guard = lock_ut1_provider();
+
+    // UT1 - UTC jumps by one second with the leap second at the end of 2016.
+    let samples = [
+        (Epoch::from_gregorian_utc_at_midnight(2016, 12, 30), -0.4080),
+        (Epoch::from_gregorian_utc_at_midnight(2016, 12, 31), -0.4086),
+        (Epoch::from_gregorian_utc_at_midnight(2017, 1, 1), 0.5908),
+        (Epoch::from_gregorian_utc_at_midnight(2017, 1, 2), 0.5902),
+    ];
+
+    for interpolation in [Ut1Interpolation::Linear, Ut1Interpolation::Lagrange] {
+        let provider = Ut1Provider::from_delta_ut1_utc(
+            samples
+                .iter()
+                .map(|(epoch, delta_ut1_utc)| (*epoch, delta_ut1_utc.seconds())),
+        )
+        .with_interpolation(interpolation);
+
+        // Stored as TAI - UT1, the data is continuous (and linear here).
+        let noon = Epoch::from_gregorian_utc_hms(2016, 12, 31, 12, 0, 0);
+        let (delta_tai_ut1, used) = provider.delta_tai_ut1_interpolated(&noon).unwrap();
+        assert_eq!(used, interpolation);
+        assert!((delta_tai_ut1 - 36.4089.seconds()).abs() < 1.microseconds());
+
+        // UT1 - UTC right before and at the leap second is still correct.
+        let before = Epoch::from_gregorian_utc_hms(2016, 12, 31, 23, 59, 59);
+        let ut1 = before
+            .to_time_scale_with_ut1(TimeScale::UT1, &provider)
+            .unwrap();
+        assert!((ut1.duration - before.duration + 0.4092.seconds()).abs() < 1.microseconds());
+
+        let after = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+        let ut1 = after
+            .to_time_scale_with_ut1(TimeScale::UT1, &provider)
+            .unwrap();
+        assert!((ut1.duration - after.duration - 0.5908.seconds()).abs() < 1.microseconds());
+    }
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_shared_source() {
+    use core::str::FromStr;
+    use hifitime::ut1::{DeltaTaiUt1, Ut1Interpolation, Ut1Provider, Ut1Source};
+    use hifitime::{Epoch, TimeScale, TimeUnits};
+    use std::sync::Arc;
+    use std::thread;
+
+    let _guard = lock_ut1_provider();
+
+    let provider =
+        Arc::new(Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap());
+    let epoch = Epoch::from_str("2022-01-03 03:05:06.7891").unwrap();
+    let expected = epoch.to_ut1(&*provider);
+
+    // A single table is shared by reference between threads, without any copy.
+    let handles: Vec<_> = (0..4)
+        .map(|hours| {
+            let provider = Arc::clone(&provider);
+            thread::spawn(move || {
+                let epoch = epoch + hours.hours();
+                (
+                    epoch.to_ut1(&*provider),
+                    epoch
+                        .to_time_scale_with_ut1(TimeScale::UT1, &*provider)
+                        .unwrap(),
+                )
+            })
+        })
+        .collect();
+    for (hours, handle) in handles.into_iter().enumerate() {
+        let (ut1, ut1_epoch) = handle.join().unwrap();
+        assert_eq!(ut1.to_tai_duration(), ut1_epoch.duration);
+        if hours == 0 {
+            assert_eq!(ut1, expected);
+        }
+    }
+
+    // User-defined stores of EOP data only provide their sorted samples.
+    struct Store {
+        samples: Vec<DeltaTaiUt1>,
+    }
+
+    impl Ut1Source for Store {
+        fn samples(&self) -> &[DeltaTaiUt1] {
+            &self.samples
+        }
+    }
+
+    let store = Store {
+        samples: provider.samples().to_vec(),
+    };
+    assert_eq!(store.interpolation(), Ut1Interpolation::Lagrange);
+    assert_eq!(epoch.to_ut1(&store), expected);
+    assert_eq!(
+        Epoch::from_ut1_duration(expected.to_tai_duration(), &store),
+        Epoch::from_ut1_duration(expected.to_tai_duration(), &*provider)
+    );
+    assert_eq!(epoch.ut1_offset(&store), provider.delta_tai_ut1(&epoch));
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_from_finals() {
+    use hifitime::ut1::{Ut1Provider, Ut1Quality, Ut1Source};
+    use hifitime::{Epoch, TimeUnits};
+
+    let _guard = lock_ut1_provider();
+
+    // This finals2000A file was built from the bundled JPL EOP2 data, so both providers should agree.
+    let finals =
+        Ut1Provider::from_finals_file("data/finals2000A-2021-12-30--2022-01-13.daily").unwrap();
+    let jpl = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+
+    // The last date has no UT1 - UTC and is skipped.
+    assert_eq!(finals.samples().len(), 14);
+    assert_eq!(
+        finals.samples()[0].epoch,
+        Epoch::from_gregorian_utc_at_midnight(2021, 12, 30)
+    );
+
+    // In the last interval, the Lagrange window can't be centered anymore, so the interpolations differ more.
+    for day in 1..=10 {
+        let epoch = Epoch::from_gregorian_utc_hms(2022, 1, day, 12, 0, 0);
+        let error =
+            (finals.delta_tai_ut1(&epoch).unwrap() - jpl.delta_tai_ut1(&epoch).unwrap()).abs();
+        assert!(error < 1.microseconds(), "{epoch}: {error}");
+    }
+
+    // Bulletin B values are final, and Bulletin A values are rapid (I) or predicted (P).
+    let qualities: Vec<Ut1Quality> = finals
+        .samples()
+        .iter()
+        .map(|sample| sample.quality)
+        .collect();
+    assert_eq!(qualities[..5], [Ut1Quality::Final; 5]);
+    assert_eq!(qualities[5..10], [Ut1Quality::Rapid; 5]);
+    assert_eq!(qualities[10..], [Ut1Quality::Predicted; 4]);
+
+    // Between two samples, the quality is that of the least reliable one.
+    let quality_at =
+        |day, hour| finals.quality_at(&Epoch::from_gregorian_utc_hms(2022, 1, day, hour, 0, 0));
+    assert_eq!(quality_at(2, 12), Some(Ut1Quality::Final));
+    assert_eq!(quality_at(3, 12), Some(Ut1Quality::Rapid));
+    assert_eq!(quality_at(8, 0), Some(Ut1Quality::Rapid));
+    assert_eq!(quality_at(8, 12), Some(Ut1Quality::Predicted));
+    assert_eq!(quality_at(13, 12), None);
+
+    // The JPL data is flagged from its header.
+    assert_eq!(
+        jpl.quality_at(&Epoch::from_gregorian_utc_at_midnight(2022, 1, 3)),
+        Some(Ut1Quality::Rapid)
+    );
+    assert_eq!(
+        jpl.quality_at(&Epoch::from_gregorian_utc_at_midnight(2022, 12, 1)),
+        Some(Ut1Quality::Predicted)
+    );
+
+    // Malformed data is rejected.
+    assert!(Ut1Provider::from_finals_data(
+        "22 1 3 59582.00 I  0.052948 0.000034  0.279034 0.000037  X-0.1100793".to_string()
+    )
+    .is_err());
+    assert!(Ut1Provider::from_finals_data(
+        "22 1 3 5958a.00 I  0.052948 0.000034  0.279034 0.000037  I-0.1100793".to_string()
+    )
+    .is_err());
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_before_1972() {
+    use hifitime::ut1::{Ut1Prediction, Ut1Provider, Ut1Source};
+    use hifitime::{Epoch, TimeScale, TimeUnits};
+
+    let _guard = lock_ut1_provider();
+
+    // Before 1972, TAI - UTC drifts, and the samples of UT1 - UTC use the same TAI - UTC as the conversions.
+    let line = |day, delta_ut1_utc| {
+        format!("1965  06  {day:02}   0  {:.2}    0.000000    0.000000   {delta_ut1_utc:.7}    0.000000    0.000000    0.000000    0.000000   0.0000000    0.000000    0.000000   0.0000000    0.000000    0.000000    0.000000    0.000000   0.0000000\n", 38911.0 + f64::from(day))
+    };
+    let eop20: String = [(14, 0.0990), (15, 0.1000), (16, 0.1010), (17, 0.1020)]
+        .into_iter()
+        .map(|(day, delta_ut1_utc)| line(day, delta_ut1_utc))
+        .collect();
+    let c04 = Ut1Provider::from_c04_data(eop20).unwrap();
+    let samples = (14..18).map(|day| {
+        (
+            Epoch::from_gregorian_utc_at_midnight(1965, 6, day),
+            (0.0990 + 0.001 * f64::from(day - 14)).seconds(),
+        )
+    });
+    let bulletins = Ut1Provider::from_delta_ut1_utc(samples);
+
+    for provider in [c04, bulletins] {
+        let epoch = Epoch::from_gregorian_utc_at_midnight(1965, 6, 15);
+        let ut1 = epoch
+            .to_time_scale_with_ut1(TimeScale::UT1, &provider)
+            .unwrap();
+        assert!((ut1.duration - epoch.duration - 0.1.seconds()).abs() < 1.nanoseconds());
+        let utc = ut1
+            .to_time_scale_with_ut1(TimeScale::UTC, &provider)
+            .unwrap();
+        assert!((utc - epoch).abs() <= 1.nanoseconds());
+        // TAI - UT1 is TAI - UTC (about 3.85 s then) minus UT1 - UTC.
+        let noon = Epoch::from_gregorian_utc_at_noon(1965, 6, 15);
+        let tai_utc = noon.to_tai_duration() - noon.to_utc_duration();
+        assert!((tai_utc - 3.854618.seconds()).abs() < 1.microseconds());
+        assert!(
+            (provider.delta_tai_ut1(&noon).unwrap() - tai_utc + 0.1005.seconds()).abs()
+                < 1.microseconds()
+        );
+        let ut1 = noon
+            .to_time_scale_with_ut1(TimeScale::UT1, &provider)
+            .unwrap();
+        assert!((ut1.duration - noon.duration - 0.1005.seconds()).abs() < 1.microseconds());
+    }
+
+    // So does the prediction model.
+    let prediction = Ut1Prediction {
+        a: 0.1,
+        b: 0.0,
+        mjd0: 38926.0,
+    };
+    let epoch = Epoch::from_gregorian_utc_at_midnight(1965, 6, 15);
+    assert!(
+        (epoch.to_tai_duration()
+            - epoch.to_utc_duration()
+            - prediction.delta_ut1_utc(&epoch)
+            - prediction.delta_tai_ut1(&epoch))
+        .abs()
+            < 1.nanoseconds()
+    );
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_from_c04() {
+    use hifitime::ut1::{Ut1Provider, Ut1Quality, Ut1Source};
+    use hifitime::{Epoch, TimeScale, TimeUnits};
+
+    let _guard = lock_ut1_provider();
+
+    // Illustrative values around the leap second at the end of 2016, in the EOP 14 C04 format.
+    let eop14 = "      FORMAT(3(I4),I7,2(F11.6),2(F12.7),2(F11.6),2(F11.6),2(F11.7),2(F12.6))
+##################################################################################
+
+      Date      MJD      x          y        UT1-UTC       LOD         dX        dY        x Err     y Err   UT1-UTC Err  LOD Err     dX Err       dY Err
+                         \"          \"           s           s          \"         \"           \"          \"          s         s            \"           \"
+     (0h UTC)
+
+2016  12  30  57752   0.063690   0.281390  -0.4080000   0.0006000   0.000100   0.000050   0.000030   0.000030  0.0000100  0.0000100    0.000060    0.000060
+2016  12  31  57753   0.061590   0.282680  -0.4086000   0.0006000   0.000100   0.000050   0.000030   0.000030  0.0000100  0.0000100    0.000060    0.000060
+2017   1   1  57754   0.059440   0.283960   0.5908000   0.0006000   0.000100   0.000050   0.000030   0.000030  0.0000100  0.0000100    0.000060    0.000060
+2017   1   2  57755   0.057270   0.285280   0.5902000   0.0006000   0.000100   0.000050   0.000030   0.000030  0.0000100  0.0000100    0.000060    0.000060
+";
+
+    // The same values in the EOP 20 C04 format.
+    let eop20 = "# EARTH ORIENTATION PARAMETER (EOP) PRODUCT CENTER CENTER (PARIS OBSERVATORY)
+# YR  MM  DD  HH       MJD        x(\")        y(\")  UT1-UTC(s)       dX(\")      dY(\")       xrt(\")      yrt(\")      LOD(s)        x Er        y Er  UT1-UTC Er      dX Er       dY Er       xrt Er      yrt Er      LOD Er
+2016  12  30   0  57752.00    0.063690    0.281390  -0.4080000    0.000100    0.000050    0.000000    0.000000   0.0006000    0.000030    0.000030   0.0000100    0.000060    0.000060    0.000000    0.000000   0.0000100
+2016  12  31   0  57753.00    0.061590    0.282680  -0.4086000    0.000100    0.000050    0.000000    0.000000   0.0006000    0.000030    0.000030   0.0000100    0.000060    0.000060    0.000000    0.000000   0.0000100
+2017  01  01   0  57754.00    0.059440    0.283960   0.5908000    0.000100    0.000050    0.000000    0.000000   0.0006000    0.000030    0.000030   0.0000100    0.000060    0.000060    0.000000    0.000000   0.0000100
+2017  01  02   0  57755.00    0.057270    0.285280   0.5902000    0.000100    0.000050    0.000000    0.000000   0.0006000    0.000030    0.000030   0.0000100    0.000060    0.000060    0.000000    0.000000   0.0000100
+";
+
+    for contents in [eop14, eop20] {
+        let provider = Ut1Provider::from_c04_data(contents.to_string()).unwrap();
+        assert_eq!(provider.samples().len(), 4);
+        assert!(provider
+            .samples()
+            .iter()
+            .all(|sample| sample.quality == Ut1Quality::Final));
+        assert_eq!(
+            provider.samples()[2].epoch,
+            Epoch::from_gregorian_utc_at_midnight(2017, 1, 1)
+        );
+
+        // Stored as TAI - UT1, the data is continuous across the leap second.
+        let noon = Epoch::from_gregorian_utc_hms(2016, 12, 31, 12, 0, 0);
+        assert!(
+            (provider.delta_tai_ut1(&noon).unwrap() - 36.4089.seconds()).abs() < 1.microseconds()
+        );
+
+        let after = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+        let ut1 = after
+            .to_time_scale_with_ut1(TimeScale::UT1, &provider)
+            .unwrap();
+        assert!((ut1.duration - after.duration - 0.5908.seconds()).abs() < 1.microseconds());
+    }
+
+    assert!(Ut1Provider::from_c04_data(
+        "2016  12  30  57752   0.063690   0.281390  -0.40800a0   0.0006000".to_string()
+    )
+    .is_err());
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_earth_orientation() {
+    use hifitime::ut1::{EarthOrientation, Ut1Provider, Ut1Source};
+    use hifitime::{Epoch, TimeUnits};
+
+    let _guard = lock_ut1_provider();
+
+    let jpl = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    let finals =
+        Ut1Provider::from_finals_file("data/finals2000A-2021-12-30--2022-01-13.daily").unwrap();
+
+    // First sample of the JPL file: 59500.0, 201.3317, 265.0565, 37105.3665, ..., 0.281, -0.008, ...
+    let first = jpl.samples()[0];
+    assert!((first.orientation.x_pole_arcsec.unwrap() - 0.2013317).abs() < 1e-12);
+    assert!((first.orientation.y_pole_arcsec.unwrap() - 0.2650565).abs() < 1e-12);
+    assert_eq!(first.orientation.lod, None);
+    assert!((first.orientation.dx_arcsec.unwrap() - 0.000281).abs() < 1e-12);
+    assert!((first.orientation.dy_arcsec.unwrap() + 0.000008).abs() < 1e-12);
+    assert_eq!(
+        jpl.earth_orientation_at(&first.epoch),
+        Some(first.orientation)
+    );
+
+    // The polar motion of the finals file was built from the JPL data, so both should agree between the samples.
+    for day in 1..=10 {
+        let epoch = Epoch::from_gregorian_utc_hms(2022, 1, day, 12, 0, 0);
+        let from_jpl = jpl.earth_orientation_at(&epoch).unwrap();
+        let from_finals = finals.earth_orientation_at(&epoch).unwrap();
+        assert!(
+            (from_jpl.x_pole_arcsec.unwrap() - from_finals.x_pole_arcsec.unwrap()).abs() < 1e-6
+        );
+        assert!(
+            (from_jpl.y_pole_arcsec.unwrap() - from_finals.y_pole_arcsec.unwrap()).abs() < 1e-6
+        );
+        assert_eq!(from_finals.lod, Some(0.4141.milliseconds()));
+    }
+
+    // Bulletin B, and then Bulletin A, celestial pole offsets.
+    for (day, dx_mas, dy_mas) in [(1, 0.170, -0.183), (10, 0.222, -0.218)] {
+        let epoch = Epoch::from_gregorian_utc_at_midnight(2022, 1, day);
+        let from_finals = finals.earth_orientation_at(&epoch).unwrap();
+        assert!((from_finals.dx_arcsec.unwrap() - dx_mas / 1000.0).abs() < 1e-9);
+        assert!((from_finals.dy_arcsec.unwrap() - dy_mas / 1000.0).abs() < 1e-9);
+    }
+    assert_eq!(
+        finals.earth_orientation_at(&Epoch::from_gregorian_utc_at_midnight(2022, 2, 1)),
+        None
+    );
+
+    // Both C04 series include the length of day, which is interpolated like the other parameters.
+    let c04 = Ut1Provider::from_c04_data(
+        "2016  12  30   0  57752.00    0.063690    0.281390  -0.4080000    0.000100    0.000050    0.000000    0.000000   0.0006000
+2016  12  31   0  57753.00    0.061590    0.282680  -0.4086000    0.000200    0.000060    0.000000    0.000000   0.0008000
+"
+        .to_string(),
+    )
+    .unwrap();
+    let noon = c04
+        .earth_orientation_at(&Epoch::from_gregorian_utc_hms(2016, 12, 30, 12, 0, 0))
+        .unwrap();
+    assert!((noon.x_pole_arcsec.unwrap() - 0.06264).abs() < 1e-6);
+    assert!((noon.y_pole_arcsec.unwrap() - 0.282035).abs() < 1e-6);
+    assert!((noon.lod.unwrap() - 0.7.milliseconds()).abs() < 1.microseconds());
+    assert!((noon.dx_arcsec.unwrap() - 0.00015).abs() < 1e-9);
+    assert!((noon.dy_arcsec.unwrap() - 0.000055).abs() < 1e-9);
+
+    // Parameters missing from the data stay missing.
+    let provider = Ut1Provider::from_delta_ut1_utc([(
+        Epoch::from_gregorian_utc_at_midnight(2022, 1, 1),
+        -0.1.seconds(),
+    )]);
+    assert_eq!(
+        provider.earth_orientation_at(&Epoch::from_gregorian_utc_at_midnight(2022, 1, 1)),
+        Some(EarthOrientation::default())
+    );
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_fetch() {
+    use core::str::FromStr;
+    use hifitime::ut1::Ut1Provider;
+    use hifitime::{Epoch, HifitimeError, ParsingError};
+    use std::fs::read_to_string;
+
+    let _guard = lock_ut1_provider();
+
+    // Serve the bundled file instead of downloading it.
+    let provider = Ut1Provider::fetch_from_jpl("latest_eop2.short", |url| {
+        assert_eq!(
+            url,
+            "https://eop2-external.jpl.nasa.gov/eop2/latest_eop2.short"
+        );
+        Ok(read_to_string("data/eop-2021-10-12--2023-01-04.short").unwrap())
+    })
+    .unwrap();
+
+    let epoch = Epoch::from_str("2022-01-03 03:05:06.7891").unwrap();
+    assert_eq!(
+        format!("{:x}", epoch.to_ut1(&provider)),
+        "2022-01-03T03:05:06.679051930 TAI"
+    );
+
+    // Errors of the callback are returned as is.
+    let err = Ut1Provider::fetch_from_jpl("missing.short", |_| {
+        Err(HifitimeError::Parse {
+            source: ParsingError::DownloadError { code: 404 },
+            details: "when fetching EOP2 file",
+        })
+    })
+    .unwrap_err();
+    assert_eq!(
+        err,
+        HifitimeError::Parse {
+            source: ParsingError::DownloadError { code: 404 },
+            details: "when fetching EOP2 file",
+        }
+    );
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_prediction() {
+    use core::f64::consts::PI;
+    use hifitime::ut1::{Ut1Estimate, Ut1Interpolation, Ut1Prediction, Ut1Provider, Ut1Source};
+    use hifitime::{Epoch, TimeScale, TimeUnits};
+
+    let _guard = lock_ut1_provider();
+
+    // Excerpt of a Bulletin A, with a prediction fitted to the end of the bundled JPL data.
+    let bulletin_a = "
+                          PREDICTIONS:
+         The following formulas will not reproduce the predictions given below,
+         but may be used to extend the predictions beyond the end of this table.
+
+                 x =  0.0437 + 0.0743 cos A + 0.1244 sin A - 0.0170 cos C - 0.0427 sin C
+                 y =  0.3766 + 0.1116 cos A - 0.0655 sin A - 0.0427 cos C + 0.0170 sin C
+            UT1-UTC = -0.0269 - 0.00010 (MJD - 59956) - (UT2-UT1)
+
+         where A = 2*pi*(MJD-59948)/365.25 and C = 2*pi*(MJD-59948)/435.
+
+         TAI-UTC(MJD 59949) = 37.0
+";
+    let prediction = Ut1Prediction::from_bulletin_a_data(bulletin_a.to_string()).unwrap();
+    assert_eq!(
+        prediction,
+        Ut1Prediction {
+            a: -0.0269,
+            b: -0.0001,
+            mjd0: 59956.0
+        }
+    );
+    assert_eq!(
+        Ut1Prediction::from_bulletin_a_data(
+            "UT1-UTC = 0.0512 + 0.00021 (MJD - 60000) - (UT2-UT1)".to_string()
+        )
+        .unwrap(),
+        Ut1Prediction {
+            a: 0.0512,
+            b: 0.00021,
+            mjd0: 60000.0
+        }
+    );
+    assert!(Ut1Prediction::from_bulletin_a_data("UT1-UTC = -0.0269".to_string()).is_err());
+
+    // The model itself, with T the Besselian year.
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2023, 3, 1);
+    let mjd = epoch.to_mjd_utc_days();
+    let two_pi_t = 2.0 * PI * (2000.0 + (mjd - 51544.03) / 365.2422);
+    let ut2_ut1 = 0.022 * two_pi_t.sin() - 0.012 * two_pi_t.cos() - 0.006 * (2.0 * two_pi_t).sin()
+        + 0.007 * (2.0 * two_pi_t).cos();
+    assert!((Ut1Prediction::ut2_minus_ut1(&epoch) - ut2_ut1).abs() < 1e-15);
+    let expected = -0.0269 - 0.0001 * (mjd - 59956.0) - ut2_ut1;
+    assert!((prediction.delta_ut1_utc(&epoch) - expected.seconds()).abs() < 1.nanoseconds());
+    assert!(
+        (prediction.delta_tai_ut1(&epoch) - (37.0 - expected).seconds()).abs() < 1.nanoseconds()
+    );
+
+    let provider = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    let last = *provider.samples().last().unwrap();
+
+    // Without a prediction model, there is no data after the last sample.
+    assert_eq!(provider.delta_tai_ut1_estimated(&epoch), None);
+    assert!(epoch
+        .to_time_scale_with_ut1(TimeScale::UT1, &provider)
+        .is_err());
+
+    let provider = provider.with_prediction(prediction);
+
+    // How each value was estimated is reported.
+    assert_eq!(
+        provider.delta_tai_ut1_estimated(&last.epoch),
+        Some((last.delta_tai_minus_ut1, Ut1Estimate::Sampled))
+    );
+    let (_, estimate) = provider
+        .delta_tai_ut1_estimated(&(last.epoch - 12.hours()))
+        .unwrap();
+    assert_eq!(
+        estimate,
+        Ut1Estimate::Interpolated(Ut1Interpolation::Lagrange)
+    );
+    let (delta_tai_ut1, estimate) = provider.delta_tai_ut1_estimated(&epoch).unwrap();
+    assert_eq!(estimate, Ut1Estimate::Predicted);
+    assert_eq!(delta_tai_ut1, prediction.delta_tai_ut1(&epoch));
+
+    // The prediction picks up where the data ends.
+    let (right_after, _) = provider
+        .delta_tai_ut1_estimated(&(last.epoch + 1.seconds()))
+        .unwrap();
+    assert!((right_after - last.delta_tai_minus_ut1).abs() < 1.milliseconds());
+
+    // And is used by the conversions and the UT1 offset.
+    let ut1 = epoch
+        .to_time_scale_with_ut1(TimeScale::UT1, &provider)
+        .unwrap();
+    assert_eq!(ut1.duration, epoch.to_tai_duration() - delta_tai_ut1);
+    assert_eq!(epoch.ut1_offset(&provider), Some(delta_tai_ut1));
+
+    // There is no prediction before the first sample.
+    let before = provider.samples()[0].epoch - 1.days();
+    assert_eq!(provider.delta_tai_ut1_estimated(&before), None);
+    assert_eq!(before.ut1_offset(&provider), None);
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_cache() {
+    use hifitime::ut1::{Ut1Interpolation, Ut1Prediction, Ut1Provider, Ut1Source};
+    use hifitime::{DeltaTModel, Epoch};
+    use std::cell::Cell;
+    use std::fs::{copy, remove_file, File};
+    use std::time::{Duration, SystemTime};
+
+    let _guard = lock_ut1_provider();
+
+    let finals =
+        Ut1Provider::from_finals_file("data/finals2000A-2021-12-30--2022-01-13.daily").unwrap();
+    let jpl = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short")
+        .unwrap()
+        .with_interpolation(Ut1Interpolation::Linear)
+        .with_prediction(Ut1Prediction {
+            a: -0.0269,
+            b: -0.0001,
+            mjd0: 59956.0,
+        })
+        .with_delta_t_model(DeltaTModel::LongTermParabola);
+
+    for provider in [finals, jpl] {
+        let bytes = provider.to_bytes();
+        let loaded = Ut1Provider::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.samples().len(), provider.samples().len());
+        for (loaded, sample) in loaded.samples().iter().zip(provider.samples()) {
+            assert_eq!(loaded.epoch, sample.epoch);
+            assert_eq!(loaded.delta_tai_minus_ut1, sample.delta_tai_minus_ut1);
+            assert_eq!(loaded.quality, sample.quality);
+            assert_eq!(loaded.orientation, sample.orientation);
+        }
+        assert_eq!(loaded.interpolation(), provider.interpolation());
+        assert_eq!(loaded.prediction(), provider.prediction());
+        assert_eq!(loaded.delta_t_model(), provider.delta_t_model());
+        assert_eq!(loaded.to_bytes(), bytes);
+
+        // Truncated, extended or corrupted data is rejected.
+        assert!(Ut1Provider::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Ut1Provider::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+        assert!(Ut1Provider::from_bytes(&bytes[1..]).is_err());
+        let mut other_version = bytes.clone();
+        other_version[12] += 1;
+        assert!(Ut1Provider::from_bytes(&other_version).is_err());
+        // A number of samples beyond the remaining bytes is rejected without allocating them.
+        let mut too_many = bytes.clone();
+        too_many[40..48].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(Ut1Provider::from_bytes(&too_many).is_err());
+        let mut one_more = bytes.clone();
+        let len = provider.samples().len() as u64 + 1;
+        one_more[40..48].copy_from_slice(&len.to_le_bytes());
+        assert!(Ut1Provider::from_bytes(&one_more).is_err());
+    }
+    assert!(Ut1Provider::from_bytes(&[]).is_err());
+
+    // The cache is only refreshed when the source file is newer.
+    let dir = std::env::temp_dir();
+    let source = dir.join(format!("hifitime-eop-{}.daily", std::process::id()));
+    let cache = dir.join(format!("hifitime-eop-{}.bin", std::process::id()));
+    let (source, cache) = (source.to_str().unwrap(), cache.to_str().unwrap());
+    copy("data/finals2000A-2021-12-30--2022-01-13.daily", source).unwrap();
+    let _ = remove_file(cache);
+
+    let parsed = Cell::new(0);
+    let load = || {
+        Ut1Provider::from_cached_file(source, cache, |path| {
+            parsed.set(parsed.get() + 1);
+            Ut1Provider::from_finals_file(path)
+        })
+        .unwrap()
+    };
+
+    let first = load();
+    assert_eq!(first.samples().len(), 14);
+    let cached = load();
+    assert_eq!(cached.to_bytes(), first.to_bytes());
+
+    File::options()
+        .write(true)
+        .open(source)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(60))
+        .unwrap();
+    let refreshed = load();
+    assert_eq!(refreshed.to_bytes(), first.to_bytes());
+    assert_eq!(parsed.get(), 2);
+
+    // A cache which cannot be loaded is replaced.
+    std::fs::write(cache, b"not a cache").unwrap();
+    File::options()
+        .write(true)
+        .open(cache)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(120))
+        .unwrap();
+    let replaced = load();
+    assert_eq!(replaced.to_bytes(), first.to_bytes());
+    assert_eq!(parsed.get(), 3);
+    assert!(replaced
+        .delta_tai_ut1(&Epoch::from_gregorian_utc_at_midnight(2022, 1, 5))
+        .is_some());
+
+    // The parsed provider is returned even if the cache cannot be written.
+    let unwritable = dir.join("hifitime-no-such-directory").join("eop.bin");
+    let uncached = Ut1Provider::from_cached_file(source, unwritable.to_str().unwrap(), |path| {
+        Ut1Provider::from_finals_file(path)
+    })
+    .unwrap();
+    assert_eq!(uncached.to_bytes(), first.to_bytes());
+    assert!(!unwritable.exists());
+
+    remove_file(source).unwrap();
+    remove_file(cache).unwrap();
+}
+
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_delta_t() {
+    use hifitime::ut1::{Ut1Estimate, Ut1Prediction, Ut1Provider, Ut1Source};
+    use hifitime::{DeltaTModel, Epoch, TimeScale, TimeUnits};
+
+    let _guard = lock_ut1_provider();
+
+    let model = DeltaTModel::EspenakMeeus;
+    let provider = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short")
+        .unwrap()
+        .with_delta_t_model(model);
+    let first = provider.samples()[0];
+    let last = provider.samples()[provider.samples().len() - 1];
+
+    // Before the EOP data, TAI - UT1 is modeled.
+    let epoch = Epoch::from_gregorian_utc_at_midnight(1900, 1, 1);
+    assert_eq!(
+        provider.delta_tai_ut1_estimated(&epoch),
+        Some((model.delta_tai_ut1(&epoch), Ut1Estimate::Modeled))
+    );
+    assert_eq!(provider.quality_at(&epoch), None);
+    // The samples are unchanged.
+    assert_eq!(
+        provider.delta_tai_ut1_estimated(&first.epoch),
+        Some((first.delta_tai_minus_ut1, Ut1Estimate::Sampled))
+    );
+    // After the EOP data, the prediction model has precedence.
+    let after = last.epoch + 30.days();
+    assert_eq!(
+        provider.delta_tai_ut1_estimated(&after).unwrap().1,
+        Ut1Estimate::Modeled
+    );
+    let predicted = provider.clone().with_prediction(Ut1Prediction {
+        a: -0.0269,
+        b: -0.0001,
+        mjd0: 59956.0,
+    });
+    assert_eq!(
+        predicted.delta_tai_ut1_estimated(&after).unwrap().1,
+        Ut1Estimate::Predicted
+    );
+    assert_eq!(
+        predicted.delta_tai_ut1_estimated(&epoch).unwrap().1,
+        Ut1Estimate::Modeled
+    );
+
+    // The model is close to the EOP data.
+    let modeled = model.delta_tai_ut1(&first.epoch) - first.delta_tai_minus_ut1;
+    assert!(modeled.abs() < 5.seconds(), "{modeled}");
+
+    // Any epoch can be converted with the provider, and a ΔT model is itself a source of UT1.
+    let ut1 = epoch
+        .to_time_scale_with_ut1(TimeScale::UT1, &provider)
+        .unwrap();
+    assert_eq!(
+        ut1,
+        epoch
+            .to_time_scale_with_ut1(TimeScale::UT1, &model)
+            .unwrap()
+    );
+    assert_eq!(ut1, epoch.to_time_scale_with_delta_t(TimeScale::UT1, model));
+    assert_eq!(
+        ut1.to_time_scale_with_ut1(TimeScale::UTC, &provider)
+            .unwrap(),
+        epoch
+    );
+    assert_eq!(epoch.ut1_offset(&model), Some(model.delta_tai_ut1(&epoch)));
+    assert!(model.samples().is_empty());
+}